@@ -0,0 +1,72 @@
+//! The timing of the notes played by the piezo buzzer (see `peripherals::Buzzer`).
+//!
+//! Timer1 plays a note by toggling the buzzer pin on every compare match, so a note is
+//!   timed by the rate of its compare matches. This is pure arithmetic, kept apart from
+//!   the timer itself so that it builds (and is tested) on the host: `cargo test` from
+//!   this directory.
+#![no_std]
+
+/// The frequency of the Timer1 clock (16 MHz system clock, prescaled by 8).
+pub const TIMER_HZ: u32 = 2_000_000;
+/// The rate of compare matches while resting, used to count down the duration of a rest.
+pub const REST_TICK_HZ: u32 = 1000;
+/// The lowest frequency played (lower frequencies are raised to it), since the compare
+///   value of a lower frequency doesn't fit the 16-bit register.
+pub const MIN_FREQUENCY_HZ: u16 = 16;
+
+
+/// Returns the rate of the compare matches of Timer1 while playing a note.
+///
+/// Each period of the square wave takes two compare matches (one per toggle), and a rest
+///   counts down its duration at `REST_TICK_HZ`.
+pub fn match_hz(frequency_hz: u16) -> u32 {
+    if frequency_hz == 0 { return REST_TICK_HZ }
+    2 * frequency_hz.max(MIN_FREQUENCY_HZ) as u32
+}
+
+/// Returns the compare value (of the OCR1A register) of Timer1 for a rate of compare matches.
+pub fn compare_value(match_hz: u32) -> u16 {
+    (TIMER_HZ / match_hz - 1) as u16
+}
+
+/// Returns the number of compare matches at a rate which last a duration, in milliseconds.
+pub fn duration_matches(match_hz: u32, duration_ms: u16) -> u32 {
+    // The whole and fractional kilohertz are multiplied separately, so that neither overflows.
+    let duration_ms = duration_ms as u32;
+    (match_hz / 1000) * duration_ms + (match_hz % 1000) * duration_ms / 1000
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_value_of_a_note() {
+        // A4 (440Hz) toggles 880 times a second: 2MHz / 880 - 1.
+        assert_eq!(compare_value(match_hz(440)), 2271);
+        assert_eq!(compare_value(match_hz(0)), 1999);
+    }
+
+    #[test]
+    fn low_frequencies_are_raised() {
+        for frequency_hz in 1..MIN_FREQUENCY_HZ {
+            assert_eq!(match_hz(frequency_hz), match_hz(MIN_FREQUENCY_HZ));
+        }
+        // The compare value of the lowest frequency fits, rather than wrapping around.
+        assert_eq!(compare_value(match_hz(MIN_FREQUENCY_HZ)), 62499);
+        assert_eq!(compare_value(match_hz(1)), 62499);
+    }
+
+    #[test]
+    fn high_frequencies_fit() {
+        assert_eq!(compare_value(match_hz(u16::MAX)), 14);
+    }
+
+    #[test]
+    fn duration_of_a_note() {
+        assert_eq!(duration_matches(match_hz(440), 500), 440);
+        assert_eq!(duration_matches(match_hz(0), 250), 250);
+        assert_eq!(duration_matches(match_hz(u16::MAX), u16::MAX), 8_589_672);
+    }
+}
@@ -1,13 +1,168 @@
 const BAUD_RATE: u32 = 9600;
+/// The time without any JoyStick input after which the microcontroller goes to sleep (5 minutes).
+const IDLE_SLEEP_MS: u32 = 5 * 60 * 1000;
+/// Whether the DotDisplay is driven by the hardware SPI peripheral, rather than by toggling
+///   its pins in software. Both use the same pins, so this needs no change to the wiring.
+const HARDWARE_SPI_DISPLAY: bool = true;
+/// The number of MAX7219 panels daisy-chained into the DotDisplay. The games play on the first panel.
+const DISPLAY_PANELS: usize = 1;
+/// The orientation of the DotDisplay (see `Orientation`), turning (and mirroring) every screen
+///   shown to undo the way its module is mounted, e.g. `Orientation::new(Rotation::Rotate180, false)` for a module mounted upside down.
+const DISPLAY_ORIENTATION: crate::peripherals::Orientation = crate::peripherals::Orientation::UPRIGHT;
+/// Whether the games are played on an SSD1306 OLED on the I2C bus (A4 and A5, see `I2C_BUS`),
+///   rather than on the DotDisplay. The DotDisplay is used if the OLED doesn't respond.
+const OLED_DISPLAY: bool = false;
+/// Whether the games are played, in color, on an 8x8 matrix of WS2812 RGB LEDs on D6,
+///   rather than on the DotDisplay.
+const RGB_MATRIX_DISPLAY: bool = false;
+/// Whether a second DotDisplay, sharing the hardware SPI with its chip select on D8,
+///   shows the score during game-play. This requires `HARDWARE_SPI_DISPLAY`.
+const SCOREBOARD_DISPLAY: bool = false;
+/// Whether a TM1637 4-digit 7-segment display, on D4 (CLK) and D5 (DIO), shows the score
+///   during game-play.
+const SCORE_DIGITS_DISPLAY: bool = false;
+/// Whether an IR receiver on D7 lets a remote control (see `DEFAULT_REMOTE_KEYMAP`) stand in for the JoyStick.
+const IR_REMOTE: bool = false;
+/// Whether a push-button on D7 (connecting it to ground) is a JoyStick button of its own,
+///   e.g. a big arcade button, see `Button`. The IR receiver takes D7 first (see `IR_REMOTE`).
+const PUSH_BUTTON: bool = false;
+/// Whether there is a JoyStick for the second player, on A3 (x-axis), A4 (y-axis), and D2
+///   (button). Without it (or the paddle, see `PADDLE`) those pins are spare. The I2C bus
+///   takes over A4 (see `I2C_BUS`), leaving no second JoyStick.
+const SECOND_JOYSTICK: bool = true;
+/// Whether a potentiometer paddle on A3 takes the place of the JoyStick of the second player
+///   (whose x-axis is on the same pin), e.g. for paddle games. The RNG takes over A3 while
+///   the I2C bus is enabled (see `RNG_CHANNEL`), leaving no paddle.
+const PADDLE: bool = false;
+/// The ADC channel of the pin of the LDR of the ambient light sensor, if there is one
+///   (e.g. Some(4) for A4, without the second JoyStick, see `SECOND_JOYSTICK`). The brightness of
+///   the display may then follow the ambient light (see `Settings::AUTO_BRIGHTNESS`).
+///   A4 and A5 aren't read while the I2C bus is enabled (see `I2C_BUS`).
+const LIGHT_SENSOR_CHANNEL: Option<u8> = None;
+/// The supply voltage (in millivolts) below which the battery is low, for battery-powered builds
+///   (e.g. Some(3400) for a single lithium cell), see `BatteryMonitor`. A low battery blinks
+///   the top-right corner dot of the display.
+const LOW_BATTERY_MV: Option<u16> = None;
+/// Whether keystrokes sent over the serial connection (WASD and space, e.g. from a PC
+///   terminal) drive a virtual JoyStick alongside the JoyStick, see `SerialJoystick`.
+///   The leaderboard doesn't receive its handshake meanwhile.
+const SERIAL_JOYSTICK: bool = false;
+/// Whether a PS/2 keyboard, with its clock on D2 and its data on A4, stands in for the JoyStick
+///   (the arrows or WASD, and space or enter), and types the initials of the high scores.
+///   It takes the place of the second JoyStick (see `SECOND_JOYSTICK`), whose pins it uses,
+///   so it can't be used with the I2C bus either.
+const PS2_KEYBOARD: bool = false;
+/// Whether a 5-button analog keypad on A3 (a resistor ladder, e.g. of an LCD keypad shield) stands
+///   in for the JoyStick, see `LadderKeypad` and `DEFAULT_LADDER_THRESHOLDS`. It takes A3 from the
+///   second JoyStick and the paddle (see `SECOND_JOYSTICK` and `PADDLE`), leaving neither, and the
+///   RNG takes A3 while the I2C bus is enabled (see `RNG_CHANNEL`), leaving no keypad.
+const LADDER_KEYPAD: bool = false;
+/// The number of daisy-chained 74HC165 shift registers reading the buttons of gamepads (up to
+///   `MAX_SHIFT_REGISTERS`), with their latch on D4, their clock on D5, and their data on D6,
+///   or 0 if there are none. The first gamepad stands in for the JoyStick, see `ShiftRegisters`.
+///   The score digits and the RGB matrix take these pins first (see `SCORE_DIGITS_DISPLAY` and
+///   `RGB_MATRIX_DISPLAY`), leaving no shift registers.
+const SHIFT_REGISTERS: usize = 0;
+/// Whether the command console (see `console`) runs over the serial connection, between the game ticks.
+const SERIAL_CONSOLE: bool = true;
+/// Whether every screen shown on the display is also written to the serial connection,
+///   as ASCII art (8 lines of `#` and `.`), for debugging. This slows the game-play down.
+const SERIAL_SCREEN_MIRROR: bool = false;
+/// Whether the I2C bus (on A4 and A5) is enabled, for devices such as an accelerometer.
+///   The bus takes over the pins of the second JoyStick's y-axis (or the keyboard's data)
+///   and of the RNG, which moves to A3 (see `RNG_CHANNEL`).
+const I2C_BUS: bool = false;
+/// Whether a DS1307 or DS3231 real-time clock is on the I2C bus (enabling the bus), which
+///   the clock app shows and sets, and which dates the high scores.
+const REAL_TIME_CLOCK: bool = false;
+/// The model of the accelerometer on the I2C bus (enabling the bus), if there is one, whose tilt
+///   stands in for the JoyStick (e.g. `Some(AccelerometerModel::Adxl345)`), see `Accelerometer`.
+///   The MPU6050 shares its I2C address with the real-time clock, see `REAL_TIME_CLOCK`.
+const ACCELEROMETER: Option<crate::peripherals::AccelerometerModel> = None;
+/// Whether the I2C bus is enabled, by itself or by any device on it.
+const I2C_ENABLED: bool = I2C_BUS | OLED_DISPLAY | REAL_TIME_CLOCK | ACCELEROMETER.is_some();
+/// The ADC channel of the floating analog pin from which the RNG reads: A5, or A3 while
+///   the I2C bus takes A5 (see `I2C_BUS`).
+const RNG_CHANNEL: u8 = if I2C_ENABLED { 3 } else { 5 };
+/// Whether an HC-SR04 ultrasonic distance sensor, with its trigger on D8 and its echo on D12,
+///   is read by the games which play by the height of a hand (see `Ultrasonic`). Its trigger
+///   takes over the pin of the scoreboard, see `SCOREBOARD_DISPLAY`.
+const ULTRASONIC_SENSOR: bool = false;
+/// Whether a capacitive touch button (a pad of foil, e.g. behind the wall of an enclosed case) is a
+///   JoyStick button of its own, with its send pin on D8 and its pad on D12, see `TouchButton`.
+///   The scoreboard and the ultrasonic sensor take these pins first, leaving no touch button.
+const TOUCH_BUTTON: bool = false;
+/// The time the crash screen is shown, after recovering from a crash.
+const CRASH_SCREEN_MS: u16 = 1500;
+
+/// The screen shown after recovering from a crash (a face with crossed out eyes).
+static CRASH_SCREEN: crate::peripherals::DotScreen =
+    crate::peripherals::DotScreen::new(
+        [
+            0b01010000,
+            0b00100010,
+            0b01010100,
+            0b00000100,
+            0b00000100,
+            0b01010100,
+            0b00100010,
+            0b01010000,
+        ]
+    );
+
+/// The analog pin of the potentiometer paddle (see `PADDLE`).
+type PaddlePin = arduino_uno::hal::port::portc::PC3<arduino_uno::hal::port::mode::Analog>;
+
+/// The analog pin of the keypad (see `LADDER_KEYPAD`).
+type KeypadPin = arduino_uno::hal::port::portc::PC3<arduino_uno::hal::port::mode::Analog>;
+
+/// The type of the serial connection.
+pub type SerialPort = arduino_uno::Serial<arduino_uno::hal::port::mode::Floating>;
 
 
 pub struct AnalogDevices {
     /// ADC used to read analog input values.
     adc: arduino_uno::adc::Adc,
-    /// The JoyStick peripheral.
-    joystick: crate::peripherals::InputPeripheral<crate::peripherals::JoyStick>,
+    /// The JoyStick peripheral, along with the remote control, the virtual JoyStick, the keyboard,
+    ///   the push-button, the keypad, the accelerometer, the gamepad, and the touch button, if there are any.
+    joystick: crate::peripherals::InputPeripheral<crate::peripherals::Paired<
+        crate::peripherals::Paired<
+            crate::peripherals::Paired<
+                crate::peripherals::Paired<
+                    crate::peripherals::Paired<
+                        crate::peripherals::Paired<
+                            crate::peripherals::Paired<
+                                crate::peripherals::Paired<crate::peripherals::JoyStick1, crate::peripherals::IrRemote>,
+                                crate::peripherals::SerialJoystick,
+                            >,
+                            crate::peripherals::Ps2Keyboard,
+                        >,
+                        crate::peripherals::Button,
+                    >,
+                    crate::peripherals::LadderKeypad<KeypadPin>,
+                >,
+                crate::peripherals::Accelerometer,
+            >,
+            crate::peripherals::ShiftRegisters,
+        >,
+        crate::peripherals::TouchButton,
+    >>,
+    /// The JoyStick peripheral of the second player, or the paddle in its place, if there is either.
+    joystick2: crate::peripherals::InputPeripheral<Option<
+        crate::peripherals::Either<crate::peripherals::JoyStick2, crate::peripherals::Potentiometer<PaddlePin>>
+    >>,
+    /// The ultrasonic distance sensor, if there is one.
+    ultrasonic: Option<crate::peripherals::Ultrasonic>,
      /// Random number generator.
     rng: crate::peripherals::XOrShiftPrng,
+    /// The time, in milliseconds, the JoySticks have been polled without any input.
+    idle_ms: u32,
+    /// Schedules the game ticks polled by the games.
+    ticker: crate::peripherals::clock::Ticker,
+    /// The input events of the latest poll of the JoyStick.
+    events: crate::peripherals::InputEvents,
+    /// The input events of the latest poll of the JoyStick of the second player.
+    events2: crate::peripherals::InputEvents,
 }
 
 impl AnalogDevices {
@@ -17,7 +172,10 @@ impl AnalogDevices {
     /// 
     /// This simplifies the user interface, removing the need to handle the ADC.
     pub fn poll_joystick(&mut self, duration_ms: usize) -> &crate::peripherals::PollArray {
-        self.joystick.poll(&mut self.adc, duration_ms)
+        let polled = self.joystick.poll(&mut self.adc, duration_ms);
+        self.events.track_poll(polled);
+        self.idle_ms = if polled.is_empty() { self.idle_ms.saturating_add(duration_ms as u32) } else { 0 };
+        polled
     }
 
     /// Pass through function to the [InputPeripheral.poll](peripherals/struct.InputPeripheral.html#method.poll_until_any)
@@ -25,23 +183,109 @@ impl AnalogDevices {
     /// 
     /// This simplifies the user interface, removing the need to handle the ADC.
     pub fn poll_joystick_until_any(&mut self) -> crate::peripherals::InputSignal {
-        self.joystick.poll_until_any(&mut self.adc)
+        self.idle_ms = 0;
+        let signal = self.joystick.poll_until_any(&mut self.adc);
+        self.events.clear();
+        self.events.track(Some(signal.to_joystick()));
+        signal
+    }
+
+    /// Seed the random number generator, see [XOrShiftPrng.seed](peripherals/struct.XOrShiftPrng.html#method.seed).
+    pub fn seed_rng(&mut self, seed: Option<u16>) {
+        self.rng.seed(seed);
+    }
+
+    /// Inject a key into the JoyStick, as though it were typed on the virtual JoyStick
+    ///   (see `SerialJoystick`), to be received by the next poll.
+    pub fn inject_key(&mut self, key: crate::peripherals::Key) {
+        self.joystick.inject(crate::peripherals::InputSignal::Serial(key));
+    }
+
+    /// Returns whether there is a JoyStick for the second player (or the paddle in its place).
+    ///   Without one, polling it never has any input.
+    pub fn has_joystick2(&self) -> bool {
+        ((SECOND_JOYSTICK && !PS2_KEYBOARD) | PADDLE) && !I2C_ENABLED && !LADDER_KEYPAD
+    }
+
+    /// Pass through function to the [InputPeripheral.poll](peripherals/struct.InputPeripheral.html#method.poll)
+    ///   method for the JoyStick of the second player.
+    /// 
+    /// This simplifies the user interface, removing the need to handle the ADC.
+    pub fn poll_joystick2(&mut self, duration_ms: usize) -> &crate::peripherals::PollArray {
+        let polled = self.joystick2.poll(&mut self.adc, duration_ms);
+        self.events2.track_poll(polled);
+        self.idle_ms = if polled.is_empty() { self.idle_ms.saturating_add(duration_ms as u32) } else { 0 };
+        polled
+    }
+
+    /// Pass through function to the [InputPeripheral.poll_with](peripherals/struct.InputPeripheral.html#method.poll_with)
+    ///   method, polling the JoySticks of both players over the same period of time.
+    /// 
+    /// This simplifies the user interface, removing the need to handle the ADC.
+    pub fn poll_joysticks(
+        &mut self, duration_ms: usize
+    ) -> (&crate::peripherals::PollArray, &crate::peripherals::PollArray) {
+        let (first, second) = self.joystick.poll_with(&mut self.joystick2, &mut self.adc, duration_ms);
+        self.events.track_poll(first);
+        self.events2.track_poll(second);
+        let is_idle = first.is_empty() & second.is_empty();
+        self.idle_ms = if is_idle { self.idle_ms.saturating_add(duration_ms as u32) } else { 0 };
+        (first, second)
+    }
+
+    /// Returns whether there is an ultrasonic distance sensor.
+    pub fn has_ultrasonic(&self) -> bool {
+        self.ultrasonic.is_some()
+    }
+
+    /// Pass through function to the [Ultrasonic.measure](peripherals/struct.Ultrasonic.html#method.measure)
+    ///   method, measuring the distance to the nearest object (e.g. a hand), in millimeters.
+    ///
+    /// # Returns
+    /// The distance, or None if nothing is in range (or there is no ultrasonic sensor).
+    pub fn measure_distance(&mut self) -> Option<u16> {
+        self.ultrasonic.as_mut().and_then(|sensor| sensor.measure())
+    }
+
+    /// Returns the input events of the latest poll of the JoyStick
+    ///   (see [InputEvents](peripherals/struct.InputEvents.html)).
+    pub fn events(&mut self) -> &mut crate::peripherals::InputEvents {
+        &mut self.events
+    }
+
+    /// Returns the input events of the latest poll of the JoyStick of the second player.
+    pub fn events2(&mut self) -> &mut crate::peripherals::InputEvents {
+        &mut self.events2
+    }
+
+    /// Returns the time, in milliseconds, the JoySticks have been polled without any input.
+    pub fn idle_ms(&self) -> u32 {
+        self.idle_ms
+    }
+
+    /// Schedule the next game tick, see [Ticker.schedule](peripherals/clock/struct.Ticker.html#method.schedule).
+    ///
+    /// Every game tick feeds the watchdog.
+    ///
+    /// # Returns
+    /// The time remaining, in milliseconds, until the tick ends.
+    pub fn schedule_tick(&mut self, interval_ms: usize) -> usize {
+        crate::peripherals::watchdog::feed();
+        self.ticker.schedule(interval_ms)
     }
 }
 
 /// Implement a RngCore as a pass through to the rng attribute.
-/// 
-/// This simplifies the user interface, removing the need to handle the ADC.
 impl rand_core::RngCore for AnalogDevices {
 
     /// Returns a pseudo-randomly generated u32 number.
     fn next_u32(&mut self) -> u32 {
-        self.rng.generate(&mut self.adc) as u32
+        self.rng.generate() as u32
     }
 
     /// Returns a pseudo-randomly generated u64 number.
     fn next_u64(&mut self) -> u64 {
-        self.rng.generate(&mut self.adc) as u64
+        self.rng.generate() as u64
     }
 
     /// Fill `dest` with random data.
@@ -60,27 +304,317 @@ impl rand_core::RngCore for AnalogDevices {
 pub struct Components {
     /// The analog devices.
     pub analog: AnalogDevices,
-    /// The DotDisplay peripheral.
-    pub display: crate::peripherals::DotDisplay,
-    /// The serial connection. Used for debugging purposes.
-    pub serial: arduino_uno::Serial<arduino_uno::hal::port::mode::Floating>,
+    /// The Buzzer peripheral.
+    pub buzzer: crate::peripherals::Buzzer,
+    /// The outputs which feedback cues are allowed to fire.
+    pub cue_outputs: crate::cues::CueOutputs,
+    /// The display the games are played on: the DotDisplay peripheral, or the OLED or RGB matrix in its place.
+    pub display: crate::peripherals::GameDisplay,
+    /// The second DotDisplay peripheral, showing the score during game-play, if there is one.
+    pub scoreboard: Option<crate::peripherals::DotDisplay>,
+    /// The 7-segment display, showing the score during game-play, if there is one.
+    pub score_digits: Option<crate::peripherals::Tm1637>,
+    /// The I2C bus, shared by the devices on it (see [I2c.share](peripherals/struct.I2c.html#method.share)), if enabled.
+    pub i2c: Option<crate::peripherals::I2c>,
+    /// The leaderboard, to which scores are broadcast over the serial connection.
+    pub leaderboard: crate::Leaderboard,
+    /// The real-time clock, if there is one (and it responds).
+    pub rtc: Option<crate::peripherals::Rtc>,
+    /// The ambient light sensor, if there is one.
+    pub light_sensor: Option<crate::peripherals::LightSensor>,
+    /// The monitor of the supply voltage, for battery-powered builds.
+    pub battery: Option<crate::peripherals::power::BatteryMonitor>,
+    /// Plays haptic feedback on the Rumble (vibration motor) peripheral.
+    pub haptics: crate::peripherals::Haptics,
+    /// The command console, receiving over the serial connection.
+    pub console: crate::console::Console,
+    /// The serial connection. Used for debugging purposes, the console, and the leaderboard.
+    pub serial: SerialPort,
+    /// The settings which apply across every game.
+    pub settings: crate::Settings,
+    /// Plays music and sound effects alongside the game-play.
+    pub sound: crate::sound::SoundPlayer,
+}
+
+impl Components {
+
+    /// Apply the settings to the peripherals, e.g. after they have been changed.
+    ///
+    /// While the brightness setting is `Settings::AUTO_BRIGHTNESS`, the brightness follows
+    ///   the ambient light instead, see `adjust_brightness`.
+    pub fn apply_settings(&mut self) {
+        let level = match self.light_sensor.as_ref() {
+            Some(sensor) if self.settings.brightness == crate::Settings::AUTO_BRIGHTNESS => { sensor.brightness() },
+            _ => { self.settings.brightness },
+        };
+        self.display.set_brightness(level);
+        if let Some(scoreboard) = self.scoreboard.as_mut() { scoreboard.set_brightness(level) }
+        if let Some(score_digits) = self.score_digits.as_mut() { score_digits.set_brightness(level) }
+        self.cue_outputs.sound = self.settings.sound;
+    }
+
+    /// Adjust the brightness of the displays to the ambient light, if the brightness setting
+    ///   is `Settings::AUTO_BRIGHTNESS` and there is a light sensor. This is called regularly
+    ///   (e.g. every game tick), and reads the light sensor only every so often.
+    pub fn adjust_brightness(&mut self) {
+        if self.settings.brightness != crate::Settings::AUTO_BRIGHTNESS { return }
+        let changed = self.light_sensor.as_mut().map_or(false, |sensor| sensor.update());
+        if changed { self.apply_settings() }
+    }
+
+    /// Monitor the supply voltage for a low battery, if there is a battery monitor, showing
+    ///   (or hiding) the low battery indicator of the display. This is called regularly
+    ///   (e.g. every game tick), and measures the voltage only every so often.
+    pub fn monitor_battery(&mut self) {
+        if let Some(battery) = self.battery.as_mut() {
+            if battery.update() { self.display.set_low_battery(battery.is_low()) }
+        }
+    }
+
+    /// Advance the music and sound effects being played, once every game tick
+    ///   (see [SoundPlayer.tick](sound/struct.SoundPlayer.html#method.tick)).
+    ///
+    /// Nothing is played while sound is disabled in the settings.
+    pub fn tick_sound(&mut self) {
+        if !self.cue_outputs.sound { self.sound.stop() }
+        self.sound.tick(&mut self.buzzer);
+    }
+
+    /// Play a sound effect (see [SoundPlayer.play_effect](sound/struct.SoundPlayer.html#method.play_effect)),
+    ///   e.g. as returned by the sound hooks of a game. The effect begins immediately.
+    ///
+    /// Nothing is played for None, or while sound is disabled in the settings.
+    pub fn play_effect(&mut self, effect: Option<&'static crate::sound::Melody>) {
+        if let Some(melody) = effect {
+            self.sound.play_effect(melody);
+            self.tick_sound();
+        }
+    }
+
+    /// Run any commands received by the command console (see `console`), if it is enabled.
+    ///   This is called regularly (e.g. every game tick), and passes the handshake of the
+    ///   leaderboard on to it.
+    pub fn poll_console(&mut self) {
+        if !SERIAL_CONSOLE { return }
+        while let Some(byte) = crate::peripherals::usart::read_byte() {
+            if self.console.is_line_empty() && self.leaderboard.receive(&mut self.serial, byte) { continue }
+            if let Some(command) = self.console.receive(byte) {
+                crate::console::run(self, command);
+            }
+        }
+    }
+
+    /// Play a haptic pattern on the vibration motor (see [Haptics.play](peripherals/struct.Haptics.html#method.play)),
+    ///   e.g. alongside the sound of a game event. The pattern plays by itself, without blocking.
+    ///
+    /// Nothing is played while the rumble output of the cues is disabled.
+    pub fn play_haptic(&mut self, pattern: &'static crate::peripherals::HapticPattern) {
+        if self.cue_outputs.rumble { self.haptics.play(pattern) }
+    }
+
+    /// Go to sleep to save power, if no JoyStick input has been received for a while.
+    ///
+    /// The display is shut down and the microcontroller put into its power-down sleep mode,
+    ///   until the JoyStick button is pressed. The display then shows what it showed before,
+    ///   and this returns once the button has been released (so the press does nothing else).
+    ///
+    /// # Returns
+    /// Whether it went to sleep.
+    pub fn sleep_if_idle(&mut self) -> bool {
+        const RELEASE_POLL_MS: usize = 50;
+        if self.analog.idle_ms() < IDLE_SLEEP_MS { return false }
+
+        self.sound.stop();
+        self.buzzer.stop();
+        self.haptics.stop();
+        self.display.shutdown(true);
+        if let Some(scoreboard) = self.scoreboard.as_mut() { scoreboard.shutdown(true) }
+        if let Some(score_digits) = self.score_digits.as_mut() { score_digits.shutdown(true) }
+        // The watchdog keeps running while asleep, so must not reset the microcontroller meanwhile.
+        crate::peripherals::watchdog::disable();
+        crate::peripherals::sleep::power_down_until_button();
+        crate::peripherals::watchdog::enable();
+        self.display.shutdown(false);
+        if let Some(scoreboard) = self.scoreboard.as_mut() { scoreboard.shutdown(false) }
+        if let Some(score_digits) = self.score_digits.as_mut() { score_digits.shutdown(false) }
+        while !self.analog.poll_joystick(RELEASE_POLL_MS).is_empty() {}
+        true
+    }
+
+    /// Report the score of a finished game to the leaderboard, if a host is listening.
+    /// 
+    /// The score is also recorded into the statistics of the game, and as its high score
+    ///   if it beats the stored one, in which case the new high score is celebrated and announced before this returns.
+    /// 
+    /// # Arguments
+    /// * game_id - The identifier of the game, a letter (usually the letter of its title screen).
+    /// * score   - The final score.
+    pub fn report_score(&mut self, game_id: u8, score: usize) {
+        self.report_score_at(game_id, score, 0);
+    }
+
+    /// Report the score of a finished game, played at a difficulty level, to the leaderboard.
+    /// 
+    /// # Arguments
+    /// * game_id    - The identifier of the game, a letter (usually the letter of its title screen).
+    /// * score      - The final score.
+    /// * difficulty - The difficulty level the game was played at.
+    pub fn report_score_at(&mut self, game_id: u8, score: usize, difficulty: u8) {
+        crate::stats::record(game_id, score as u16);
+
+        let mut initials = crate::leaderboard::NO_INITIALS;
+        let date = self.rtc.as_mut().and_then(|rtc| rtc.now().ok()).map(|now| now.date);
+        if crate::high_scores::record(game_id, score as u16, date) {
+            const SCROLL_COLUMN_MS: usize = 70;
+            let screen = &crate::high_scores::NEW_HIGH_SCORE_SCREEN;
+            for _ in 0..3 {
+                crate::cues::play(self, &crate::cues::POINT, screen);
+                arduino_uno::delay_ms(200);
+            }
+
+            // Announce the new high score, unless the player skips it with any input.
+            let font = &crate::graphics::SmallFont;
+            let mut buffer = crate::graphics::NumberBuffer::default();
+            let score = crate::graphics::format_number(score as u32, &mut buffer);
+            if !crate::graphics::scroll(self, font, "NEW HIGH SCORE", SCROLL_COLUMN_MS) {
+                crate::graphics::scroll(self, font, score, SCROLL_COLUMN_MS);
+            }
+            if PS2_KEYBOARD { initials = self.enter_initials() }
+        }
+
+        let report = crate::ScoreReport { game_id, score: score as u16, difficulty, initials };
+        self.leaderboard.report(&mut self.serial, &report);
+    }
+
+    /// Let the player type their initials on the keyboard (up to three letters or digits),
+    ///   showing the latest two characters typed, with a dot along the bottom row for each.
+    ///
+    /// Enter accepts the initials, Backspace erases the latest character, and Escape
+    ///   (or a while without typing) skips them.
+    ///
+    /// # Returns
+    /// The initials, padded with spaces, or `NO_INITIALS` if skipped.
+    fn enter_initials(&mut self) -> [u8; 3] {
+        use crate::peripherals::ps2_keyboard::{self, BACKSPACE, ENTER, ESCAPE};
+        const SCROLL_COLUMN_MS: usize = 70;
+        const POLL_MS: u16 = 20;
+        const IDLE_TIMEOUT_MS: u16 = 15_000;
+        const RELEASE_POLL_MS: usize = 50;
+
+        crate::graphics::scroll(self, &crate::graphics::SmallFont, "INITIALS", SCROLL_COLUMN_MS);
+        ps2_keyboard::clear_typed();
+        let mut initials = [b' '; 3];
+        let mut length: usize = 0;
+        let mut idle_ms = 0;
+        let entered = loop {
+            crate::peripherals::watchdog::feed();
+            match ps2_keyboard::take_char() {
+                Some(ENTER) if length > 0 => { break true },
+                Some(ESCAPE) => { break false },
+                Some(BACKSPACE) => { length = length.saturating_sub(1) },
+                Some(character) if character.is_ascii_alphanumeric() && (length < initials.len()) => {
+                    initials[length] = character;
+                    length += 1;
+                },
+                Some(_) => {},
+                None => {
+                    arduino_uno::delay_ms(POLL_MS);
+                    idle_ms += POLL_MS;
+                    if idle_ms >= IDLE_TIMEOUT_MS { break false }
+                    continue
+                },
+            }
+            idle_ms = 0;
+
+            let mut screen = crate::peripherals::DotScreen::new_empty();
+            let latest = core::str::from_utf8(&initials[length.saturating_sub(2)..length]).unwrap_or("");
+            crate::graphics::draw_text(&mut screen, &crate::graphics::SmallFont, latest, 0);
+            for x in 0..length { screen.add(&crate::peripherals::Dot { x, y: 0 }) }
+            self.display.show(&screen);
+        };
+        // Don't let the keys still held (e.g. Enter, which is also Select) reach the game.
+        while !self.analog.poll_joystick(RELEASE_POLL_MS).is_empty() {}
+        if entered { initials } else { crate::leaderboard::NO_INITIALS }
+    }
 }
 
 
 /// Construct the `crate::Components` object.
 pub fn get_components() -> Components {
+    // The Watchdog Timer may still be enabled, if it was used to reset the microcontroller.
+    let crashed = crate::peripherals::watchdog::take_crash();
+    crate::peripherals::watchdog::disable();
+
     // Grab the peripheral pins.
     let dp = arduino_uno::Peripherals::take().unwrap();
 
     // Collect all the available pins.
     let mut pins = arduino_uno::Pins::new(dp.PORTB, dp.PORTC, dp.PORTD);
 
-    // Create the peripheral components.
-    let display = crate::peripherals::DotDisplay::new(
-        pins.d10.into_output(&mut pins.ddr).downgrade(),
-        pins.d13.into_output(&mut pins.ddr).downgrade(),
-        pins.d11.into_output(&mut pins.ddr).downgrade(),
-    );
+    // Construct the I2C bus, if it is used (by the OLED, or any other device), on A4 and A5.
+    //   Otherwise, A4 is left to the second JoyStick (or the keyboard).
+    let (i2c, a4) = if I2C_ENABLED {
+        (Some(crate::peripherals::I2c::new(dp.TWI, pins.a4, pins.a5)), None)
+    } else {
+        (None, Some(pins.a4))
+    };
+
+    let rtc = i2c.as_ref()
+        .filter(|_| REAL_TIME_CLOCK)
+        .and_then(|bus| crate::peripherals::Rtc::new(bus.share()).ok());
+
+    // Create the peripheral components. The OLED, if it is used and responds, takes the place of the DotDisplay.
+    let oled = i2c.as_ref()
+        .filter(|_| OLED_DISPLAY)
+        .and_then(|bus| crate::peripherals::Ssd1306::new(bus.share()).ok());
+    // D6 is the data line of the RGB matrix, or else the data of the shift registers.
+    let (rgb_matrix_pin, d6) = if RGB_MATRIX_DISPLAY { (Some(pins.d6), None) } else { (None, Some(pins.d6)) };
+    let display = if let Some(oled) = oled {
+        crate::peripherals::GameDisplay::Ssd1306(oled)
+    } else if let Some(pin) = rgb_matrix_pin {
+        crate::peripherals::GameDisplay::Ws2812(crate::peripherals::Ws2812::new(pin.into_output(&mut pins.ddr)))
+    } else if HARDWARE_SPI_DISPLAY {
+        crate::peripherals::GameDisplay::Max7219(crate::peripherals::DotDisplay::new_hardware_spi_chain(
+            dp.SPI,
+            pins.d10.into_output(&mut pins.ddr).downgrade(),
+            pins.d13.into_output(&mut pins.ddr),
+            pins.d11.into_output(&mut pins.ddr),
+            DISPLAY_PANELS,
+        ).with_orientation(DISPLAY_ORIENTATION))
+    } else {
+        crate::peripherals::GameDisplay::Max7219(crate::peripherals::DotDisplay::new_chain(
+            pins.d10.into_output(&mut pins.ddr).downgrade(),
+            pins.d13.into_output(&mut pins.ddr).downgrade(),
+            pins.d11.into_output(&mut pins.ddr).downgrade(),
+            DISPLAY_PANELS,
+        ).with_orientation(DISPLAY_ORIENTATION))
+    };
+
+    // D8 is the chip select of the scoreboard, or else the trigger of the ultrasonic sensor (or the touch button).
+    let mut d8 = Some(pins.d8);
+    let scoreboard = match &display {
+        crate::peripherals::GameDisplay::Max7219(display) if SCOREBOARD_DISPLAY => {
+            match d8.take() {
+                Some(pin) => { display.share_spi(pin.into_output(&mut pins.ddr).downgrade()) },
+                None => { None },
+            }
+        },
+        _ => { None },
+    };
+
+    // D4 and D5 are the pins of the score digits, or else the latch and clock of the shift registers.
+    let (score_digits, d4_d5) = if SCORE_DIGITS_DISPLAY {
+        (Some(crate::peripherals::Tm1637::new(pins.d4, pins.d5)), None)
+    } else {
+        (None, Some((pins.d4, pins.d5)))
+    };
+
+    // Construct the Buzzer peripheral.
+    let buzzer = crate::peripherals::Buzzer::new(dp.TC1, pins.d9.into_output(&mut pins.ddr));
+
+    // Construct the Rumble peripheral, and the Haptics which play on it.
+    let rumble = crate::peripherals::Rumble::new(pins.d3.into_output(&mut pins.ddr).downgrade());
+    let haptics = crate::peripherals::Haptics::new(dp.TC2, rumble);
 
     // Construct a Serial object (used for debugging purposes).
     let serial = {
@@ -88,6 +622,8 @@ pub fn get_components() -> Components {
         let tx = pins.d1.into_output(&mut pins.ddr);
         arduino_uno::Serial::new(dp.USART0, rx, tx, BAUD_RATE)
     };
+    // The bytes received are taken by the USART_RX interrupt, to be read with `usart::read_byte`.
+    crate::peripherals::usart::start_receiving();
 
     // Construct the ADC.
     let mut adc = {
@@ -95,23 +631,156 @@ pub fn get_components() -> Components {
         arduino_uno::adc::Adc::new(dp.ADC, settings)
     };
 
-    // Construct the JoyStick peripheral.
+    // Construct the keypad, if there is one. A3 is otherwise left to the second JoyStick (or the paddle),
+    //   unless the I2C bus is enabled (A3 then floats for the RNG).
+    let (keypad, a3) = match a4 {
+        Some(_) if LADDER_KEYPAD => {
+            let pin = pins.a3.into_analog_input(&mut adc);
+            (Some(crate::peripherals::LadderKeypad::new(pin, crate::peripherals::DEFAULT_LADDER_THRESHOLDS)), None)
+        },
+        Some(_) => { (None, Some(pins.a3)) },
+        None => { (None, None) },
+    };
+
+    // Construct the JoyStick peripheral of the second player, or the paddle in its place, if there is either.
+    //   Otherwise, the keyboard may take the pins of the second JoyStick. None of them are left
+    //   any pins by the I2C bus.
+    let (joystick2, keyboard) = match (a4, a3) {
+        (Some(_), Some(a3)) if PADDLE => {
+            let pin = a3.into_analog_input(&mut adc);
+            (Some(crate::peripherals::Either::Second(crate::peripherals::Potentiometer::new(pin))), None)
+        },
+        (Some(a4), Some(a3)) if SECOND_JOYSTICK && !PS2_KEYBOARD => {
+            let x_axis = a3.into_analog_input(&mut adc);
+            let y_axis = a4.into_analog_input(&mut adc);
+            let z_axis = pins.d2.into_floating_input(&mut pins.ddr).downgrade();
+            (Some(crate::peripherals::Either::First(crate::peripherals::JoyStick::new(x_axis, y_axis, z_axis))), None)
+        },
+        (Some(a4), _) if PS2_KEYBOARD => {
+            let clock = pins.d2.into_pull_up_input(&mut pins.ddr);
+            let data = a4.into_pull_up_input(&mut pins.ddr);
+            (None, Some(crate::peripherals::Ps2Keyboard::new(clock, data)))
+        },
+        _ => { (None, None) },
+    };
+    let joystick2 = crate::peripherals::InputPeripheral::new(joystick2);
+
+    // Construct the ultrasonic distance sensor, or else the touch button, if there is either (and D8 is free).
+    //   D8 and D12 are the trigger and echo of the sensor, or the send pin and pad of the touch button.
+    let (ultrasonic, touch_button) = match d8 {
+        Some(trigger) if ULTRASONIC_SENSOR => {
+            let echo = pins.d12.into_floating_input(&mut pins.ddr).downgrade();
+            (Some(crate::peripherals::Ultrasonic::new(trigger.into_output(&mut pins.ddr).downgrade(), echo)), None)
+        },
+        Some(send) if TOUCH_BUTTON => {
+            let pad = pins.d12.into_floating_input(&mut pins.ddr).downgrade();
+            (None, Some(crate::peripherals::TouchButton::new(send.into_output(&mut pins.ddr).downgrade(), pad)))
+        },
+        _ => { (None, None) },
+    };
+
+    // Construct the JoyStick peripheral, along with the remote control, the virtual JoyStick, the keyboard,
+    //   the push-button, the keypad, the accelerometer (if it responds), the gamepad, and the touch button
+    //   if there are any.
     let joystick = {
         let x_axis = pins.a0.into_analog_input(&mut adc);
         let y_axis = pins.a1.into_analog_input(&mut adc);
         let z_axis = pins.a2.into_floating_input(&mut pins.ddr).downgrade();
-        crate::peripherals::InputPeripheral::new(
-            crate::peripherals::JoyStick::new(x_axis, y_axis, z_axis)
-        )
+        // D7 is the IR receiver, or else the push-button.
+        let (remote, button) = if IR_REMOTE {
+            let pin = pins.d7.into_floating_input(&mut pins.ddr);
+            (Some(crate::peripherals::IrRemote::new(pin, crate::peripherals::DEFAULT_REMOTE_KEYMAP)), None)
+        } else if PUSH_BUTTON {
+            let pin = pins.d7.into_pull_up_input(&mut pins.ddr).downgrade();
+            (None, Some(crate::peripherals::Button::new(pin)))
+        } else {
+            (None, None)
+        };
+        let serial_joystick = if SERIAL_JOYSTICK { Some(crate::peripherals::SerialJoystick::new()) } else { None };
+        let accelerometer = ACCELEROMETER.and_then(|model| {
+            i2c.as_ref().and_then(|bus| crate::peripherals::Accelerometer::new(bus.share(), model).ok())
+        });
+        let gamepad = match (d4_d5, d6) {
+            (Some((d4, d5)), Some(d6)) if SHIFT_REGISTERS > 0 => {
+                let latch = d4.into_output(&mut pins.ddr).downgrade();
+                let clock = d5.into_output(&mut pins.ddr).downgrade();
+                let data = d6.into_floating_input(&mut pins.ddr).downgrade();
+                let keymap = &crate::peripherals::DEFAULT_GAMEPAD_KEYMAP;
+                Some(crate::peripherals::ShiftRegisters::new(latch, clock, data, SHIFT_REGISTERS, keymap))
+            },
+            _ => { None },
+        };
+        crate::peripherals::InputPeripheral::new(crate::peripherals::Paired::new(
+            crate::peripherals::Paired::new(
+                crate::peripherals::Paired::new(
+                    crate::peripherals::Paired::new(
+                        crate::peripherals::Paired::new(
+                            crate::peripherals::Paired::new(
+                                crate::peripherals::Paired::new(
+                                    crate::peripherals::Paired::new(crate::peripherals::JoyStick::new(x_axis, y_axis, z_axis), remote),
+                                    serial_joystick,
+                                ),
+                                keyboard,
+                            ),
+                            button,
+                        ),
+                        keypad,
+                    ),
+                    accelerometer,
+                ),
+                gamepad,
+            ),
+            touch_button,
+        ))
     };
 
-    // Construct the RNG.
-    let rng = {
-        let pin = pins.a5.into_analog_input(&mut adc);
-        crate::peripherals::XOrShiftPrng::new(pin, &mut adc)
-    };
+    // Construct the RNG, reading its floating pin by its channel (so that, unlike an analog input,
+    //   the pin keeps its digital input, which the I2C bus needs on A5).
+    let rng = crate::peripherals::XOrShiftPrng::new(RNG_CHANNEL);
     
-    let analog = AnalogDevices { adc, joystick, rng };
+    let ticker = crate::peripherals::clock::Ticker::new();
+    let events = crate::peripherals::InputEvents::new();
+    let events2 = crate::peripherals::InputEvents::new();
+    let analog = AnalogDevices { adc, joystick, joystick2, ultrasonic, rng, idle_ms: 0, ticker, events, events2 };
+
+    // Start the millisecond clock.
+    crate::peripherals::clock::start(dp.TC0);
+
+    // Enable interrupts globally, which are required by the Buzzer and the clock.
+    unsafe { avr_device::interrupt::enable() };
+
+    let leaderboard = crate::Leaderboard::new();
+
+    // Construct the ambient light sensor, once the clock (which times its readings) has started.
+    //   A4 and A5 are left to the I2C bus, if it is enabled.
+    let light_sensor = LIGHT_SENSOR_CHANNEL
+        .filter(|&channel| !(I2C_ENABLED && (channel == 4 || channel == 5)))
+        .map(crate::peripherals::LightSensor::new);
+    let battery = LOW_BATTERY_MV.map(crate::peripherals::power::BatteryMonitor::new);
+
+    let cue_outputs = crate::cues::CueOutputs::default();
+
+    // Load the settings stored in the EEPROM.
+    let settings = crate::Settings::load();
+
+    let sound = crate::sound::SoundPlayer::new();
+    let console = crate::console::Console::new();
+    let mut components = Components {
+        analog, battery, buzzer, console, cue_outputs, display, haptics, i2c, leaderboard, light_sensor,
+        rtc, score_digits, scoreboard, serial, settings, sound,
+    };
+    components.apply_settings();
+    if let Some(battery) = components.battery.as_ref() { components.display.set_low_battery(battery.is_low()) }
+    components.display.set_serial_mirror(SERIAL_SCREEN_MIRROR);
+
+    // If the watchdog reset the microcontroller, show that it crashed before returning
+    //   to the selection screen.
+    if crashed {
+        components.display.show(&CRASH_SCREEN);
+        arduino_uno::delay_ms(CRASH_SCREEN_MS);
+    }
 
-    Components { analog, display, serial }
+    // From now on, the watchdog recovers from any crash.
+    crate::peripherals::watchdog::enable();
+    components
 }
@@ -0,0 +1,215 @@
+/// An interactive command console over the serial connection, for development.
+///
+/// The protocol is line based ASCII, over the serial connection (see `BAUD_RATE`): each
+///   command is a line (ending in a carriage return or a line feed), answered by the lines
+///   of its output, ending with `OK` (or `ERR <reason>`). The commands are:
+///   * `help`           - List the commands.
+///   * `brightness <n>` - Set (and save) the brightness setting, from 0 to 8 (8 being automatic).
+///   * `play <n>`       - Switch to the game at index n of the selection screen (from 0).
+///   * `input <key>`    - Inject a key into the JoyStick: `w`, `a`, `s`, `d`, or `space`.
+///   * `eeprom`         - Dump the contents of the EEPROM, as hex, 16 bytes per line.
+///   * `ram`            - Print the free RAM (between the heap and the stack), in bytes.
+///   * `vcc`            - Print the supply voltage (see `power::vcc_millivolts`), in millivolts.
+///   * `record <seed>`  - Record the input of the next game selected (see `games::arm_replay`).
+///   * `replay <seed>`  - Play back recorded input in the next game selected.
+///
+/// The console is polled every game tick of any game or menu (see `Components::poll_console`,
+///   called by `utils::poll_tick`), so a command runs once the current tick is over. The handshake of the leaderboard (see
+///   `Leaderboard`), at the start of a line, is passed on to the leaderboard.
+use arduino_uno::prelude::*;
+use crate::{components::SerialPort, Components, Settings};
+use crate::games::{arm_replay, ReplayKind};
+use crate::peripherals::{eeprom, power, Key};
+
+/// The number of bytes of the longest command line. Longer lines are rejected.
+const LINE_CAPACITY: usize = 24;
+/// The number of bytes of the EEPROM dumped on each line.
+const DUMP_BYTES_PER_LINE: usize = 16;
+
+
+/// A command of the console.
+#[derive(Copy, Clone)]
+pub enum Command {
+    /// List the commands.
+    Help,
+    /// Set the brightness setting.
+    Brightness(u8),
+    /// Switch to the game at an index of the selection screen.
+    Play(usize),
+    /// Inject a key into the JoyStick.
+    Input(Key),
+    /// Dump the contents of the EEPROM.
+    Eeprom,
+    /// Print the free RAM.
+    Ram,
+    /// Print the supply voltage.
+    Vcc,
+    /// Arm a replay of the next game selected, with the seed of the random number generator.
+    Replay(ReplayKind, u16),
+}
+
+impl Command {
+
+    /// Parse a command line.
+    ///
+    /// # Returns
+    /// The command, or the reason the line isn't a valid command.
+    fn parse(line: &[u8]) -> Result<Self, &'static str> {
+        let line = core::str::from_utf8(line).map_err(|_| "not ascii")?;
+        let mut words = line.split(' ').filter(|word| !word.is_empty());
+        let name = words.next().unwrap_or("");
+        let argument = words.next();
+        if words.next().is_some() { return Err("too many arguments") }
+        let number = || argument.and_then(|argument| argument.parse::<u16>().ok()).ok_or("expected a number");
+        match name {
+            "help" => { Ok(Command::Help) },
+            "brightness" => {
+                let level = number()?;
+                if level > Settings::AUTO_BRIGHTNESS as u16 { return Err("no such level") }
+                Ok(Command::Brightness(level as u8))
+            },
+            "play" => { Ok(Command::Play(number()? as usize)) },
+            "input" => {
+                let key = match argument.ok_or("expected a key")? {
+                    "w" => { Key::Up },
+                    "a" => { Key::Left },
+                    "s" => { Key::Down },
+                    "d" => { Key::Right },
+                    "space" => { Key::Select },
+                    _ => { return Err("no such key") },
+                };
+                Ok(Command::Input(key))
+            },
+            "eeprom" => { Ok(Command::Eeprom) },
+            "ram" => { Ok(Command::Ram) },
+            "vcc" => { Ok(Command::Vcc) },
+            "record" => { Ok(Command::Replay(ReplayKind::Record, number()?)) },
+            "replay" => { Ok(Command::Replay(ReplayKind::Play, number()?)) },
+            _ => { Err("unknown command") },
+        }
+    }
+}
+
+
+/// Collects the bytes received on the serial connection into command lines.
+pub struct Console {
+    /// The command line received so far.
+    line: [u8; LINE_CAPACITY],
+    /// The number of bytes of the command line received so far.
+    length: usize,
+    /// Whether the command line has outgrown the capacity, and is to be rejected.
+    overflowed: bool,
+}
+
+impl Console {
+
+    /// Create a new Console object, with nothing received.
+    pub const fn new() -> Self {
+        Self { line: [0; LINE_CAPACITY], length: 0, overflowed: false }
+    }
+
+    /// Returns whether no part of a command line has been received.
+    pub fn is_line_empty(&self) -> bool {
+        self.length == 0 && !self.overflowed
+    }
+
+    /// Process a byte received on the serial connection.
+    ///
+    /// # Returns
+    /// The command (or the reason it is invalid), once its line is complete.
+    ///   Empty lines are ignored.
+    pub fn receive(&mut self, byte: u8) -> Option<Result<Command, &'static str>> {
+        if (byte == b'\r') | (byte == b'\n') {
+            if self.is_line_empty() { return None }
+            let command = if self.overflowed { Err("line too long") } else { Command::parse(&self.line[..self.length]) };
+            self.length = 0;
+            self.overflowed = false;
+            return Some(command)
+        }
+        if self.length == LINE_CAPACITY {
+            self.overflowed = true;
+        } else {
+            self.line[self.length] = byte;
+            self.length += 1;
+        }
+        None
+    }
+}
+
+
+/// Run a command of the console, writing its output to the serial connection.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * command    - The command (or the reason it is invalid).
+pub fn run(components: &mut Components, command: Result<Command, &'static str>) {
+    match command {
+        Ok(Command::Help) => {
+            ufmt::uwriteln!(&mut components.serial, "brightness <n>|play <n>|input <w|a|s|d|space>|eeprom|ram|vcc|record <seed>|replay <seed>\r")
+                .void_unwrap();
+        },
+        Ok(Command::Brightness(level)) => {
+            components.settings.brightness = level;
+            components.apply_settings();
+            components.settings.save();
+        },
+        Ok(Command::Play(index)) => {
+            // The game being played returns, then the game switched to runs.
+            if !crate::games::switch_to_game(index) { write_error(&mut components.serial, "no such game") }
+        },
+        Ok(Command::Input(key)) => { components.analog.inject_key(key) },
+        Ok(Command::Eeprom) => { dump_eeprom(&mut components.serial) },
+        Ok(Command::Ram) => {
+            ufmt::uwriteln!(&mut components.serial, "{}\r", free_ram() as u16).void_unwrap();
+        },
+        Ok(Command::Vcc) => {
+            ufmt::uwriteln!(&mut components.serial, "{}\r", power::vcc_millivolts()).void_unwrap();
+        },
+        Ok(Command::Replay(kind, seed)) => { arm_replay(kind, seed) },
+        Err(reason) => { return write_error(&mut components.serial, reason) },
+    }
+    ufmt::uwriteln!(&mut components.serial, "OK\r").void_unwrap();
+}
+
+/// Write the reason a command failed to the serial connection.
+fn write_error(serial: &mut SerialPort, reason: &str) {
+    ufmt::uwriteln!(serial, "ERR {}\r", reason).void_unwrap();
+}
+
+/// Write the contents of the EEPROM to the serial connection, as lines of the address
+///   followed by the bytes, in hex (e.g. `0000: 5E 01 01 ...`).
+fn dump_eeprom(serial: &mut SerialPort) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for address in (0..eeprom::SIZE).step_by(DUMP_BYTES_PER_LINE) {
+        let mut bytes = [0u8; DUMP_BYTES_PER_LINE];
+        eeprom::read_slice(address, &mut bytes);
+        let mut line = [b' '; 5 + 3 * DUMP_BYTES_PER_LINE];
+        for (index, shift) in [12, 8, 4, 0].iter().enumerate() {
+            line[index] = HEX[(address >> shift) & 0x0F];
+        }
+        line[4] = b':';
+        for (index, &byte) in bytes.iter().enumerate() {
+            line[6 + 3 * index] = HEX[(byte >> 4) as usize];
+            line[7 + 3 * index] = HEX[(byte & 0x0F) as usize];
+        }
+        let line = core::str::from_utf8(&line).unwrap_or("");
+        ufmt::uwriteln!(serial, "{}\r", line).void_unwrap();
+    }
+}
+
+/// Returns the free RAM, in bytes: the space between the end of the static data (where
+///   the heap would start) and the top of the stack.
+fn free_ram() -> usize {
+    #[cfg(target_arch = "avr")]
+    {
+        extern "C" {
+            // The end of the static data, defined by the linker script.
+            static __heap_start: u8;
+        }
+        let top_of_stack = 0u8;
+        let heap_start = unsafe { &__heap_start as *const u8 as usize };
+        (&top_of_stack as *const u8 as usize).saturating_sub(heap_start)
+    }
+    #[cfg(not(target_arch = "avr"))]
+    { 0 }
+}
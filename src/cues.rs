@@ -0,0 +1,105 @@
+/// Synchronized feedback cues, firing the buzzer, the rumble motor, and a screen flash together.
+///
+/// A cue is defined once, as a sequence of steps. Every output of a step starts at the same
+///   moment and lasts for the duration of the step, so that the sound, vibration, and flash
+///   of an event feel like a single response rather than three separate ones. Each output
+///   may be disabled in the settings (`CueOutputs`), in which case the remaining outputs
+///   keep the same timing.
+use crate::Components;
+use crate::peripherals::{DotScreen, Note};
+
+
+/// The outputs which cues are allowed to fire.
+#[derive(Copy, Clone)]
+pub struct CueOutputs {
+    /// Whether cues play their notes on the buzzer.
+    pub sound: bool,
+    /// Whether cues pulse the rumble motor.
+    pub rumble: bool,
+    /// Whether cues flash (invert) the screen.
+    pub flash: bool,
+}
+
+impl CueOutputs {
+
+    /// Every output enabled.
+    pub const ALL: Self = Self { sound: true, rumble: true, flash: true };
+}
+
+impl Default for CueOutputs {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+
+/// A single step of a cue.
+#[derive(Copy, Clone)]
+pub struct CueStep {
+    /// The frequency of the note played during the step, in Hertz. 0 is silence.
+    pub frequency_hz: u16,
+    /// Whether the rumble motor runs during the step.
+    pub rumble: bool,
+    /// Whether the screen is inverted during the step.
+    pub flash: bool,
+    /// The duration of the step in milliseconds.
+    pub duration_ms: u16,
+}
+
+impl CueStep {
+
+    /// Create a new CueStep.
+    pub const fn new(frequency_hz: u16, rumble: bool, flash: bool, duration_ms: u16) -> Self {
+        Self { frequency_hz, rumble, flash, duration_ms }
+    }
+}
+
+
+/// A feedback cue, played as a sequence of steps.
+pub type Cue = [CueStep];
+
+/// A short blip, for a point scored.
+pub static POINT: [CueStep; 1] = [CueStep::new(1319, false, true, 60)];
+/// A low thud, for the player being hit.
+pub static HIT: [CueStep; 2] = [
+    CueStep::new(131, true, true, 120),
+    CueStep::new(0, true, false, 60),
+];
+/// A short tick, for each count of the countdown to the start of a game.
+pub static COUNT: [CueStep; 1] = [CueStep::new(880, false, false, 80)];
+/// A long high note, for the start of a game ("GO").
+pub static GO: [CueStep; 1] = [CueStep::new(1760, true, false, 250)];
+/// A falling pair of notes, for the game being over.
+pub static GAME_OVER: [CueStep; 3] = [
+    CueStep::new(392, true, true, 150),
+    CueStep::new(0, false, false, 50),
+    CueStep::new(262, true, true, 300),
+];
+
+
+/// Play a cue, blocking until it is complete.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * cue        - The steps of the cue.
+/// * screen     - The screen currently being displayed, which is flashed and then restored.
+pub fn play(components: &mut Components, cue: &Cue, screen: &DotScreen) {
+    let outputs = components.cue_outputs;
+    let mut inverted = *screen;
+    for column in inverted.columns.iter_mut() {
+        *column = !*column;
+    }
+
+    for step in cue.iter() {
+        if outputs.sound {
+            // Cut off anything still playing, so the note starts with the other outputs.
+            components.buzzer.stop();
+            components.buzzer.play(Note::new(step.frequency_hz, step.duration_ms));
+        }
+        if outputs.rumble && step.rumble { components.haptics.start() } else { components.haptics.stop() }
+        components.display.show(if outputs.flash && step.flash { &inverted } else { screen });
+        arduino_uno::delay_ms(step.duration_ms);
+    }
+    components.haptics.stop();
+    components.display.show(screen);
+}
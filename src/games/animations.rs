@@ -0,0 +1,113 @@
+/// Short procedural animations, played by the attract loop of the selection screen.
+use crate::Components;
+use crate::peripherals::{Dot, DotScreen};
+
+
+/// A short animation, drawn procedurally frame by frame.
+pub struct Animation {
+    /// The number of frames in the animation.
+    pub frames: usize,
+    /// The time each frame is shown.
+    pub frame_ms: usize,
+    /// Draws the frame with the provided index onto an empty screen.
+    pub draw: fn(usize, &mut DotScreen),
+}
+
+impl Animation {
+
+    /// Play the animation on the display, stopping early if any input is received.
+    ///
+    /// # Arguments
+    /// * components - The peripheral components.
+    ///
+    /// # Returns
+    /// Whether the animation was interrupted by input.
+    pub fn play(&self, components: &mut Components) -> bool {
+        let mut screen = DotScreen::new_empty();
+        for frame in 0..self.frames {
+            screen.clear();
+            (self.draw)(frame, &mut screen);
+            components.display.show(&screen);
+            if !components.analog.poll_joystick(self.frame_ms).is_empty() { return true }
+        }
+        false
+    }
+}
+
+
+/// The animations of the attract loop, played in order.
+pub static REEL: [Animation; 3] = [
+    Animation { frames: 2 * BORDER_LENGTH, frame_ms: 70, draw: draw_snake_chase },
+    Animation { frames: 84, frame_ms: 80, draw: draw_bouncing_ball },
+    Animation { frames: 4 * RIPPLE_RINGS, frame_ms: 150, draw: draw_ripple },
+];
+
+
+/// Watermark a frame of a game demo with a blinking dot in the top right corner,
+///   so the demo is not mistaken for live play.
+///
+/// # Arguments
+/// * screen - The frame of the demo.
+/// * frame  - The index of the frame.
+pub fn watermark(screen: &mut DotScreen, frame: usize) {
+    let corner = Dot { x: DotScreen::WIDTH - 1, y: DotScreen::HEIGHT - 1 };
+    if frame % 2 == 0 { screen.add(&corner) } else { screen.remove(&corner) }
+}
+
+
+/// The number of dots around the border of the screen.
+const BORDER_LENGTH: usize = 2 * (DotScreen::WIDTH + DotScreen::HEIGHT) - 4;
+/// The length of the snake chasing its tail around the border.
+const SNAKE_LENGTH: usize = 10;
+/// The number of concentric rings of the ripple.
+const RIPPLE_RINGS: usize = DotScreen::WIDTH / 2;
+
+
+/// Returns the dot at the provided position around the border of the screen,
+///   travelling anti-clockwise from the bottom left corner.
+fn border_dot(position: usize) -> Dot {
+    let (right, top) = (DotScreen::WIDTH - 1, DotScreen::HEIGHT - 1);
+    let position = position % BORDER_LENGTH;
+    if position < right {
+        Dot { x: position, y: 0 }
+    } else if position < right + top {
+        Dot { x: right, y: position - right }
+    } else if position < 2 * right + top {
+        Dot { x: right - (position - right - top), y: top }
+    } else {
+        Dot { x: 0, y: top - (position - 2 * right - top) }
+    }
+}
+
+/// Returns the position of a triangle wave bouncing between 0 and `max`.
+fn bounce(time: usize, max: usize) -> usize {
+    let time = time % (2 * max);
+    if time <= max { time } else { 2 * max - time }
+}
+
+/// Draw a snake chasing its tail around the border of the screen.
+fn draw_snake_chase(frame: usize, screen: &mut DotScreen) {
+    for segment in 0..SNAKE_LENGTH {
+        screen.add(&border_dot(frame + segment));
+    }
+}
+
+/// Draw a ball bouncing off the edges of the screen.
+fn draw_bouncing_ball(frame: usize, screen: &mut DotScreen) {
+    let x = bounce(frame, DotScreen::WIDTH - 1);
+    let y = bounce(frame + 3, DotScreen::HEIGHT - 2);
+    screen.add(&Dot { x, y });
+}
+
+/// Draw a square ripple expanding from the center of the screen.
+fn draw_ripple(frame: usize, screen: &mut DotScreen) {
+    let ring = frame % RIPPLE_RINGS;
+    for x in 0..DotScreen::WIDTH {
+        for y in 0..DotScreen::HEIGHT {
+            // The distance (in half-dots) of the dot from the center of the screen.
+            let dx = (2 * x as isize - (DotScreen::WIDTH as isize - 1)).abs();
+            let dy = (2 * y as isize - (DotScreen::HEIGHT as isize - 1)).abs();
+            if dx.max(dy) as usize == 2 * ring + 1 { screen.add(&Dot { x, y }) }
+        }
+    }
+}
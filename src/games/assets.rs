@@ -0,0 +1,116 @@
+/// The assets of each game, bundled into a single static table per game.
+///
+/// The tables, and the title screens they refer to, are kept in program memory (see `progmem`),
+///   so every asset is read through the accessors of the GameAssets.
+use crate::Components;
+use crate::peripherals::{progmem, DotScreen, Note};
+
+
+/// A short demo of a game, shown in the attract loop. Returns whether it was interrupted by input.
+pub type Demo = fn(&mut Components) -> bool;
+
+
+/// The assets of a game.
+///
+/// This is constructed with the `game_assets!` macro, so that every game declares its
+///   assets in the same way, placed in the `.progmem.data` section.
+#[derive(Copy, Clone)]
+pub struct GameAssets {
+    /// The title screen, shown on the selection screen.
+    title: &'static DotScreen,
+    /// The icon, if it differs from the title screen.
+    icon: Option<&'static DotScreen>,
+    /// The demo, shown in the attract loop.
+    demo: Option<Demo>,
+    /// The melody of the game.
+    melody: &'static [Note],
+    /// The help text, explaining how to play the game.
+    help: &'static str,
+}
+
+impl GameAssets {
+
+    /// Construct a new GameAssets object. Prefer the `game_assets!` macro.
+    pub const fn new(
+        title: &'static DotScreen,
+        icon: Option<&'static DotScreen>,
+        demo: Option<Demo>,
+        melody: &'static [Note],
+        help: &'static str,
+    ) -> Self {
+        Self { title, icon, demo, melody, help }
+    }
+
+    /// Read the assets from program memory.
+    fn read(&self) -> Self {
+        unsafe { progmem::read_value(self) }
+    }
+
+    /// Returns the title screen.
+    pub fn title(&self) -> DotScreen {
+        unsafe { progmem::read_value(self.read().title) }
+    }
+
+    /// Returns the icon, which is the title screen unless the game declares its own.
+    pub fn icon(&self) -> DotScreen {
+        let assets = self.read();
+        unsafe { progmem::read_value(assets.icon.unwrap_or(assets.title)) }
+    }
+
+    /// Returns the demo, if the game has one.
+    pub fn demo(&self) -> Option<Demo> {
+        self.read().demo
+    }
+
+    /// Returns the melody, which is empty if the game has none.
+    pub fn melody(&self) -> &'static [Note] {
+        self.read().melody
+    }
+
+    /// Returns the help text, which is empty if the game has none.
+    pub fn help(&self) -> &'static str {
+        self.read().help
+    }
+}
+
+
+/// Expands to the provided asset, or to the default if the asset was omitted.
+macro_rules! asset_or {
+    ($default:expr;) => { $default };
+    ($default:expr; $asset:expr) => { $asset };
+}
+
+/// Declare the assets of a game as the static `ASSETS` table of the game's module.
+///
+/// Only the title screen is required; the icon, demo, melody, and help text are optional
+///   but must be given in that order. The title screen and icon must be placed in the
+///   `.progmem.data` section.
+///
+/// # Example
+/// ```ignore
+/// game_assets! {
+///     title: TITLE_SCREEN,
+///     demo: snake_demo,
+///     help: "Eat the eggs, but not your tail.",
+/// }
+/// ```
+macro_rules! game_assets {
+    (
+        title: $title:expr
+        $(, icon: $icon:expr)?
+        $(, demo: $demo:expr)?
+        $(, melody: $melody:expr)?
+        $(, help: $help:expr)?
+        $(,)?
+    ) => {
+        /// The assets of the game.
+        #[link_section = ".progmem.data"]
+        pub static ASSETS: $crate::games::GameAssets = $crate::games::GameAssets::new(
+            &$title,
+            asset_or!(None; $( Some(&$icon) )?),
+            asset_or!(None; $( Some($demo as $crate::games::Demo) )?),
+            asset_or!(&[]; $( &$melody )?),
+            asset_or!(""; $( $help )?),
+        );
+    };
+}
@@ -0,0 +1,279 @@
+/// Implementation of the Asteroid Field (dodging) game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Color, Dot, Display, DotScreen, Palette, Tint, TintedScreen};
+use crate::sound::Melody;
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Asteroid Field game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'A';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 100;
+//   The maximum number of asteroids on the screen at once.
+const MAX_ASTEROIDS: usize = 8;
+//   The fastest and slowest speeds of the asteroids, in game ticks per step.
+const FASTEST_ASTEROID_TICKS: usize = 2;
+const SLOWEST_ASTEROID_TICKS: usize = 4;
+//   The initial and minimum number of game ticks between each asteroid spawn.
+const INITIAL_SPAWN_TICKS: usize = 12;
+const MIN_SPAWN_TICKS: usize = 3;
+//   The number of game ticks survived after which asteroids spawn more often.
+const RAMP_INTERVAL: usize = 50;
+//   The number of game ticks survived for each point scored.
+const TICKS_PER_POINT: usize = 10;
+//   The colors of the game: grey asteroids and a cyan player.
+const PALETTE: Palette = Palette::new(
+    Color::new(0, 0, 0), Color::new(0, 0, 0), Color::new(96, 96, 96), Color::new(0, 255, 255), Color::new(255, 255, 255),
+);
+
+
+/// The Title Screen for the Asteroid Field Game ("A").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00111110,
+            0b01001000,
+            0b01001000,
+            0b01001000,
+            0b00111110,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(asteroids_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Asteroid Field game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn asteroids_game_loop(components: &mut Components) {
+    let mut game = AsteroidsGame::new();
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// An asteroid drifting across the screen.
+#[derive(Copy, Clone)]
+struct Asteroid {
+    /// The x-index of the asteroid, which may be just off the screen (-1 or WIDTH).
+    x: isize,
+    /// The y-index of the asteroid, which may be just off the screen (-1 or HEIGHT).
+    y: isize,
+    /// The horizontal and vertical steps of the asteroid's trajectory (each -1, 0, or 1).
+    velocity: (isize, isize),
+    /// The number of game ticks between each step of the asteroid.
+    step_ticks: usize,
+}
+
+impl Asteroid {
+
+    /// Create a new Asteroid just off a random edge of the screen,
+    ///   on a random trajectory heading onto the screen.
+    fn random(rng: &mut dyn rand_core::RngCore) -> Self {
+        let (width, height) = (DotScreen::WIDTH as isize, DotScreen::HEIGHT as isize);
+        let along = utils::random_below(rng, DotScreen::WIDTH) as isize;
+        let drift = utils::random_below(rng, 3) as isize - 1;
+        let (x, y, velocity) = match utils::random_below(rng, 4) {
+            0 => { (-1, along, (1, drift)) },
+            1 => { (width, along, (-1, drift)) },
+            2 => { (along, -1, (drift, 1)) },
+            _ => { (along, height, (drift, -1)) },
+        };
+        let step_ticks = FASTEST_ASTEROID_TICKS
+            + utils::random_below(rng, SLOWEST_ASTEROID_TICKS - FASTEST_ASTEROID_TICKS + 1);
+        Self { x, y, velocity, step_ticks }
+    }
+
+    /// Returns whether the asteroid has drifted beyond the edges of the screen.
+    fn is_lost(&self) -> bool {
+        (self.x < -1) | (self.x > DotScreen::WIDTH as isize)
+            | (self.y < -1) | (self.y > DotScreen::HEIGHT as isize)
+    }
+
+    /// Returns the dot the asteroid is on, if it is on the screen.
+    fn dot(&self) -> Option<Dot> {
+        let on_screen = (self.x >= 0) & (self.x < DotScreen::WIDTH as isize)
+            & (self.y >= 0) & (self.y < DotScreen::HEIGHT as isize);
+        if on_screen { Some(Dot { x: self.x as usize, y: self.y as usize }) } else { None }
+    }
+}
+
+
+/// The AsteroidsGame object.
+///
+/// Asteroids drift across the screen on random trajectories, while the player steers
+///   freely in all four directions to avoid them. Asteroids appear more often the longer
+///   the player survives, and the game ends when the player is struck by an asteroid.
+struct AsteroidsGame {
+    /// The asteroids in the field, if any.
+    asteroids: [Option<Asteroid>; MAX_ASTEROIDS],
+    /// The position of the player.
+    player: Dot,
+    /// The countdown until the next asteroid appears.
+    spawn: Countdown,
+    /// The screen depicting the current state of the game.
+    screen: TintedScreen,
+    /// The number of game ticks survived.
+    tick: usize,
+}
+
+impl AsteroidsGame {
+
+    /// Construct a new AsteroidsGame object.
+    fn new() -> Self {
+        let mut game = Self {
+            asteroids: [None; MAX_ASTEROIDS],
+            player: Dot { x: 0, y: 0 },
+            spawn: Countdown::new(0),
+            screen: TintedScreen::new_empty(),
+            tick: 0,
+        };
+        game.reset();
+        return game
+    }
+
+    /// Returns whether any asteroid is on the player.
+    fn is_player_struck(&self) -> bool {
+        self.asteroids.iter()
+            .filter_map(|asteroid| asteroid.and_then(|asteroid| asteroid.dot()))
+            .any(|dot| dot == self.player)
+    }
+
+    /// Returns the current score for the game, based on the time survived.
+    fn get_score(&self) -> usize {
+        self.tick / TICKS_PER_POINT
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        const EXPLOSION_RADII: usize = 3;
+        const EXPLOSION_FRAME_MS: u16 = 60;
+        self.render();
+        // The ship explodes in a ring spreading out from it.
+        for radius in 1..=EXPLOSION_RADII {
+            let mut frame = self.screen.flatten();
+            crate::graphics::draw_circle(&mut frame, &self.player, radius);
+            components.display.show(&frame);
+            arduino_uno::delay_ms(EXPLOSION_FRAME_MS);
+        }
+        self.screen.add(Tint::Actor, &self.player);
+        utils::flash_screen(&mut components.display, &self.screen.flatten(), 2);
+        components.report_score(GAME_ID, self.get_score());
+        utils::show_tally(&mut components.display, self.get_score());
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    fn reset(&mut self) {
+        self.asteroids = [None; MAX_ASTEROIDS];
+        self.player = Dot { x: DotScreen::WIDTH / 2, y: DotScreen::HEIGHT / 2 };
+        self.spawn = Countdown::new(INITIAL_SPAWN_TICKS);
+        self.tick = 0;
+        self.render();
+    }
+}
+
+
+impl GamePlay for AsteroidsGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        let Dot { x, y } = self.player;
+        match input.direction {
+            Some(Direction::Left) if x > 0 => { self.player.x -= 1 },
+            Some(Direction::Right) if x < DotScreen::WIDTH - 1 => { self.player.x += 1 },
+            Some(Direction::Down) if y > 0 => { self.player.y -= 1 },
+            Some(Direction::Up) if y < DotScreen::HEIGHT - 1 => { self.player.y += 1 },
+            _ => {}
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This drifts the asteroids, spawns new ones,
+    ///   and checks whether the player has been struck.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        // The player may have steered into an asteroid.
+        if self.is_player_struck() { return false }
+        self.tick += 1;
+
+        for slot in self.asteroids.iter_mut() {
+            if let Some(asteroid) = slot {
+                if self.tick % asteroid.step_ticks == 0 {
+                    asteroid.x += asteroid.velocity.0;
+                    asteroid.y += asteroid.velocity.1;
+                }
+                if asteroid.is_lost() { *slot = None }
+            }
+        }
+
+        if self.spawn.tick() {
+            if let Some(slot) = self.asteroids.iter_mut().find(|slot| slot.is_none()) {
+                *slot = Some(Asteroid::random(rng));
+            }
+            let interval = INITIAL_SPAWN_TICKS.saturating_sub(self.tick / RAMP_INTERVAL);
+            self.spawn = Countdown::new(interval.max(MIN_SPAWN_TICKS));
+        }
+
+        !self.is_player_struck()
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The player blinks briefly every few ticks, to distinguish it from the asteroids.
+    fn render(&mut self) {
+        self.screen.clear();
+        for asteroid in self.asteroids.iter().filter_map(|asteroid| *asteroid) {
+            if let Some(dot) = asteroid.dot() { self.screen.add(Tint::Hazard, &dot) }
+        }
+        if self.tick % 4 != 0 { self.screen.add(Tint::Actor, &self.player) }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show_tinted(&self.screen, &PALETTE);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen.flatten()
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score: the time survived.
+    fn score(&self) -> usize {
+        self.get_score()
+    }
+
+    /// The score rises steadily, so its increases play no sound.
+    fn on_score(&self) -> Option<&'static Melody> {
+        None
+    }
+}
@@ -0,0 +1,339 @@
+/// Implementation of a Bomberman-lite game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Bomberman game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'B';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 150;
+//   The number of game ticks before a placed bomb explodes.
+const FUSE_TICKS: usize = 16;
+//   The number of game ticks the explosion remains on screen.
+const EXPLOSION_TICKS: usize = 3;
+//   The number of dots the explosion reaches in each direction.
+const BLAST_RANGE: usize = 2;
+//   The number of game ticks between each step of the enemy.
+const ENEMY_STEP_TICKS: usize = 4;
+//   The chance (out of 8) that an open dot is filled with a soft wall.
+const SOFT_WALL_CHANCE: usize = 3;
+//   The points awarded for destroying the enemy.
+const ENEMY_POINTS: usize = 5;
+//   The position from which the player starts, the top left corner.
+const PLAYER_START: Dot = Dot { x: 0, y: DotScreen::HEIGHT - 1 };
+
+
+/// The Title Screen for the Bomberman Game ("B").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b01010010,
+            0b01010010,
+            0b01010010,
+            0b00101100,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(bomberman_game_loop, id: GAME_ID);
+
+/// The indestructible walls, a pillar on every other dot of every other column.
+static HARD_WALLS: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b10101010,
+            0b00000000,
+            0b10101010,
+            0b00000000,
+            0b10101010,
+            0b00000000,
+            0b10101010,
+        ]
+    );
+
+
+/// The game loop which runs the Bomberman game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn bomberman_game_loop(components: &mut Components) {
+    let mut game = BombermanGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A bomb placed by the player, which explodes once its fuse burns out.
+#[derive(Copy, Clone)]
+struct Bomb {
+    position: Dot,
+    fuse: Countdown,
+}
+
+/// The explosion of a bomb, which lingers on the screen for a few frames.
+#[derive(Copy, Clone)]
+struct Explosion {
+    /// The dots covered by the explosion.
+    area: DotScreen,
+    /// The remaining frames of the explosion.
+    frames: Countdown,
+}
+
+
+/// The BombermanGame object.
+///
+/// The player navigates a grid of indestructible pillars and destructible soft walls,
+///   placing bombs that explode in a plus pattern. Destroying the wandering enemy
+///   advances to a new board, while being caught by the enemy or an explosion ends the game.
+struct BombermanGame {
+    /// The character that the player controls.
+    player: Dot,
+    /// The wandering enemy, if it has not been destroyed.
+    enemy: Option<Dot>,
+    /// The soft walls, which can be destroyed by explosions.
+    soft_walls: DotScreen,
+    /// The bomb placed by the player, if any.
+    bomb: Option<Bomb>,
+    /// The explosion of the most recent bomb, if it is still on screen.
+    explosion: Option<Explosion>,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of soft walls destroyed, plus the points for each enemy destroyed.
+    score: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl BombermanGame {
+
+    /// Construct a new BombermanGame object.
+    fn new() -> Self {
+        Self {
+            player: PLAYER_START,
+            enemy: None,
+            soft_walls: DotScreen::new_empty(),
+            bomb: None,
+            explosion: None,
+            screen: DotScreen::new_empty(),
+            score: 0,
+            tick: 0,
+        }
+    }
+
+    /// Determine whether a dot is blocked by a wall or the bomb.
+    fn is_blocked(&self, dot: &Dot) -> bool {
+        HARD_WALLS.is_dot_on(dot)
+            || self.soft_walls.is_dot_on(dot)
+            || self.bomb.map_or(false, |bomb| bomb.position == *dot)
+    }
+
+    /// Move the player in the provided direction, if the way is not blocked.
+    fn move_player(&mut self, direction: Direction) {
+        let next = self.player.step(direction);
+        if !self.is_blocked(&next) {
+            self.player = next;
+        }
+    }
+
+    /// Move the enemy to a random neighbouring dot that is not blocked.
+    fn move_enemy(&mut self, rng: &mut dyn rand_core::RngCore) {
+        const DIRECTIONS: [Direction; 4] = [Direction::Left, Direction::Right, Direction::Up, Direction::Down];
+        if let Some(enemy) = self.enemy {
+            let start = utils::random_below(rng, DIRECTIONS.len());
+            for offset in 0..DIRECTIONS.len() {
+                let next = enemy.step(DIRECTIONS[(start + offset) % DIRECTIONS.len()]);
+                if next != enemy && !self.is_blocked(&next) {
+                    self.enemy = Some(next);
+                    return
+                }
+            }
+        }
+    }
+
+    /// Detonate the bomb, computing the area covered by the explosion.
+    ///
+    /// The explosion extends in a plus pattern from the bomb. Each arm of the plus is
+    ///   stopped by the hard walls, and by the first soft wall it destroys.
+    fn detonate(&mut self, bomb: Bomb) {
+        let mut area = DotScreen::new_empty();
+        area.add(&bomb.position);
+        for &direction in [Direction::Left, Direction::Right, Direction::Up, Direction::Down].iter() {
+            let mut dot = bomb.position;
+            for _ in 0..BLAST_RANGE {
+                let next = dot.step(direction);
+                if next == dot || HARD_WALLS.is_dot_on(&next) { break }
+                dot = next;
+                area.add(&dot);
+                if self.soft_walls.is_dot_on(&dot) {
+                    self.soft_walls.remove(&dot);
+                    self.score += 1;
+                    break
+                }
+            }
+        }
+        self.explosion = Some(Explosion { area, frames: Countdown::new(EXPLOSION_TICKS) });
+    }
+
+    /// Generate a new board, with randomly placed soft walls and the enemy in the far corner.
+    ///
+    /// The dots neighbouring the player are kept clear, so the player has room
+    ///   to escape their first bomb.
+    fn new_board(&mut self, rng: &mut dyn rand_core::RngCore) {
+        let enemy = Dot { x: DotScreen::WIDTH - 2, y: 0 };
+        self.soft_walls.clear();
+        for dot in HARD_WALLS.iter_off() {
+            let dx = (dot.x as isize - self.player.x as isize).abs();
+            let dy = (dot.y as isize - self.player.y as isize).abs();
+            if dx + dy <= 2 || dot == enemy { continue }
+            if utils::random_below(rng, 8) < SOFT_WALL_CHANCE {
+                self.soft_walls.add(&dot);
+            }
+        }
+        self.enemy = Some(enemy);
+        self.bomb = None;
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.score);
+        utils::show_tally(&mut components.display, self.score);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the board.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.player = PLAYER_START;
+        self.explosion = None;
+        self.score = 0;
+        self.tick = 0;
+        self.new_board(rng);
+    }
+}
+
+
+impl GamePlay for BombermanGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction {
+            self.move_player(direction);
+        }
+        if input.button && self.bomb.is_none() {
+            self.bomb = Some(Bomb { position: self.player, fuse: Countdown::new(FUSE_TICKS) });
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This burns the fuse of the bomb,
+    ///   advances the explosion, moves the enemy, and resolves any collisions.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+
+        // Burn the fuse of the bomb, detonating it once burnt out.
+        if let Some(mut bomb) = self.bomb {
+            if bomb.fuse.tick() {
+                self.bomb = None;
+                self.detonate(bomb);
+            } else {
+                self.bomb = Some(bomb);
+            }
+        }
+
+        if self.tick % ENEMY_STEP_TICKS == 0 { self.move_enemy(rng) }
+
+        // Resolve the explosion.
+        if let Some(mut explosion) = self.explosion {
+            if explosion.area.is_dot_on(&self.player) { return false }
+            if let Some(enemy) = self.enemy {
+                if explosion.area.is_dot_on(&enemy) {
+                    self.enemy = None;
+                    self.score += ENEMY_POINTS;
+                }
+            }
+            self.explosion = if explosion.frames.tick() { None } else { Some(explosion) };
+        }
+
+        if self.enemy == Some(self.player) { return false }
+
+        // Once the enemy is destroyed and the explosion has cleared, advance to a new board.
+        if self.enemy.is_none() && self.explosion.is_none() {
+            self.new_board(rng);
+        }
+        return true
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The walls and the player are always lit, the enemy blinks slowly,
+    ///   and the bomb blinks faster and faster as its fuse burns.
+    fn render(&mut self) {
+        self.screen = HARD_WALLS;
+        for x in 0..DotScreen::WIDTH {
+            self.screen.columns[x] |= self.soft_walls.columns[x];
+        }
+        self.screen.add(&self.player);
+        if let Some(enemy) = self.enemy {
+            if (self.tick / 2) % 2 == 0 { self.screen.add(&enemy) }
+        }
+        if let Some(bomb) = self.bomb {
+            let blink_ticks = if bomb.fuse.remaining() > FUSE_TICKS / 2 { 2 } else { 1 };
+            if (self.tick / blink_ticks) % 2 == 0 { self.screen.add(&bomb.position) }
+        }
+        if let Some(explosion) = self.explosion {
+            for x in 0..DotScreen::WIDTH {
+                self.screen.columns[x] |= explosion.area.columns[x];
+            }
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the points for the walls and enemies destroyed.
+    fn score(&self) -> usize {
+        self.score
+    }
+}
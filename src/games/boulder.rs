@@ -0,0 +1,416 @@
+/// Implementation of the Boulder Dig game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::GamePlay, level::{self, Goal, Level, LevelPlay}, overlay::{self, OverlayChoice}, saved_game::{self, SaveData, SaveState}, utils::{self, TickInput}};
+
+// Constants for the Boulder Dig game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'O';
+//   The length of a single game tick in the first level, and the minimum length.
+const INITIAL_TICK_INTERVAL_MS: usize = 120;
+const MIN_TICK_INTERVAL_MS: usize = 70;
+//   The amount by which a game tick is shortened with each level.
+const TICK_SPEED_UP_MS: usize = 10;
+//   The number of game ticks between each step of gravity.
+const GRAVITY_TICKS: usize = 2;
+//   The number of boulders and gems buried in each level.
+const BOULDERS: usize = 6;
+const GEMS: usize = 5;
+//   The position at which the player begins each level (the top left corner).
+const START: Dot = Dot { x: 0, y: DotScreen::HEIGHT - 1 };
+
+
+/// The Title Screen for the Boulder Dig Game (a gem).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00010000,
+            0b00111000,
+            0b01111100,
+            0b11111110,
+            0b01111100,
+            0b00111000,
+            0b00010000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(boulder_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Boulder Dig game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn boulder_game_loop(components: &mut Components) {
+    // A saved game may be continued from its level, or abandoned for a new game.
+    let mut game = match saved_game::load::<BoulderGame>(GAME_ID) {
+        Some(saved) if overlay::choose_continue(components) => saved,
+        _ => BoulderGame::new(),
+    };
+    // Quitting from the overlay menu skips the game over screen.
+    let first_level = game.level;
+    if level::play_from(&mut game, components, first_level) { game.game_over(components) }
+}
+
+
+/// The contents of a single cell of the grid.
+#[derive(Copy, Clone, PartialEq)]
+enum Cell {
+    Empty,
+    Dirt,
+    Boulder,
+    Gem,
+}
+
+impl Cell {
+
+    /// Every kind of cell, indexed by its two-bit code in a saved game.
+    const ALL: [Cell; 4] = [Cell::Empty, Cell::Dirt, Cell::Boulder, Cell::Gem];
+}
+
+
+/// The BoulderGame object.
+///
+/// The player tunnels through the dirt collecting the buried gems. Boulders fall
+///   once the dirt beneath them is dug away, and roll off the top of other boulders
+///   and gems. The player can push a boulder sideways into an empty space, and may
+///   stand beneath a resting boulder, but is crushed by a falling one. Collecting
+///   every gem completes the level, burying the next (a little faster), and the game
+///   ends once the player is crushed.
+///   A button press pauses the game, from which the game can also be saved.
+struct BoulderGame {
+    /// The contents of the grid, indexed by x-index and then y-index.
+    grid: [[Cell; DotScreen::HEIGHT]; DotScreen::WIDTH],
+    /// The boulders which fell during the previous step of gravity.
+    falling: DotScreen,
+    /// The position of the player.
+    player: Dot,
+    /// The number of gems collected.
+    gems: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of game ticks elapsed.
+    tick: usize,
+    /// The number of the current level, the first level being 1.
+    level: usize,
+    /// The length of a game tick during the current level.
+    tick_interval_ms: usize,
+    /// Whether the grid holds the level of a saved game, which is resumed rather than buried.
+    resumed: bool,
+}
+
+impl BoulderGame {
+
+    /// Construct a new BoulderGame object.
+    fn new() -> Self {
+        Self {
+            grid: [[Cell::Dirt; DotScreen::HEIGHT]; DotScreen::WIDTH],
+            falling: DotScreen::new_empty(),
+            player: START,
+            gems: 0,
+            screen: DotScreen::new_empty(),
+            tick: 0,
+            level: 1,
+            tick_interval_ms: INITIAL_TICK_INTERVAL_MS,
+            resumed: false,
+        }
+    }
+
+    /// Returns the contents of the cell at the position.
+    fn cell(&self, dot: &Dot) -> Cell {
+        self.grid[dot.x][dot.y]
+    }
+
+    /// Set the contents of the cell at the position.
+    fn set_cell(&mut self, dot: &Dot, cell: Cell) {
+        self.grid[dot.x][dot.y] = cell;
+    }
+
+    /// Move the player one cell in the direction, digging through dirt and collecting gems.
+    ///
+    /// A boulder can be pushed sideways if the cell beyond it is empty.
+    fn move_player(&mut self, direction: Direction) {
+        let next = self.player.step(direction);
+        if next == self.player { return }
+        match self.cell(&next) {
+            Cell::Empty | Cell::Dirt => {},
+            Cell::Gem => { self.gems += 1 },
+            Cell::Boulder => {
+                let horizontal = (direction == Direction::Left) | (direction == Direction::Right);
+                let beyond = next.step(direction);
+                let pushable = horizontal & (beyond != next) && (self.cell(&beyond) == Cell::Empty);
+                if !pushable { return }
+                self.set_cell(&beyond, Cell::Boulder);
+            },
+        }
+        self.set_cell(&next, Cell::Empty);
+        self.player = next;
+    }
+
+    /// Returns whether a boulder could move into the cell: it is empty, and not the player.
+    fn is_open(&self, dot: &Dot) -> bool {
+        (self.cell(dot) == Cell::Empty) & (*dot != self.player)
+    }
+
+    /// Apply a single step of gravity to every boulder.
+    ///
+    /// The rows are processed from the bottom up, and each boulder moves at most once.
+    ///
+    /// # Returns
+    /// Whether the player survived (was not crushed by a falling boulder).
+    fn apply_gravity(&mut self) -> bool {
+        let mut falling = DotScreen::new_empty();
+        for y in 1..DotScreen::HEIGHT {
+            for x in 0..DotScreen::WIDTH {
+                let boulder = Dot { x, y };
+                // Skip any boulder which has already moved during this step.
+                if (self.cell(&boulder) != Cell::Boulder) | falling.is_dot_on(&boulder) { continue }
+                let below = boulder.down();
+
+                // Only a boulder already in motion is heavy enough to crush the player.
+                if (below == self.player) & self.falling.is_dot_on(&boulder) { return false }
+
+                let destination = if self.is_open(&below) {
+                    Some(below)
+                } else if (self.cell(&below) == Cell::Boulder) | (self.cell(&below) == Cell::Gem) {
+                    // Roll off the rounded top of a boulder or gem, preferring the left.
+                    // The sides are clamped at the edges of the screen, so must differ from the boulder.
+                    [boulder.left(), boulder.right()].iter()
+                        .find(|&side| (*side != boulder) && self.is_open(side) && self.is_open(&side.down()))
+                        .copied()
+                } else {
+                    None
+                };
+
+                if let Some(destination) = destination {
+                    self.set_cell(&boulder, Cell::Empty);
+                    self.set_cell(&destination, Cell::Boulder);
+                    falling.add(&destination);
+                }
+            }
+        }
+        self.falling = falling;
+        true
+    }
+
+    /// Bury a new level: fill the grid with dirt, and scatter the boulders and gems.
+    ///
+    /// The player is returned to the starting position.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to scatter the boulders and gems.
+    fn bury(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.grid = [[Cell::Dirt; DotScreen::HEIGHT]; DotScreen::WIDTH];
+        self.set_cell(&START, Cell::Empty);
+        self.player = START;
+        self.falling.clear();
+
+        for &(cell, count) in [(Cell::Boulder, BOULDERS), (Cell::Gem, GEMS)].iter() {
+            let mut placed = 0;
+            while placed < count {
+                let dot = Dot {
+                    x: utils::random_below(rng, DotScreen::WIDTH),
+                    y: utils::random_below(rng, DotScreen::HEIGHT),
+                };
+                if self.cell(&dot) != Cell::Dirt { continue }
+                self.set_cell(&dot, cell);
+                placed += 1;
+            }
+        }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.gems);
+        utils::show_tally(&mut components.display, self.gems);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game (on restarting it from the overlay menu),
+    ///   with no gems collected and the current level buried anew.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to bury the level.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.gems = 0;
+        self.tick = 0;
+        self.bury(rng);
+    }
+}
+
+
+impl GamePlay for BoulderGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        self.tick_interval_ms
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction {
+            self.move_player(direction);
+        }
+    }
+
+    /// A button press pauses the game.
+    fn pauses_on_press(&self) -> bool {
+        true
+    }
+
+    /// Pause the game, opening the overlay menu (which offers to save the game).
+    fn pause(&mut self, components: &mut Components) -> bool {
+        match overlay::open_with_save(components, &self.screen) {
+            OverlayChoice::Resume => { true },
+            OverlayChoice::Restart => {
+                self.reset(&mut components.analog);
+                true
+            },
+            OverlayChoice::SaveAndQuit => {
+                saved_game::save(GAME_ID, self);
+                false
+            },
+            OverlayChoice::Quit => { false },
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This applies gravity to the boulders.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        !((self.tick % GRAVITY_TICKS == 0) && !self.apply_gravity())
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// Dirt is lit, tunnels are unlit, boulders flicker off briefly, gems blink,
+    ///   and the player is inverted on alternating pairs of ticks.
+    fn render(&mut self) {
+        self.screen.clear();
+        for x in 0..DotScreen::WIDTH {
+            for y in 0..DotScreen::HEIGHT {
+                let lit = match self.grid[x][y] {
+                    Cell::Empty => { false },
+                    Cell::Dirt => { true },
+                    Cell::Boulder => { self.tick % 4 != 0 },
+                    Cell::Gem => { self.tick % 2 == 0 },
+                };
+                if lit { self.screen.add(&Dot { x, y }) }
+            }
+        }
+        if (self.tick / 2) % 2 == 0 {
+            self.screen.toggle(&self.player);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the number of gems collected.
+    fn score(&self) -> usize {
+        self.gems
+    }
+}
+
+
+impl LevelPlay for BoulderGame {
+
+    /// Bury a level, unless resuming that of a saved game. Its obstacles are the boulders.
+    ///
+    /// Each level is a little faster than the one before.
+    fn level(&mut self, number: usize, components: &mut Components) -> Level {
+        if !core::mem::replace(&mut self.resumed, false) {
+            self.bury(&mut components.analog);
+        }
+        let mut boulders = DotScreen::new_empty();
+        for x in 0..DotScreen::WIDTH {
+            for y in 0..DotScreen::HEIGHT {
+                if self.grid[x][y] == Cell::Boulder { boulders.add(&Dot { x, y }) }
+            }
+        }
+        let speed_up_ms = (number - 1) * TICK_SPEED_UP_MS;
+        Level {
+            obstacles: boulders,
+            tick_interval_ms: INITIAL_TICK_INTERVAL_MS.saturating_sub(speed_up_ms).max(MIN_TICK_INTERVAL_MS),
+            time_limit: None,
+            goal: Goal::Collect(GEMS),
+        }
+    }
+
+    /// Begin a level, at its speed.
+    fn begin(&mut self, number: usize, level: &Level) {
+        self.level = number;
+        self.tick_interval_ms = level.tick_interval_ms;
+    }
+
+    /// Returns whether every gem of the level has been collected.
+    fn is_goal_met(&self, goal: &Goal) -> bool {
+        match goal {
+            Goal::Collect(_) => { !self.grid.iter().flatten().any(|&cell| cell == Cell::Gem) },
+            Goal::Reach(dot) => { self.player == *dot },
+        }
+    }
+}
+
+
+/// A saved game holds the grid (two bits per cell, a pair of bytes per column), the position
+///   of the player (its x-index in the high nibble), the number of gems collected, and the
+///   number of the level. Boulders which were falling come to rest.
+impl SaveState for BoulderGame {
+
+    /// Serialize the state of the game.
+    fn save(&self, data: &mut SaveData) {
+        for (x, column) in self.grid.iter().enumerate() {
+            let bits = column.iter().enumerate()
+                .fold(0u16, |bits, (y, &cell)| bits | ((cell as u16) << (2 * y)));
+            data[(2 * x)..(2 * x + 2)].copy_from_slice(&bits.to_le_bytes());
+        }
+        data[16] = ((self.player.x << 4) | self.player.y) as u8;
+        data[17..19].copy_from_slice(&(self.gems as u16).to_le_bytes());
+        data[19] = self.level as u8;
+    }
+
+    /// Restore the state of a game, which is invalid if the player is not in a tunnel.
+    fn restore(data: &SaveData) -> Option<Self> {
+        let mut game = Self::new();
+        for (x, column) in game.grid.iter_mut().enumerate() {
+            let bits = u16::from_le_bytes([data[2 * x], data[2 * x + 1]]);
+            for (y, cell) in column.iter_mut().enumerate() {
+                *cell = Cell::ALL[((bits >> (2 * y)) & 0b11) as usize];
+            }
+        }
+        game.player = Dot { x: (data[16] >> 4) as usize, y: (data[16] & 0x0F) as usize };
+        game.gems = u16::from_le_bytes([data[17], data[18]]) as usize;
+        game.level = data[19] as usize;
+        game.resumed = true;
+        let valid = (game.player.x < DotScreen::WIDTH) & (game.player.y < DotScreen::HEIGHT)
+            && (game.cell(&game.player) == Cell::Empty) && (game.level > 0);
+        if valid { Some(game) } else { None }
+    }
+}
@@ -0,0 +1,223 @@
+/// Implementation of the Cave Flyer (helicopter) game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use crate::sound::Melody;
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Cave Flyer game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'C';
+//   The x-index (column) along which the player flies.
+const PLAYER_X: usize = 1;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 120;
+//   The number of game ticks between each scroll of the cave.
+const SCROLL_TICKS: usize = 2;
+//   The initial height of the gap between the cave walls.
+const INITIAL_GAP: usize = 5;
+//   The minimum height of the gap between the cave walls.
+const MIN_GAP: usize = 3;
+//   The number of columns scrolled after which the gap narrows.
+const NARROW_INTERVAL: usize = 32;
+//   The number of columns scrolled for each point scored.
+const DISTANCE_PER_POINT: usize = 4;
+
+
+/// The Title Screen for the Cave Flyer Game ("C").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00111100,
+            0b01000010,
+            0b01000010,
+            0b01000010,
+            0b00100100,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(cave_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Cave Flyer game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn cave_game_loop(components: &mut Components) {
+    let mut game = CaveGame::new();
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A single column of the cave, described by the open gap between its walls.
+#[derive(Copy, Clone)]
+struct CaveColumn {
+    /// The lowest open y-index of the gap.
+    floor: usize,
+    /// The height of the gap.
+    gap: usize,
+}
+
+impl CaveColumn {
+
+    /// Returns whether the dot at the y-index is open (not part of the cave walls).
+    fn is_open(&self, y: usize) -> bool {
+        (y >= self.floor) & (y < self.floor + self.gap)
+    }
+}
+
+
+/// The CaveGame object.
+///
+/// The cave scrolls from right to left while the player flies through it.
+///   Holding the JoyStick Up makes the player rise, and releasing it makes the
+///   player fall. The gap between the cave walls narrows as the player flies deeper.
+struct CaveGame {
+    /// The columns of the cave currently on the screen, ordered left to right.
+    cave: [CaveColumn; DotScreen::WIDTH],
+    /// The y-index of the player.
+    player_y: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of columns scrolled.
+    distance: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl CaveGame {
+
+    /// Construct a new CaveGame object.
+    fn new() -> Self {
+        let mut game = Self {
+            cave: [CaveColumn { floor: 0, gap: 0 }; DotScreen::WIDTH],
+            player_y: 0,
+            screen: DotScreen::new_empty(),
+            distance: 0,
+            tick: 0,
+        };
+        game.reset();
+        return game
+    }
+
+    /// Scroll the cave one column to the left, generating a new column on the right.
+    ///
+    /// The gap of the new column wanders at most one dot from the gap of the previous
+    ///   column, so the cave is always passable.
+    fn scroll(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.cave.rotate_left(1);
+        self.distance += 1;
+
+        let last = self.cave[DotScreen::WIDTH - 2];
+        let gap = INITIAL_GAP.saturating_sub(self.distance / NARROW_INTERVAL).max(MIN_GAP);
+        let floor = match utils::random_below(rng, 3) {
+            0 => { last.floor.saturating_sub(1) },
+            1 => { last.floor },
+            _ => { last.floor + 1 },
+        };
+        let floor = floor.min(DotScreen::HEIGHT - gap);
+        self.cave[DotScreen::WIDTH - 1] = CaveColumn { floor, gap };
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.distance / DISTANCE_PER_POINT);
+        utils::show_tally(&mut components.display, self.distance / DISTANCE_PER_POINT);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    fn reset(&mut self) {
+        let floor = (DotScreen::HEIGHT - INITIAL_GAP) / 2;
+        self.cave = [CaveColumn { floor, gap: INITIAL_GAP }; DotScreen::WIDTH];
+        self.player_y = floor + INITIAL_GAP / 2;
+        self.distance = 0;
+        self.tick = 0;
+        self.render();
+    }
+}
+
+
+impl GamePlay for CaveGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        if input.direction == Some(Direction::Up) {
+            if self.player_y < DotScreen::HEIGHT - 1 { self.player_y += 1 }
+        } else if self.player_y > 0 {
+            self.player_y -= 1;
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This scrolls the cave and checks whether
+    ///   the player has collided with the cave walls.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        if self.tick % SCROLL_TICKS == 0 {
+            self.scroll(rng);
+        }
+        self.cave[PLAYER_X].is_open(self.player_y)
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        for (x, column) in self.cave.iter().enumerate() {
+            for y in 0..DotScreen::HEIGHT {
+                if !column.is_open(y) { self.screen.add(&Dot { x, y }) }
+            }
+        }
+        self.screen.toggle(&Dot { x: PLAYER_X, y: self.player_y });
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score: the distance travelled.
+    fn score(&self) -> usize {
+        self.distance / DISTANCE_PER_POINT
+    }
+
+    /// The score rises steadily, so its increases play no sound.
+    fn on_score(&self) -> Option<&'static Melody> {
+        None
+    }
+}
@@ -0,0 +1,259 @@
+/// Implementation of a Doodle-Jump style Climber game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Display, DotScreen};
+use crate::sound::Melody;
+use super::{game::{self, GamePlay}, utils::{self, TickInput, VerticalCamera}};
+
+// Constants for the Climber game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'D';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 110;
+//   The number of rows the player rises with each bounce.
+const JUMP_HEIGHT: usize = 4;
+//   The highest screen row the player reaches before the camera scrolls upward.
+const CAMERA_ROW: usize = DotScreen::HEIGHT / 2;
+//   The number of platforms kept in the world at once.
+const PLATFORM_COUNT: usize = 6;
+//   The minimum and maximum vertical distance between consecutive platforms.
+const MIN_PLATFORM_GAP: usize = 2;
+const MAX_PLATFORM_GAP: usize = 3;
+//   The number of rows climbed for each point scored.
+const ROWS_PER_POINT: usize = 2;
+
+
+/// The Title Screen for the Climber Game ("D").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b01000010,
+            0b01000010,
+            0b01000010,
+            0b00100100,
+            0b00011000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(climber_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Climber game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn climber_game_loop(components: &mut Components) {
+    let mut game = ClimberGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A horizontal platform, in world coordinates.
+#[derive(Copy, Clone)]
+struct Platform {
+    /// The x-index of the leftmost dot of the platform.
+    x: usize,
+    /// The world y-coordinate of the platform.
+    y: usize,
+    /// The number of dots in the platform.
+    width: usize,
+}
+
+impl Platform {
+
+    /// Returns whether the platform covers the x-index.
+    fn covers(&self, x: usize) -> bool {
+        (x >= self.x) & (x < self.x + self.width)
+    }
+}
+
+
+/// The ClimberGame object.
+///
+/// The player bounces automatically, rising a few rows each time they land on a platform.
+///   Steering left and right (wrapping around the edges of the screen) guides the player
+///   onto higher platforms, and the camera follows the player upward.
+///   The game ends when the player falls off the bottom of the screen.
+struct ClimberGame {
+    /// The platforms, in world coordinates.
+    platforms: [Platform; PLATFORM_COUNT],
+    /// The x-index of the player.
+    player_x: usize,
+    /// The world y-coordinate of the player.
+    player_y: usize,
+    /// The number of rows the player has left to rise in the current bounce.
+    /// When this is zero, the player is falling.
+    rising: usize,
+    /// The camera, which views the part of the world around the player.
+    camera: VerticalCamera,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+}
+
+impl ClimberGame {
+
+    /// Construct a new ClimberGame object.
+    fn new() -> Self {
+        Self {
+            platforms: [Platform { x: 0, y: 0, width: 0 }; PLATFORM_COUNT],
+            player_x: 0,
+            player_y: 0,
+            rising: 0,
+            camera: VerticalCamera::new(),
+            screen: DotScreen::new_empty(),
+        }
+    }
+
+    /// Generate a random platform above the highest platform.
+    fn random_platform(rng: &mut dyn rand_core::RngCore, highest: usize) -> Platform {
+        let gap = MIN_PLATFORM_GAP + utils::random_below(rng, MAX_PLATFORM_GAP - MIN_PLATFORM_GAP + 1);
+        let width = 2 + utils::random_below(rng, 2);
+        let x = utils::random_below(rng, DotScreen::WIDTH - width + 1);
+        Platform { x, y: highest + gap, width }
+    }
+
+    /// Returns the current score for the game, based on the height climbed.
+    fn get_score(&self) -> usize {
+        self.camera.bottom() / ROWS_PER_POINT
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.get_score());
+        utils::show_tally(&mut components.display, self.get_score());
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the platforms.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.camera = VerticalCamera::new();
+
+        // The first platform spans the whole floor, so the player always begins with a bounce.
+        self.platforms[0] = Platform { x: 0, y: 0, width: DotScreen::WIDTH };
+        for index in 1..PLATFORM_COUNT {
+            self.platforms[index] = Self::random_platform(rng, self.platforms[index - 1].y);
+        }
+        self.player_x = DotScreen::WIDTH / 2;
+        self.player_y = 1;
+        self.rising = 0;
+    }
+}
+
+
+impl GamePlay for ClimberGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        match input.direction {
+            Some(Direction::Left) => {
+                self.player_x = (self.player_x + DotScreen::WIDTH - 1) % DotScreen::WIDTH;
+            },
+            Some(Direction::Right) => {
+                self.player_x = (self.player_x + 1) % DotScreen::WIDTH;
+            },
+            _ => {}
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This moves the player up or down,
+    ///   bouncing off any platform landed on, and scrolls the camera.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        if self.rising > 0 {
+            self.player_y += 1;
+            self.rising -= 1;
+        } else {
+            // Bounce if there is a platform directly beneath the player.
+            let (x, y) = (self.player_x, self.player_y);
+            let landed = self.platforms.iter().any(|platform| (platform.y + 1 == y) & platform.covers(x));
+            if landed {
+                self.rising = JUMP_HEIGHT;
+            } else if self.camera.is_below(y) | (y == 0) {
+                return false
+            } else {
+                self.player_y -= 1;
+                if self.camera.is_below(self.player_y) { return false }
+            }
+        }
+
+        self.camera.follow(self.player_y, CAMERA_ROW);
+
+        // Replace any platform that has scrolled out of view with a new one above the rest.
+        for index in 0..PLATFORM_COUNT {
+            if self.camera.is_below(self.platforms[index].y) {
+                let highest = self.platforms.iter().map(|platform| platform.y).max().unwrap_or(0);
+                self.platforms[index] = Self::random_platform(rng, highest);
+            }
+        }
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        for platform in self.platforms.iter() {
+            for x in platform.x..(platform.x + platform.width) {
+                if let Some(dot) = self.camera.to_screen(x, platform.y) {
+                    self.screen.add(&dot);
+                }
+            }
+        }
+        if let Some(dot) = self.camera.to_screen(self.player_x, self.player_y) {
+            self.screen.add(&dot);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the height climbed.
+    fn score(&self) -> usize {
+        self.get_score()
+    }
+
+    /// The score rises steadily, so its increases play no sound.
+    fn on_score(&self) -> Option<&'static Melody> {
+        None
+    }
+}
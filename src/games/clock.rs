@@ -0,0 +1,198 @@
+/// Implementation of the Clock app for an 8x8 LED Dot Screen.
+///
+/// The app shows the time of the real-time clock (see `Rtc`) as a binary clock, and
+///   lets the player set the date and time of the real-time clock with the JoyStick.
+use crate::{graphics, sound, Components, Direction};
+use crate::peripherals::{Date, DateTime, Dot, DotScreen};
+use super::{digits, utils};
+
+// Constants for the Clock app.
+//   The time between reads of the real-time clock.
+const TICK_INTERVAL_MS: usize = 100;
+//   The time between polls of the JoyStick, while waiting for it to be released.
+const RELEASE_POLL_MS: usize = 50;
+//   The x-index (column) of the tens digit of the hours, minutes, and seconds.
+//   The ones digit of each is in the following column.
+const DIGIT_COLUMNS: [usize; 3] = [0, 3, 6];
+//   The number of rows (the height) of each bit of the binary clock.
+const BIT_HEIGHT: usize = 2;
+
+
+/// The Title Screen for the Clock App (a clock face).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00111100,
+            0b01000010,
+            0b10000001,
+            0b11110001,
+            0b10010001,
+            0b10010001,
+            0b01000010,
+            0b00111100,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN, help: "Press to set the time." }
+game!(clock_app_loop);
+
+
+/// The loop which runs the Clock app.
+///
+/// The time is shown until the JoyStick is pushed in any direction, and pressing the
+///   button sets the date and time. Without a real-time clock, this only says so.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn clock_app_loop(components: &mut Components) {
+    const SCROLL_COLUMN_MS: usize = 70;
+    if components.rtc.is_none() {
+        graphics::scroll(components, &graphics::SmallFont, "NO CLOCK", SCROLL_COLUMN_MS);
+        return
+    }
+    // Don't let the press which started the app set the time.
+    while utils::poll_tick(components, RELEASE_POLL_MS).button {}
+
+    let mut now = None;
+    loop {
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        if input.pushed.is_some() { return }
+        now = components.rtc.as_mut().and_then(|rtc| rtc.now().ok()).or(now);
+        if input.pressed {
+            let time = now.unwrap_or(DateTime {
+                date: Date { year: 0, month: 1, day: 1 }, hours: 0, minutes: 0, seconds: 0,
+            });
+            if let Some(time) = set_time(components, time) {
+                if let Some(rtc) = components.rtc.as_mut() { rtc.set(&time).ok(); }
+            }
+            continue
+        }
+        if super::selection::is_switch_requested() { return }
+
+        let mut screen = DotScreen::new_empty();
+        if let Some(time) = now.as_ref() { draw_binary_clock(&mut screen, time) }
+        components.display.show(&screen);
+    }
+}
+
+
+/// Draw the time as a binary clock: a column for each digit of the hours, minutes, and
+///   seconds, lighting the bits of the digit from the bottom (the least significant bit) up.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * time   - The time to draw.
+fn draw_binary_clock(screen: &mut DotScreen, time: &DateTime) {
+    let values = [time.hours, time.minutes, time.seconds];
+    for (&left, &value) in DIGIT_COLUMNS.iter().zip(values.iter()) {
+        for (x, digit) in [(left, value / 10), (left + 1, value % 10)].iter().copied() {
+            for bit in 0..(DotScreen::HEIGHT / BIT_HEIGHT) {
+                if digit & (1 << bit) == 0 { continue }
+                screen.draw_v_line(&Dot { x, y: bit * BIT_HEIGHT }, BIT_HEIGHT);
+            }
+        }
+    }
+}
+
+
+/// A field of the date and time, set one after another.
+#[derive(Copy, Clone, PartialEq)]
+enum Field {
+    Year,
+    Month,
+    Day,
+    Hours,
+    Minutes,
+}
+
+impl Field {
+
+    /// Every field, in the order they are set.
+    const ALL: [Field; 5] = [Field::Year, Field::Month, Field::Day, Field::Hours, Field::Minutes];
+
+    /// Returns the value of the field (the year as its last two digits).
+    fn value(self, time: &DateTime) -> u8 {
+        match self {
+            Field::Year => { time.date.year },
+            Field::Month => { time.date.month },
+            Field::Day => { time.date.day },
+            Field::Hours => { time.hours },
+            Field::Minutes => { time.minutes },
+        }
+    }
+
+    /// Step the value of the field up or down, wrapping around its range.
+    ///   The day is kept within the month, as the year and month change.
+    fn adjust(self, time: &mut DateTime, up: bool) {
+        let step = |value: u8, min: u8, max: u8| {
+            if up {
+                if value >= max { min } else { value + 1 }
+            } else if value <= min {
+                max
+            } else {
+                value - 1
+            }
+        };
+        let days = Date::days_in_month(time.date.year, time.date.month);
+        match self {
+            Field::Year => { time.date.year = step(time.date.year, 0, 99) },
+            Field::Month => { time.date.month = step(time.date.month, 1, 12) },
+            Field::Day => { time.date.day = step(time.date.day, 1, days) },
+            Field::Hours => { time.hours = step(time.hours, 0, 23) },
+            Field::Minutes => { time.minutes = step(time.minutes, 0, 59) },
+        }
+        time.date.day = time.date.day.min(Date::days_in_month(time.date.year, time.date.month));
+    }
+}
+
+
+/// Set the date and time with the JoyStick, one field at a time (the year, month, day,
+///   hours, and minutes). The value of each field is shown as two digits, with a dot
+///   along the top row for each field reached so far.
+///
+/// Up and Down change the value of the field, and Right (or the button) moves on to the
+///   next field. Left moves back to the previous field, or cancels from the first field.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * time       - The date and time to begin from.
+///
+/// # Returns
+/// The date and time set (with the seconds at 0), once moving on from the last field,
+///   or None if cancelled.
+fn set_time(components: &mut Components, mut time: DateTime) -> Option<DateTime> {
+    let mut index = 0;
+    let result = loop {
+        let field = Field::ALL[index];
+        let value = field.value(&time);
+        let mut screen = DotScreen::new_empty();
+        digits::draw_glyph(&mut screen, b'0' + value / 10, 0);
+        digits::draw_glyph(&mut screen, b'0' + value % 10, digits::GLYPH_WIDTH + 1);
+        screen.draw_h_line(&Dot { x: 0, y: DotScreen::HEIGHT - 1 }, index + 1);
+        components.display.show(&screen);
+
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        // A switch to another game (requested on the console) cancels setting the time.
+        if super::selection::is_switch_requested() { break None }
+        let next = input.pressed | (input.pushed == Some(Direction::Right));
+        match input.pushed {
+            _ if next => {
+                if index + 1 == Field::ALL.len() {
+                    time.seconds = 0;
+                    break Some(time)
+                }
+                index += 1;
+            },
+            Some(Direction::Left) => {
+                if index == 0 { break None }
+                index -= 1;
+            },
+            Some(direction) => { field.adjust(&mut time, direction == Direction::Up) },
+            None => {},
+        }
+        if next | input.pushed.is_some() { components.play_effect(Some(&sound::BLIP)) }
+    };
+    while utils::poll_tick(components, RELEASE_POLL_MS).button {}
+    result
+}
@@ -0,0 +1,73 @@
+/// The difficulty levels a game may be played at, and the pre-game screen on which
+///   the player chooses one.
+use crate::{Components, Direction};
+use crate::peripherals::DotScreen;
+use crate::sound::Melody;
+use super::utils;
+
+
+/// A difficulty level, chosen before the games which offer a choice of difficulty
+///   (see `Game::offers_difficulty`). Each game decides what the level changes.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+
+    /// Every difficulty level, from the easiest to the hardest.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Returns the difficulty of a level, as stored in the settings (0 is easy, 1 is normal,
+    ///   2 is hard). Levels beyond the hardest are hard.
+    pub fn from_level(level: u8) -> Self {
+        Self::ALL[(level as usize).min(Self::ALL.len() - 1)]
+    }
+
+    /// Returns the level of the difficulty, as stored in the settings and reported to the leaderboard.
+    pub fn level(self) -> u8 {
+        self as u8
+    }
+}
+
+
+/// Run the pre-game screen on which the player chooses the difficulty level.
+///
+/// The levels are displayed as three bars of increasing height (easy, normal, and hard),
+///   with the bars up to the chosen level lit and the highest of them blinking. Left and
+///   Right (or Down and Up) change the level, and a button press starts the game.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * difficulty - The difficulty to begin with (e.g. the default from the settings).
+/// * move_sound - The sound played when the level changes (see `Game::on_menu_move`).
+///
+/// # Returns
+/// The chosen difficulty.
+pub fn choose_difficulty(
+    components: &mut Components, difficulty: Difficulty, move_sound: Option<&'static Melody>
+) -> Difficulty {
+    const TICK_INTERVAL_MS: usize = 150;
+    let mut level = difficulty.level();
+    let mut tick = 0usize;
+    loop {
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        // A switch to another game (requested on the console) leaves the level as it is.
+        if input.pressed | super::selection::is_switch_requested() { return Difficulty::from_level(level) }
+
+        let previous = level;
+        match input.direction {
+            Some(Direction::Left) | Some(Direction::Down) => { level = level.saturating_sub(1) },
+            Some(Direction::Right) | Some(Direction::Up) => { level = Difficulty::from_level(level + 1).level() },
+            None => {}
+        }
+        if level != previous { components.play_effect(move_sound) }
+
+        tick += 1;
+        let mut screen = DotScreen::new_empty();
+        utils::draw_difficulty(&mut screen, level as usize, tick % 2 == 0);
+        components.display.show(&screen);
+    }
+}
@@ -0,0 +1,175 @@
+/// A small 3x5 font of the digits and arithmetic symbols, for games which display numbers.
+///
+/// Two digits fit side by side on the screen, so larger numbers are scrolled across it.
+use crate::graphics::{self, Font, Glyph};
+use crate::peripherals::{Display, DotScreen};
+
+// Constants for the digit font.
+//   The width and height of each glyph.
+pub const GLYPH_WIDTH: usize = 3;
+pub const GLYPH_HEIGHT: usize = 5;
+//   The glyphs of the digits 0-9, as columns ordered left to right.
+//   Within each column, bit 4 is the top of the glyph.
+const DIGITS: [[u8; GLYPH_WIDTH]; 10] = [
+    [0b11111, 0b10001, 0b11111],
+    [0b01001, 0b11111, 0b00001],
+    [0b10111, 0b10101, 0b11101],
+    [0b10101, 0b10101, 0b11111],
+    [0b11100, 0b00100, 0b11111],
+    [0b11101, 0b10101, 0b10111],
+    [0b11111, 0b10101, 0b10111],
+    [0b10000, 0b10000, 0b11111],
+    [0b11111, 0b10101, 0b11111],
+    [0b11101, 0b10101, 0b11111],
+];
+//   The glyphs of the arithmetic symbols.
+const PLUS: [u8; GLYPH_WIDTH] = [0b00100, 0b01110, 0b00100];
+const EQUALS: [u8; GLYPH_WIDTH] = [0b01010, 0b01010, 0b01010];
+const QUESTION: [u8; GLYPH_WIDTH] = [0b10000, 0b10101, 0b11000];
+
+
+/// Returns the glyph of an ASCII digit or arithmetic symbol ('+', '=', or '?'), if there is one.
+pub fn glyph(symbol: u8) -> Option<&'static [u8; GLYPH_WIDTH]> {
+    match symbol {
+        b'0'..=b'9' => { Some(&DIGITS[(symbol - b'0') as usize]) },
+        b'+' => { Some(&PLUS) },
+        b'=' => { Some(&EQUALS) },
+        b'?' => { Some(&QUESTION) },
+        _ => { None },
+    }
+}
+
+/// The digit font, for drawing text with the graphics functionality (e.g. the marquee).
+pub struct DigitFont;
+
+impl Font for DigitFont {
+    fn height(&self) -> usize {
+        GLYPH_HEIGHT
+    }
+
+    fn glyph(&self, character: char) -> Option<Glyph> {
+        if !character.is_ascii() { return None }
+        glyph(character as u8).map(|glyph| Glyph::new(glyph))
+    }
+}
+
+/// Draw a single column of a glyph onto a screen, lighting the dots of the glyph.
+///
+/// # Arguments
+/// * screen       - The screen to draw onto.
+/// * x            - The x-index of the column to draw into. Columns off the screen are ignored.
+/// * glyph_column - The column of the glyph.
+pub fn draw_column(screen: &mut DotScreen, x: usize, glyph_column: u8) {
+    if x < DotScreen::WIDTH { screen.columns[x] |= graphics::to_screen_column(glyph_column, GLYPH_HEIGHT) }
+}
+
+/// Draw the glyph of an ASCII digit or arithmetic symbol onto a screen.
+///
+/// Symbols without a glyph are not drawn, and columns off the screen are ignored.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * symbol - The ASCII digit or arithmetic symbol.
+/// * left   - The x-index of the leftmost column of the glyph.
+pub fn draw_glyph(screen: &mut DotScreen, symbol: u8, left: usize) {
+    if let Some(glyph) = glyph(symbol) {
+        for (offset, &column) in glyph.iter().enumerate() {
+            draw_column(screen, left + offset, column);
+        }
+    }
+}
+
+/// Scroll a line of text across the display from right to left, until it has
+///   scrolled off the left edge of the screen.
+///
+/// Unlike `graphics::scroll`, this can't be interrupted by input.
+///
+/// # Arguments
+/// * display   - The display.
+/// * text      - The ASCII digits and arithmetic symbols to scroll.
+/// * column_ms - The time taken to scroll by a single column.
+pub fn scroll_text(display: &mut dyn Display, text: &[u8], column_ms: u16) {
+    let mut screen = DotScreen::new_empty();
+    let text = core::str::from_utf8(text).unwrap_or("");
+    for column in graphics::columns(&DigitFont, text) {
+        screen.shift_left(1, false);
+        screen.columns[DotScreen::WIDTH - 1] = column;
+        display.show(&screen);
+        arduino_uno::delay_ms(column_ms);
+    }
+}
+
+/// Returns a column of a line of text scrolling across the display from right to left,
+///   one column at a time (see `graphics::columns`).
+///
+/// # Arguments
+/// * text   - The ASCII digits and arithmetic symbols to scroll.
+/// * column - The index of the column, in the order the columns scroll onto the screen.
+///
+/// # Returns
+/// The column, or None once the text has scrolled off the left edge of the screen.
+pub fn scroll_column(text: &[u8], column: usize) -> Option<u8> {
+    let text = core::str::from_utf8(text).unwrap_or("");
+    graphics::columns(&DigitFont, text).nth(column)
+}
+
+/// Draw a number of one or two digits onto a screen, centered.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * number - The number to draw.
+///
+/// # Returns
+/// Whether the number was drawn. Numbers of more than two digits don't fit on the screen,
+///   so aren't drawn (see `show_score`).
+pub fn draw_number(screen: &mut DotScreen, number: usize) -> bool {
+    let mut buffer = graphics::NumberBuffer::default();
+    let digits = graphics::format_number(number as u32, &mut buffer).as_bytes();
+    if digits.len() > 2 { return false }
+
+    // The digits are separated by a single blank column.
+    let width = digits.len() * (GLYPH_WIDTH + 1) - 1;
+    let left = (DotScreen::WIDTH - width) / 2;
+    for (index, &digit) in digits.iter().enumerate() {
+        draw_glyph(screen, digit, left + index * (GLYPH_WIDTH + 1));
+    }
+    true
+}
+
+/// Draw the scoreboard of a game being played: the score, as two digits (with a leading
+///   zero, and larger scores showing their last two digits), and the remaining lives,
+///   as dots along the bottom row.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * score  - The current score.
+/// * lives  - The number of lives remaining, if the game has lives. At most 8 are drawn.
+pub fn draw_scoreboard(screen: &mut DotScreen, score: usize, lives: Option<usize>) {
+    let score = score % 100;
+    draw_glyph(screen, b'0' + (score / 10) as u8, 0);
+    draw_glyph(screen, b'0' + (score % 10) as u8, GLYPH_WIDTH + 1);
+    for life in 0..lives.unwrap_or(0).min(DotScreen::WIDTH) {
+        screen.add(&crate::peripherals::Dot { x: life, y: DotScreen::HEIGHT - 1 });
+    }
+}
+
+/// Display a score as a number.
+///
+/// Scores of one or two digits are shown on the screen for a couple of seconds,
+///   while larger scores are scrolled across the screen.
+///
+/// # Arguments
+/// * display - The display.
+/// * score   - The score to display.
+pub fn show_score(display: &mut dyn Display, score: usize) {
+    const SHOW_MS: u16 = 2000;
+    const SCROLL_COLUMN_MS: u16 = 90;
+    let mut screen = DotScreen::new_empty();
+    if draw_number(&mut screen, score) {
+        display.show(&screen);
+        arduino_uno::delay_ms(SHOW_MS);
+    } else {
+        let mut buffer = graphics::NumberBuffer::default();
+        scroll_text(display, graphics::format_number(score as u32, &mut buffer).as_bytes(), SCROLL_COLUMN_MS);
+    }
+}
@@ -0,0 +1,221 @@
+/// Implementation of the Falldown game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Falldown game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'F';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 80;
+//   The number of rows between consecutive floors, including the floor itself.
+const FLOOR_SPACING: usize = 3;
+//   The initial and minimum number of game ticks between each upward scroll.
+const INITIAL_SCROLL_TICKS: usize = 6;
+const MIN_SCROLL_TICKS: usize = 2;
+//   The number of floors survived after which the floors scroll faster.
+const SPEED_UP_FLOORS: usize = 10;
+
+
+/// The Title Screen for the Falldown Game ("F").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b01010000,
+            0b01010000,
+            0b01000000,
+            0b00000000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(falldown_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Falldown game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn falldown_game_loop(components: &mut Components) {
+    let mut game = FalldownGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The FalldownGame object.
+///
+/// Floors, each with a single gap, scroll upward. The player slides left and right along
+///   the floor they stand on, dropping through the gaps, and is carried upward by any
+///   floor they cannot drop through. The floors scroll faster over time, and the game
+///   ends when the player is pushed off the top of the screen.
+struct FalldownGame {
+    /// The x-index of the gap of each row which is a floor, indexed by y-index.
+    rows: [Option<usize>; DotScreen::HEIGHT],
+    /// The position of the player.
+    player: Dot,
+    /// The number of rows scrolled since the last floor was generated.
+    rows_since_floor: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of floors scrolled off the top of the screen.
+    floors_survived: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl FalldownGame {
+
+    /// Construct a new FalldownGame object.
+    fn new() -> Self {
+        Self {
+            rows: [None; DotScreen::HEIGHT],
+            player: Dot { x: 0, y: 0 },
+            rows_since_floor: 0,
+            screen: DotScreen::new_empty(),
+            floors_survived: 0,
+            tick: 0,
+        }
+    }
+
+    /// Returns whether the dot at the position is open (not part of a floor).
+    fn is_open(&self, x: usize, y: usize) -> bool {
+        match self.rows[y] {
+            Some(gap) => { gap == x },
+            None => { true },
+        }
+    }
+
+    /// Scroll the floors one row upward, generating a new row at the bottom.
+    fn scroll(&mut self, rng: &mut dyn rand_core::RngCore) {
+        if self.rows[DotScreen::HEIGHT - 1].is_some() { self.floors_survived += 1 }
+        self.rows.rotate_right(1);
+
+        self.rows_since_floor += 1;
+        self.rows[0] = if self.rows_since_floor == FLOOR_SPACING {
+            self.rows_since_floor = 0;
+            Some(utils::random_below(rng, DotScreen::WIDTH))
+        } else {
+            None
+        };
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.floors_survived);
+        utils::show_tally(&mut components.display, self.floors_survived);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the floors.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.rows = [None; DotScreen::HEIGHT];
+        self.rows_since_floor = 0;
+        for _ in 0..DotScreen::HEIGHT {
+            self.scroll(rng);
+        }
+        self.floors_survived = 0;
+        self.player = Dot { x: DotScreen::WIDTH / 2, y: DotScreen::HEIGHT - 1 };
+        while !self.is_open(self.player.x, self.player.y) {
+            self.player.y -= 1;
+        }
+        self.tick = 0;
+    }
+}
+
+
+impl GamePlay for FalldownGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        let x = self.player.x;
+        let target = match input.direction {
+            Some(Direction::Left) if x > 0 => { x - 1 },
+            Some(Direction::Right) if x < DotScreen::WIDTH - 1 => { x + 1 },
+            _ => { x },
+        };
+        if self.is_open(target, self.player.y) { self.player.x = target }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This drops the player, scrolls the floors,
+    ///   and checks whether the player has been pushed off the top of the screen.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+
+        // Drop the player if nothing is beneath them.
+        let Dot { x, y } = self.player;
+        if (y > 0) && self.is_open(x, y - 1) { self.player.y -= 1 }
+
+        let speed_ups = self.floors_survived / SPEED_UP_FLOORS;
+        let scroll_ticks = INITIAL_SCROLL_TICKS.saturating_sub(speed_ups).max(MIN_SCROLL_TICKS);
+        if self.tick % scroll_ticks == 0 {
+            self.scroll(rng);
+
+            // A floor which scrolled into the player carries them upward.
+            if !self.is_open(self.player.x, self.player.y) {
+                if self.player.y == DotScreen::HEIGHT - 1 { return false }
+                self.player.y += 1;
+            }
+        }
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        for (y, row) in self.rows.iter().enumerate() {
+            if let Some(gap) = row { self.screen.set_row(y, !(0b10000000 >> gap)) }
+        }
+        self.screen.add(&self.player);
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the number of floors survived.
+    fn score(&self) -> usize {
+        self.floors_survived
+    }
+}
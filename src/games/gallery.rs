@@ -0,0 +1,291 @@
+/// Implementation of the Shooting Gallery game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::graphics::{Animation, Bitmap, Sprite};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Shooting Gallery game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'G';
+//   The number of rows along the top of the screen along which the targets slide.
+const TARGET_ROWS: usize = 3;
+//   The y-index (row) of the cannon.
+const CANNON_ROW: usize = 0;
+//   The number of shots the player is given.
+const TOTAL_SHOTS: usize = 20;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 80;
+//   The fastest and slowest speeds of the targets, in game ticks per step.
+const FASTEST_TARGET_TICKS: usize = 2;
+const SLOWEST_TARGET_TICKS: usize = 6;
+//   The number of game ticks before a hit target reappears.
+const RESPAWN_TICKS: usize = 10;
+//   The maximum number of points awarded for a single hit.
+const MAX_COMBO: usize = 4;
+//   The frames of the burst shown where a target is hit (a dot, a plus, then a cross),
+//   and the number of game ticks each frame is shown for.
+const BURST_FRAMES: [Bitmap; 3] = [&[0b000, 0b010, 0b000], &[0b010, 0b111, 0b010], &[0b101, 0b000, 0b101]];
+const BURST_FRAME_TICKS: usize = 2;
+
+
+/// The Title Screen for the Shooting Gallery Game ("G").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00111100,
+            0b01000010,
+            0b01000010,
+            0b01001010,
+            0b01001100,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(gallery_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Shooting Gallery game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn gallery_game_loop(components: &mut Components) {
+    let mut game = GalleryGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A target sliding along one of the top rows.
+#[derive(Copy, Clone)]
+struct Target {
+    /// The x-index of the target, which may be off the screen
+    ///   (-1 or WIDTH) as it wraps around.
+    x: isize,
+    /// The direction the target slides, either 1 (rightward) or -1 (leftward).
+    heading: isize,
+    /// The number of game ticks between each step of the target.
+    step_ticks: usize,
+    /// The countdown until the target reappears, after it has been hit.
+    respawn: Countdown,
+}
+
+impl Target {
+
+    /// Create a new Target with a random heading and speed, entering from off the screen.
+    fn random(rng: &mut dyn rand_core::RngCore) -> Self {
+        let heading = if utils::random_below(rng, 2) == 0 { 1 } else { -1 };
+        let x = if heading == 1 { -1 } else { DotScreen::WIDTH as isize };
+        let step_ticks = FASTEST_TARGET_TICKS
+            + utils::random_below(rng, SLOWEST_TARGET_TICKS - FASTEST_TARGET_TICKS + 1);
+        Self { x, heading, step_ticks, respawn: Countdown::new(0) }
+    }
+
+    /// Returns whether the target is currently on the screen.
+    fn is_visible(&self) -> bool {
+        self.respawn.is_expired() & (self.x >= 0) & (self.x < DotScreen::WIDTH as isize)
+    }
+}
+
+
+/// The GalleryGame object.
+///
+/// Targets slide across the top rows of the screen at varying speeds, while the player
+///   moves a cannon along the bottom row and fires at them. Consecutive hits build a combo,
+///   awarding more points per hit, while a miss resets the combo. The game ends once
+///   every shot has been fired.
+struct GalleryGame {
+    /// The targets, one per row, ordered from the top row down.
+    targets: [Target; TARGET_ROWS],
+    /// The x-index of the cannon.
+    cannon_x: usize,
+    /// The shot in flight, if any.
+    shot: Option<Dot>,
+    /// The burst shown where the last target was hit, while its animation plays.
+    burst: Option<Sprite>,
+    /// The animation of the burst.
+    burst_animation: Animation,
+    /// The number of shots remaining.
+    shots_remaining: usize,
+    /// The number of consecutive hits.
+    combo: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The total number of points scored.
+    score: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl GalleryGame {
+
+    /// Construct a new GalleryGame object.
+    fn new() -> Self {
+        let target = Target { x: -1, heading: 1, step_ticks: SLOWEST_TARGET_TICKS, respawn: Countdown::new(0) };
+        Self {
+            targets: [target; TARGET_ROWS],
+            cannon_x: DotScreen::WIDTH / 2,
+            shot: None,
+            burst: None,
+            burst_animation: Animation::new(&BURST_FRAMES, BURST_FRAME_TICKS, false),
+            shots_remaining: TOTAL_SHOTS,
+            combo: 0,
+            screen: DotScreen::new_empty(),
+            score: 0,
+            tick: 0,
+        }
+    }
+
+    /// Returns the y-index of the row of the target with the provided index.
+    fn target_row(index: usize) -> usize {
+        DotScreen::HEIGHT - 1 - index
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.score);
+        utils::show_tally(&mut components.display, self.score);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the targets.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        for target in self.targets.iter_mut() {
+            *target = Target::random(rng);
+        }
+        self.cannon_x = DotScreen::WIDTH / 2;
+        self.shot = None;
+        self.burst = None;
+        self.shots_remaining = TOTAL_SHOTS;
+        self.combo = 0;
+        self.score = 0;
+        self.tick = 0;
+    }
+}
+
+
+impl GamePlay for GalleryGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        match input.direction {
+            Some(Direction::Left) if self.cannon_x > 0 => { self.cannon_x -= 1 },
+            Some(Direction::Right) if self.cannon_x < DotScreen::WIDTH - 1 => { self.cannon_x += 1 },
+            _ => {}
+        }
+        if input.pressed && self.shot.is_none() && self.shots_remaining > 0 {
+            self.shots_remaining -= 1;
+            self.shot = Some(Dot { x: self.cannon_x, y: CANNON_ROW });
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This slides the targets, advances the shot,
+    ///   and resolves any hits or misses.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. whether a shot remains to be fired or is in flight.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+
+        self.burst_animation.tick();
+        if self.burst_animation.is_finished() { self.burst = None }
+
+        for target in self.targets.iter_mut() {
+            if !target.respawn.is_expired() {
+                if target.respawn.tick() { *target = Target::random(rng) }
+            } else if self.tick % target.step_ticks == 0 {
+                target.x += target.heading;
+                // Wrap around once the target has left the screen.
+                if target.x < -1 { target.x = DotScreen::WIDTH as isize }
+                if target.x > DotScreen::WIDTH as isize { target.x = -1 }
+            }
+        }
+
+        if let Some(mut shot) = self.shot {
+            shot.y += 1;
+            self.shot = Some(shot);
+            for (index, target) in self.targets.iter_mut().enumerate() {
+                if target.is_visible() && (target.x as usize == shot.x) && (Self::target_row(index) == shot.y) {
+                    // Hit! Award points for the combo and remove the target for a while.
+                    self.combo = (self.combo + 1).min(MAX_COMBO);
+                    self.score += self.combo;
+                    target.respawn = Countdown::new(RESPAWN_TICKS);
+                    self.shot = None;
+                    // The burst is centered on the target.
+                    self.burst = Some(Sprite::new(BURST_FRAMES[0], 3, target.x - 1, shot.y as isize - 1));
+                    self.burst_animation.restart();
+                    return self.shots_remaining > 0
+                }
+            }
+            if shot.y == DotScreen::HEIGHT - 1 {
+                // Miss! The shot left the top of the screen.
+                self.combo = 0;
+                self.shot = None;
+            }
+        }
+        (self.shots_remaining > 0) | self.shot.is_some()
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        for (index, target) in self.targets.iter().enumerate() {
+            if target.is_visible() {
+                self.screen.add(&Dot { x: target.x as usize, y: Self::target_row(index) });
+            }
+        }
+        if let Some(shot) = self.shot {
+            self.screen.add(&shot);
+        }
+        if let Some(mut burst) = self.burst {
+            burst.bitmap = self.burst_animation.frame();
+            burst.blit(&mut self.screen);
+        }
+        self.screen.add(&Dot { x: self.cannon_x, y: CANNON_ROW });
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the points for the targets hit.
+    fn score(&self) -> usize {
+        self.score
+    }
+}
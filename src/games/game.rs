@@ -0,0 +1,326 @@
+/// The interfaces between the framework (the selection screen and the game loop) and the games.
+use crate::Components;
+use crate::peripherals::{haptics, Display, DotScreen};
+use crate::sound::{self, Melody};
+use super::{digits, overlay::{self, OverlayChoice}, selection, utils::{self, TickInput}, Difficulty, GameAssets, GameSwitch};
+
+
+/// A game, which can be selected and played from the selection screen.
+///
+/// Every game implements this with the `game!` macro, which declares it as the static
+///   `GAME` of the game's module; registering the game is then a single line of the
+///   registry of the selection screen.
+pub trait Game: Sync {
+
+    /// Returns the assets of the game, e.g. its title screen.
+    fn assets(&self) -> &'static GameAssets;
+
+    /// Returns the identifier of the game, used when reporting scores (see `Components::report_score`),
+    ///   or None if the game reports no scores (e.g. a two-player game).
+    fn id(&self) -> Option<u8> { None }
+
+    /// Returns whether the game offers a choice of difficulty, in which case the player
+    ///   chooses one (see `choose_difficulty`) before the game runs.
+    fn offers_difficulty(&self) -> bool { false }
+
+    /// Configure the game with the difficulty chosen by the player, before it runs.
+    ///
+    /// This is only called for games which offer a choice of difficulty.
+    fn configure(&self, _difficulty: Difficulty) {}
+
+    /// Returns the sound played when the selection moves onto the game (on the selection
+    ///   screen), and when moving through its difficulty levels, if any.
+    fn on_menu_move(&self) -> Option<&'static Melody> { Some(&sound::BLIP) }
+
+    /// Run the game, returning once it is over (after its game over screen) or the
+    ///   player quits it, after which the selection screen runs again.
+    ///
+    /// # Arguments
+    /// * components - The peripheral components.
+    ///
+    /// # Returns
+    /// The game to switch directly to instead, skipping the selection screen, if the
+    ///   quick-switch shortcut (or the console) requested one (see `quick_switch`).
+    fn run(&self, components: &mut Components) -> Option<GameSwitch>;
+}
+
+
+/// The game-play of a game, split into the phases of each game tick.
+///
+/// The framework owns the game loop (see `play`): every tick it polls the JoyStick,
+///   then the game handles the input, updates its state, and renders it. A game whose
+///   display is animated throughout each tick (see `is_animated`), or which is played by
+///   two players (see `is_versus`), has the game loop poll for it accordingly. Any game
+///   can be paused from the game loop, opening the overlay menu (see `pause`).
+///
+/// The framework also plays the sounds of the game-play: whenever the score increases
+///   (`on_score`), and once the game is over (`on_death`, or `on_victory` if the player
+///   has won the game, see `is_won`). Every hook has a default sound,
+///   which a game overrides only to change it (or None, to stay silent). The score and
+///   lives are shown on the scoreboard displays, if there are any, and the brightness of the
+///   display follows the ambient light, if set to (see `Components::adjust_brightness`).
+pub trait GamePlay {
+
+    /// Returns the length of a game tick, in milliseconds.
+    fn tick_interval_ms(&self) -> usize;
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput);
+
+    /// Returns whether the game is played by two players, in which case the JoySticks of
+    ///   both are polled (see `utils::poll_tick2`), the input of the second player being
+    ///   handled by `handle_input2`.
+    fn is_versus(&self) -> bool { false }
+
+    /// Handle the JoyStick input of the second player received over a game tick,
+    ///   before that of the first player (see `is_versus`).
+    fn handle_input2(&mut self, _input: TickInput) {}
+
+    /// Returns whether the display is animated throughout each game tick (see `animate`),
+    ///   rather than only showing the screen of the game once the tick is over.
+    fn is_animated(&self) -> bool { false }
+
+    /// Animate the display during a game tick (see `utils::animate_tick`), if the game
+    ///   is animated (see `is_animated`).
+    ///
+    /// # Arguments
+    /// * display    - The display.
+    /// * elapsed_ms - The time elapsed since the tick began.
+    fn animate(&self, _display: &mut dyn Display, _elapsed_ms: usize) {}
+
+    /// Returns whether a fresh press of the JoyStick button pauses the game (see `pause`),
+    ///   rather than being handled as input. Otherwise, a long press of the button pauses
+    ///   the game (see `TickInput::held`).
+    fn pauses_on_press(&self) -> bool { false }
+
+    /// Pause the game, opening the overlay menu, and carry out the choice made.
+    ///
+    /// By default, the overlay menu shows the paused screen of the game (see `paused_screen`),
+    ///   and restarting restarts the game (see `restart`).
+    ///
+    /// # Arguments
+    /// * components - The peripheral components.
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once the player quits.
+    fn pause(&mut self, components: &mut Components) -> bool {
+        match overlay::open(components, &self.paused_screen()) {
+            OverlayChoice::Resume => { true },
+            OverlayChoice::Restart => {
+                self.restart(&mut components.analog);
+                true
+            },
+            OverlayChoice::Quit | OverlayChoice::SaveAndQuit => { false },
+        }
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen;
+
+    /// Restart the game from the beginning, on restarting it from the overlay menu.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore);
+
+    /// Update the game state, once every game tick.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once the game is over.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool;
+
+    /// Render the game state onto the screen of the game.
+    fn render(&mut self);
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display);
+
+    /// Returns the current score, shown on the scoreboard and whose increases play
+    ///   the `on_score` sound. Games without a score have 0.
+    fn score(&self) -> usize { 0 }
+
+    /// Returns the number of lives remaining, shown on the scoreboard, if the game has lives.
+    fn lives(&self) -> Option<usize> { None }
+
+    /// Returns the sound played whenever the score increases, if any.
+    fn on_score(&self) -> Option<&'static Melody> { Some(&sound::BLIP) }
+
+    /// Returns the sound played once the game is over, if any.
+    fn on_death(&self) -> Option<&'static Melody> { Some(&sound::BUZZ) }
+
+    /// Returns whether the game is over because the player has won it (e.g. a puzzle
+    ///   solved), rather than lost it.
+    fn is_won(&self) -> bool { false }
+
+    /// Returns the sound played once the game is over, if the player has won it, if any.
+    fn on_victory(&self) -> Option<&'static Melody> { Some(&sound::FANFARE) }
+}
+
+
+/// Play a game, running its game loop until the game is over.
+///
+/// The game-play starts with a countdown (see `utils::countdown`), as it does again
+///   whenever it is resumed after pausing.
+/// # Arguments
+/// * game       - The game.
+/// * components - The peripheral components.
+///
+/// # Returns
+/// Whether the game is over, i.e. false if the player quit the game instead.
+pub fn play<G: GamePlay>(game: &mut G, components: &mut Components) -> bool {
+    utils::countdown(components);
+    run(game, components, |_| None) == Outcome::Over
+}
+
+
+/// How a run of the game loop ended, see `run`.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Outcome {
+    /// The game is over.
+    Over,
+    /// The player quit the game.
+    Quit,
+    /// The game-play was completed (e.g. a level of the game).
+    Complete,
+}
+
+/// Run the game loop of a game, until the game is over, the player quits it,
+///   or the game-play is judged to have ended otherwise.
+///
+/// # Arguments
+/// * game       - The game.
+/// * components - The peripheral components.
+/// * judge      - Called after each update of the game state, returning how the
+///                  game-play ended, if it has.
+pub fn run<G, F>(game: &mut G, components: &mut Components, mut judge: F) -> Outcome
+  where G: GamePlay, F: FnMut(&G) -> Option<Outcome>
+{
+    game.render();
+    game.show(&mut components.display);
+    show_scoreboard(game, components);
+    loop {
+        let input = poll(game, components);
+        let pausing = if game.pauses_on_press() { input.pressed } else { input.held };
+        if pausing {
+            if !game.pause(components) { return Outcome::Quit }
+            utils::countdown(components);
+            game.render();
+            game.show(&mut components.display);
+            continue
+        }
+        game.handle_input(input);
+
+        // Update the game state. If unsuccessful, the game is over.
+        let score = game.score();
+        if !game.update(&mut components.analog) {
+            if game.is_won() {
+                components.play_effect(game.on_victory());
+                components.play_haptic(&haptics::VICTORY);
+            } else {
+                components.play_effect(game.on_death());
+                components.play_haptic(&haptics::GAME_OVER);
+            }
+            return Outcome::Over
+        }
+        if game.score() > score {
+            // Scores which rise steadily play no sound, and likewise no haptic tap.
+            let effect = game.on_score();
+            if effect.is_some() { components.play_haptic(&haptics::TAP) }
+            components.play_effect(effect);
+        }
+        components.tick_sound();
+        // A switch to another game (requested on the console) quits this one.
+        if selection::is_switch_requested() { return Outcome::Quit }
+        if let Some(outcome) = judge(game) { return outcome }
+
+        // Display the game state to the LED Dot Display.
+        game.render();
+        game.show(&mut components.display);
+        show_scoreboard(game, components);
+    }
+}
+
+/// Poll the JoyStick for a single game tick of a game, animating the display throughout
+///   the tick if the game is animated, and handing the input of the second player to the
+///   game if it is played by two players.
+///
+/// # Returns
+/// The input of the (first) player.
+fn poll<G: GamePlay>(game: &mut G, components: &mut Components) -> TickInput {
+    let interval_ms = game.tick_interval_ms();
+    if game.is_animated() {
+        utils::animate_tick(components, interval_ms, |display, elapsed_ms| game.animate(display, elapsed_ms))
+    } else if game.is_versus() {
+        let [input, input2] = utils::poll_tick2(components, interval_ms);
+        game.handle_input2(input2);
+        input
+    } else {
+        utils::poll_tick(components, interval_ms)
+    }
+}
+
+/// Show the score and lives of the game on the scoreboard display, and the score on the
+///   7-segment display, if there are either, and alongside the screen of the game if the
+///   display has room for it (see `Display.show_status`).
+fn show_scoreboard<G: GamePlay>(game: &G, components: &mut Components) {
+    components.display.show_status(game.score(), game.lives());
+    if let Some(score_digits) = components.score_digits.as_mut() { score_digits.show_number(game.score() as u32) }
+    if let Some(scoreboard) = components.scoreboard.as_mut() {
+        let mut screen = DotScreen::new_empty();
+        digits::draw_scoreboard(&mut screen, game.score(), game.lives());
+        scoreboard.show(&screen);
+    }
+}
+
+
+/// Declare the game of a module as the static `GAME`, with the assets of the module
+///   (see `game_assets!`) and run by the provided game loop.
+///
+/// A game which reports scores also provides its identifier, and a game which offers a
+///   choice of difficulty also provides the function configuring it with the chosen
+///   difficulty (see `Game::configure`), in that order.
+///
+/// # Example
+/// ```ignore
+/// game_assets! { title: TITLE_SCREEN }
+/// game!(snake_game_loop, id: GAME_ID, configure: configure);
+/// ```
+macro_rules! game {
+    ($game_loop:path $(, id: $id:expr)? $(, configure: $configure:path)? $(,)?) => {
+        /// The game, as registered with the selection screen.
+        pub static GAME: &dyn $crate::games::Game = &GameEntry;
+
+        /// The implementation of the Game trait of the module.
+        struct GameEntry;
+
+        impl $crate::games::Game for GameEntry {
+            fn assets(&self) -> &'static $crate::games::GameAssets {
+                &ASSETS
+            }
+
+            $(
+                fn id(&self) -> Option<u8> {
+                    Some($id)
+                }
+            )?
+
+            $(
+                fn offers_difficulty(&self) -> bool {
+                    true
+                }
+
+                fn configure(&self, difficulty: $crate::games::Difficulty) {
+                    $configure(difficulty)
+                }
+            )?
+
+            fn run(&self, components: &mut $crate::Components) -> Option<$crate::games::GameSwitch> {
+                $game_loop(components);
+                $crate::games::selection::take_switch()
+            }
+        }
+    };
+}
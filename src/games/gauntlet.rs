@@ -0,0 +1,464 @@
+/// Implementation of the Micro-Game Gauntlet for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Micro-Game Gauntlet.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'W';
+//   The number of lives the player begins with.
+const LIVES: usize = 3;
+//   The length of a single tick of a micro-game.
+const TICK_INTERVAL_MS: usize = 80;
+//   The number of ticks of a micro-game at the slowest speed, and the number
+//   removed at each faster speed.
+const MICRO_GAME_TICKS: usize = 50;
+const TICKS_PER_SPEED_UP: usize = 6;
+//   The fastest speed.
+const MAX_SPEED: usize = 3;
+//   The number of micro-games cleared after which the gauntlet speeds up.
+const CLEARED_PER_SPEED_UP: usize = 3;
+//   The number of ticks the prompt of each micro-game is shown before it begins.
+const PROMPT_TICKS: usize = 9;
+//   The number of ticks the remaining lives are shown after each micro-game.
+const LIVES_TICKS: usize = 8;
+//   The number of rocks to dodge in the "dodge!" micro-game.
+const ROCKS: usize = 4;
+
+
+/// The Title Screen for the Micro-Game Gauntlet ("W").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b00000100,
+            0b00001000,
+            0b00001000,
+            0b00000100,
+            0b01111110,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(gauntlet_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Micro-Game Gauntlet.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn gauntlet_game_loop(components: &mut Components) {
+    let mut game = GauntletGame::new(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A micro-game: a single challenge lasting a few seconds, played as part of the gauntlet.
+///
+/// Unlike a full game, a micro-game does not play in the game loop itself. The gauntlet
+///   sets it up, ticks it (handing it the input and displaying the screen on its behalf)
+///   until it is decided or its time runs out, and then asks it to judge the outcome.
+trait MicroGame {
+
+    /// Returns the screen shown to prompt the player before the micro-game begins.
+    fn prompt(&self) -> &'static DotScreen;
+
+    /// Prepare the micro-game to be played.
+    ///
+    /// # Arguments
+    /// * rng   - The Random Number Generator.
+    /// * speed - The speed of the gauntlet, from 0 up to `MAX_SPEED`.
+    fn setup(&mut self, rng: &mut dyn rand_core::RngCore, speed: usize);
+
+    /// Advance the micro-game by a single tick, drawing it onto the screen.
+    ///
+    /// # Arguments
+    /// * input  - The input received during the tick.
+    /// * screen - The screen to draw onto. This is cleared before each tick.
+    ///
+    /// # Returns
+    /// Whether the micro-game is still undecided. Once decided, it is judged immediately.
+    fn tick(&mut self, input: &TickInput, screen: &mut DotScreen) -> bool;
+
+    /// Returns whether the player won the micro-game.
+    fn judge(&self) -> bool;
+}
+
+
+/// The prompt for the "press now!" micro-game (an exclamation mark).
+static PRESS_PROMPT: DotScreen =
+    DotScreen::new([0, 0, 0, 0b01111010, 0b01111010, 0, 0, 0]);
+
+/// Press the button as soon as the screen lights up, but not before.
+struct PressNow {
+    /// The tick on which the screen lights up.
+    signal_tick: usize,
+    /// The tick on which the button was first pressed, if it has been.
+    pressed_tick: Option<usize>,
+    /// The number of ticks elapsed.
+    tick: usize,
+}
+
+impl MicroGame for PressNow {
+
+    fn prompt(&self) -> &'static DotScreen {
+        &PRESS_PROMPT
+    }
+
+    fn setup(&mut self, rng: &mut dyn rand_core::RngCore, speed: usize) {
+        let latest = MICRO_GAME_TICKS - TICKS_PER_SPEED_UP * speed;
+        self.signal_tick = latest / 4 + utils::random_below(rng, latest / 2);
+        self.pressed_tick = None;
+        self.tick = 0;
+    }
+
+    fn tick(&mut self, input: &TickInput, screen: &mut DotScreen) -> bool {
+        self.tick += 1;
+        if input.button { self.pressed_tick = Some(self.tick) }
+        if self.tick >= self.signal_tick {
+            *screen = DotScreen::new_full();
+        } else if self.tick % 2 == 0 {
+            screen.add(&Dot { x: DotScreen::WIDTH / 2, y: DotScreen::HEIGHT / 2 });
+        }
+        self.pressed_tick.is_none()
+    }
+
+    fn judge(&self) -> bool {
+        self.pressed_tick.map_or(false, |tick| tick >= self.signal_tick)
+    }
+}
+
+
+/// The prompt for the "dodge!" micro-game (a rock above the player).
+static DODGE_PROMPT: DotScreen =
+    DotScreen::new([0, 0, 0b00100000, 0b00100001, 0b00100000, 0, 0, 0]);
+
+/// Move along the bottom row, dodging the rocks falling from above.
+struct Dodge {
+    /// The x-index of the player along the bottom row.
+    player_x: usize,
+    /// The x-index of each rock.
+    rock_xs: [usize; ROCKS],
+    /// The height of each rock above the bottom row, which may be above the screen.
+    ///   A rock is removed once it has passed the bottom row.
+    rock_heights: [Option<usize>; ROCKS],
+    /// The number of ticks between each fall of the rocks.
+    fall_ticks: usize,
+    /// Whether the player has been hit.
+    hit: bool,
+    /// The number of ticks elapsed.
+    tick: usize,
+}
+
+impl MicroGame for Dodge {
+
+    fn prompt(&self) -> &'static DotScreen {
+        &DODGE_PROMPT
+    }
+
+    fn setup(&mut self, rng: &mut dyn rand_core::RngCore, speed: usize) {
+        self.player_x = DotScreen::WIDTH / 2;
+        for rock in 0..ROCKS {
+            self.rock_xs[rock] = utils::random_below(rng, DotScreen::WIDTH);
+            self.rock_heights[rock] = Some(DotScreen::HEIGHT - 1 + 3 * rock);
+        }
+        self.fall_ticks = fall_ticks(speed);
+        self.hit = false;
+        self.tick = 0;
+    }
+
+    fn tick(&mut self, input: &TickInput, screen: &mut DotScreen) -> bool {
+        self.tick += 1;
+        self.player_x = step_along_row(self.player_x, input);
+        if self.tick % self.fall_ticks == 0 {
+            for height in self.rock_heights.iter_mut() {
+                *height = height.and_then(|height| height.checked_sub(1));
+            }
+        }
+
+        for (&x, &height) in self.rock_xs.iter().zip(self.rock_heights.iter()) {
+            match height {
+                Some(0) if x == self.player_x => { self.hit = true },
+                Some(y) if y < DotScreen::HEIGHT => { screen.add(&Dot { x, y }) },
+                _ => {}
+            }
+        }
+        screen.add(&Dot { x: self.player_x, y: 0 });
+        !self.hit
+    }
+
+    fn judge(&self) -> bool {
+        !self.hit
+    }
+}
+
+
+/// The prompt for the "catch!" micro-game (a basket).
+static CATCH_PROMPT: DotScreen =
+    DotScreen::new([0, 0, 0b00000110, 0b00000011, 0b00000011, 0b00000110, 0, 0]);
+
+/// Move the basket along the bottom row to catch the falling egg.
+struct Catch {
+    /// The x-index of the basket along the bottom row.
+    basket_x: usize,
+    /// The position of the egg.
+    egg: Dot,
+    /// The number of ticks between each fall of the egg.
+    fall_ticks: usize,
+    /// Whether the egg was caught, once it has landed.
+    caught: Option<bool>,
+    /// The number of ticks elapsed.
+    tick: usize,
+}
+
+impl MicroGame for Catch {
+
+    fn prompt(&self) -> &'static DotScreen {
+        &CATCH_PROMPT
+    }
+
+    fn setup(&mut self, rng: &mut dyn rand_core::RngCore, speed: usize) {
+        self.basket_x = DotScreen::WIDTH / 2;
+        self.egg = Dot { x: utils::random_below(rng, DotScreen::WIDTH), y: DotScreen::HEIGHT - 1 };
+        self.fall_ticks = fall_ticks(speed) + 1;
+        self.caught = None;
+        self.tick = 0;
+    }
+
+    fn tick(&mut self, input: &TickInput, screen: &mut DotScreen) -> bool {
+        self.tick += 1;
+        self.basket_x = step_along_row(self.basket_x, input);
+        if self.tick % self.fall_ticks == 0 {
+            if self.egg.y == 1 {
+                self.caught = Some(self.egg.x == self.basket_x);
+            } else {
+                self.egg.move_down();
+            }
+        }
+        screen.add(&self.egg);
+        screen.add(&Dot { x: self.basket_x, y: 0 });
+        self.caught.is_none()
+    }
+
+    fn judge(&self) -> bool {
+        self.caught == Some(true)
+    }
+}
+
+
+/// Returns the number of ticks between each fall of the falling objects at the speed.
+fn fall_ticks(speed: usize) -> usize {
+    (3 - speed / 2).max(1)
+}
+
+/// Move an x-index along a row, one step in the direction of the input (if Left or Right).
+fn step_along_row(x: usize, input: &TickInput) -> usize {
+    match input.direction {
+        Some(Direction::Left) => { x.saturating_sub(1) },
+        Some(Direction::Right) => { (x + 1).min(DotScreen::WIDTH - 1) },
+        _ => { x },
+    }
+}
+
+
+/// The micro-games of the gauntlet.
+struct MicroGames {
+    press: PressNow,
+    dodge: Dodge,
+    catch: Catch,
+}
+
+impl MicroGames {
+
+    /// The number of micro-games.
+    const COUNT: usize = 3;
+
+    /// Returns the micro-game of the provided index, from 0 up to `COUNT`.
+    fn get(&mut self, index: usize) -> &mut dyn MicroGame {
+        match index {
+            0 => { &mut self.press },
+            1 => { &mut self.dodge },
+            _ => { &mut self.catch },
+        }
+    }
+}
+
+
+/// The stage of the gauntlet, around the micro-game being played.
+enum Stage {
+    /// The prompt of the micro-game is shown until the countdown expires.
+    Prompt(Countdown),
+    /// The micro-game is played until it is decided, or its countdown expires.
+    Playing { remaining: Countdown, undecided: bool },
+    /// The remaining lives are shown (blinking, if one was lost) until the countdown expires.
+    Lives { lost: bool, remaining: Countdown },
+}
+
+
+/// The GauntletGame object.
+///
+/// The player faces a gauntlet of micro-games in a random order, each lasting only a few
+///   seconds, and each introduced by its prompt. Losing a micro-game costs a life, and the
+///   gauntlet speeds up as micro-games are cleared. The game ends once every life is lost.
+struct GauntletGame {
+    /// The micro-games of the gauntlet.
+    micro_games: MicroGames,
+    /// The index of the micro-game being played.
+    current: usize,
+    /// The stage of the gauntlet.
+    stage: Stage,
+    /// The number of lives remaining.
+    lives: usize,
+    /// The number of micro-games cleared.
+    cleared: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+}
+
+impl GauntletGame {
+
+    /// Construct a new GauntletGame object.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to choose the first micro-game.
+    fn new(rng: &mut dyn rand_core::RngCore) -> Self {
+        Self {
+            micro_games: MicroGames {
+                press: PressNow { signal_tick: 0, pressed_tick: None, tick: 0 },
+                dodge: Dodge {
+                    player_x: 0, rock_xs: [0; ROCKS], rock_heights: [None; ROCKS], fall_ticks: 1, hit: false, tick: 0
+                },
+                catch: Catch { basket_x: 0, egg: Dot { x: 0, y: 0 }, fall_ticks: 1, caught: None, tick: 0 },
+            },
+            current: utils::random_below(rng, MicroGames::COUNT),
+            stage: Stage::Prompt(Countdown::new(PROMPT_TICKS)),
+            lives: LIVES,
+            cleared: 0,
+            screen: DotScreen::new_empty(),
+        }
+    }
+
+    /// Returns the speed of the gauntlet, from 0 up to `MAX_SPEED`.
+    fn speed(&self) -> usize {
+        (self.cleared / CLEARED_PER_SPEED_UP).min(MAX_SPEED)
+    }
+
+    /// Draw the remaining lives onto the screen, as a dot for each in the middle row.
+    fn render_lives(&mut self) {
+        self.screen.clear();
+        for life in 0..self.lives {
+            self.screen.add(&Dot { x: 2 * life + 1, y: DotScreen::HEIGHT / 2 });
+        }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        components.report_score(GAME_ID, self.cleared);
+        utils::show_tally(&mut components.display, self.cleared);
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+impl GamePlay for GauntletGame {
+
+    /// Returns the length of a single tick of a micro-game.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a tick, ticking the micro-game being played.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Stage::Playing { undecided, .. } = &mut self.stage {
+            self.screen.clear();
+            *undecided = self.micro_games.get(self.current).tick(&input, &mut self.screen);
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every tick. This moves the gauntlet through the stages around
+    ///   each micro-game: its prompt, its play, and its judgement (after which the remaining
+    ///   lives are shown), and then on to the next micro-game.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to choose and set up the micro-games.
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once every life is lost.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        let speed = self.speed();
+        match &mut self.stage {
+            Stage::Prompt(remaining) => {
+                if remaining.tick() {
+                    self.micro_games.get(self.current).setup(rng, speed);
+                    let remaining = Countdown::new(MICRO_GAME_TICKS - TICKS_PER_SPEED_UP * speed);
+                    self.stage = Stage::Playing { remaining, undecided: true };
+                }
+            },
+            Stage::Playing { remaining, undecided } => {
+                let ticks_up = remaining.tick();
+                if !*undecided | ticks_up {
+                    let won = self.micro_games.get(self.current).judge();
+                    if won { self.cleared += 1 } else { self.lives -= 1 }
+                    if self.lives == 0 { return false }
+                    self.stage = Stage::Lives { lost: !won, remaining: Countdown::new(LIVES_TICKS) };
+                }
+            },
+            Stage::Lives { remaining, .. } => {
+                if remaining.tick() {
+                    self.current = utils::random_below(rng, MicroGames::COUNT);
+                    self.stage = Stage::Prompt(Countdown::new(PROMPT_TICKS));
+                }
+            },
+        }
+        true
+    }
+
+    /// Draw the current game state onto the screen: the prompt of the micro-game, or the
+    ///   remaining lives. The micro-game draws itself as it is ticked.
+    fn render(&mut self) {
+        match &self.stage {
+            Stage::Prompt(_) => { self.screen = *self.micro_games.get(self.current).prompt() },
+            Stage::Playing { .. } => {},
+            Stage::Lives { lost, remaining } => {
+                let blink_off = *lost & (remaining.remaining() % 4 < 2);
+                self.render_lives();
+                if blink_off { self.screen.clear() }
+            },
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning, with every life restored.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        *self = Self::new(rng);
+    }
+
+    /// Returns the current score: the number of micro-games cleared.
+    fn score(&self) -> usize {
+        self.cleared
+    }
+
+    /// Returns the number of lives remaining.
+    fn lives(&self) -> Option<usize> {
+        Some(self.lives)
+    }
+}
@@ -0,0 +1,283 @@
+/// Implementation of the two-player Air Hockey game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::graphics::FrameBuffer;
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::GamePlay, utils::{self, TickInput}};
+use super::versus::{self, Handicaps, RoundPlay, VersusMatch};
+
+// Constants for the Air Hockey game.
+//   The number of points needed to win a match.
+const POINTS_TO_WIN: usize = 4;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 40;
+//   The number of sub-dot units in the width and height of a dot, for smooth ball movement.
+const UNITS_PER_DOT: isize = 16;
+//   The initial and maximum horizontal speed of the ball, in units per tick.
+const SERVE_SPEED: isize = 4;
+const MAX_SPEED: isize = 10;
+//   The largest vertical speed of the ball, in units per tick.
+const MAX_VERTICAL_SPEED: isize = 6;
+//   The vertical speed given to the ball for each dot it strikes away from the paddle's center.
+const SPIN: isize = 2;
+//   The number of game ticks between each move of a paddle.
+const PADDLE_MOVE_TICKS: usize = 2;
+//   The height of a paddle without a handicap. Each handicap level lengthens the paddle.
+const PADDLE_HEIGHT: usize = 2;
+//   The width of each half-court, within which each player's paddle moves.
+const HALF_COURT: usize = DotScreen::WIDTH / 2;
+
+
+/// The Title Screen for the Air Hockey Game ("H").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b00010000,
+            0b00010000,
+            0b00010000,
+            0b01111110,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(hockey_game_loop);
+
+
+/// The game loop which runs the Air Hockey game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn hockey_game_loop(components: &mut Components) {
+    let mut game = HockeyGame::new();
+    game.handicaps = versus::choose_handicaps(components, Handicaps::default());
+    if VersusMatch::best_of(2 * POINTS_TO_WIN - 1).play(&mut game, components).is_some() {
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+/// The HockeyGame object.
+///
+/// Each player moves a paddle freely within their half of the court, the first player's
+///   on the left and the second player's on the right, either side of the net. The ball
+///   bounces off the top and bottom of the court and off the paddles, speeding up with
+///   each hit, and striking the ball away from the paddle's center angles it. A point is
+///   scored when the ball gets past the other player's paddle, off their edge of the court.
+struct HockeyGame {
+    /// The bottom dot of each player's paddle (the first player first).
+    paddles: [Dot; 2],
+    /// The height of each player's paddle.
+    paddle_heights: [usize; 2],
+    /// The position of the ball, in units.
+    ball: (isize, isize),
+    /// The velocity of the ball, in units per tick.
+    velocity: (isize, isize),
+    /// The index of the player (0 or 1) toward whom the next ball is served.
+    serve_to: usize,
+    /// The player who scored the point (1 or 2), once it has been scored.
+    scorer: Option<usize>,
+    /// The handicaps of the players, each level of which lengthens their paddle.
+    handicaps: Handicaps,
+    /// The screens depicting the current state of the game.
+    frame: FrameBuffer,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl HockeyGame {
+
+    /// Construct a new HockeyGame object.
+    fn new() -> Self {
+        Self {
+            paddles: [Dot { x: 0, y: 0 }; 2],
+            paddle_heights: [PADDLE_HEIGHT; 2],
+            ball: (0, 0),
+            velocity: (0, 0),
+            serve_to: 0,
+            scorer: None,
+            handicaps: Handicaps::default(),
+            frame: FrameBuffer::new(),
+            tick: 0,
+        }
+    }
+
+    /// Move the player's paddle one dot, keeping it within their half of the court.
+    ///
+    /// The paddles only move once every few game ticks.
+    ///
+    /// # Arguments
+    /// * player    - The index of the player (0 or 1).
+    /// * direction - The direction in which to move the paddle.
+    fn move_paddle(&mut self, player: usize, direction: Direction) {
+        if self.tick % PADDLE_MOVE_TICKS != 0 { return }
+        let left = player * HALF_COURT;
+        let top = DotScreen::HEIGHT - self.paddle_heights[player];
+        let paddle = &mut self.paddles[player];
+        match direction {
+            Direction::Left if paddle.x > left => { paddle.x -= 1 },
+            Direction::Right if paddle.x < left + HALF_COURT - 1 => { paddle.x += 1 },
+            Direction::Up if paddle.y < top => { paddle.y += 1 },
+            Direction::Down if paddle.y > 0 => { paddle.y -= 1 },
+            _ => {}
+        }
+    }
+
+    /// Returns the dot which the ball is on.
+    fn ball_dot(&self) -> Dot {
+        Dot { x: (self.ball.0 / UNITS_PER_DOT) as usize, y: (self.ball.1 / UNITS_PER_DOT) as usize }
+    }
+
+    /// Move the ball for a single game tick, bouncing it off the top and bottom of the
+    ///   court and off the paddles.
+    ///
+    /// # Returns
+    /// The player who scored (1 or 2), if the ball left the court.
+    fn move_ball(&mut self) -> Option<usize> {
+        let width = DotScreen::WIDTH as isize * UNITS_PER_DOT;
+        let height = DotScreen::HEIGHT as isize * UNITS_PER_DOT;
+        self.ball.0 += self.velocity.0;
+        self.ball.1 += self.velocity.1;
+
+        if self.ball.0 < 0 { return Some(2) }
+        if self.ball.0 >= width { return Some(1) }
+        if self.ball.1 < 0 {
+            self.ball.1 = -self.ball.1;
+            self.velocity.1 = -self.velocity.1;
+        } else if self.ball.1 >= height {
+            self.ball.1 = 2 * (height - 1) - self.ball.1;
+            self.velocity.1 = -self.velocity.1;
+        }
+
+        // Only the paddle the ball is travelling toward can strike it.
+        let player = if self.velocity.0 < 0 { 0 } else { 1 };
+        let (paddle, paddle_height) = (self.paddles[player], self.paddle_heights[player]);
+        let ball = self.ball_dot();
+        if (ball.x == paddle.x) & (ball.y >= paddle.y) & (ball.y < paddle.y + paddle_height) {
+            let speed = (self.velocity.0.abs() + 1).min(MAX_SPEED);
+            self.velocity.0 = if player == 0 { speed } else { -speed };
+
+            // Measured in half dots, so that an even-height paddle has no dead center.
+            let offset = (2 * ball.y + 1) as isize - (2 * paddle.y + paddle_height) as isize;
+            self.velocity.1 = (self.velocity.1 + SPIN * offset)
+                .max(-MAX_VERTICAL_SPEED)
+                .min(MAX_VERTICAL_SPEED);
+        }
+        None
+    }
+
+    /// This method is called to reset the court for a new point.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to angle the serve.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        for player in 0..2 {
+            let height = PADDLE_HEIGHT + self.handicaps.level(player + 1) as usize;
+            let x = if player == 0 { 0 } else { DotScreen::WIDTH - 1 };
+            self.paddle_heights[player] = height;
+            self.paddles[player] = Dot { x, y: (DotScreen::HEIGHT - height) / 2 };
+        }
+
+        let center = DotScreen::WIDTH as isize * UNITS_PER_DOT / 2;
+        self.ball = (center, DotScreen::HEIGHT as isize * UNITS_PER_DOT / 2);
+        let angle = utils::random_below(rng, 2 * SPIN as usize + 1) as isize - SPIN;
+        self.velocity = (if self.serve_to == 0 { -SERVE_SPEED } else { SERVE_SPEED }, angle);
+        self.scorer = None;
+        self.tick = 0;
+    }
+}
+
+
+impl GamePlay for HockeyGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the input of the first player received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction { self.move_paddle(0, direction) }
+    }
+
+    /// The game is played by two players.
+    fn is_versus(&self) -> bool {
+        true
+    }
+
+    /// Handle the input of the second player received over a game tick.
+    fn handle_input2(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction { self.move_paddle(1, direction) }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This moves the ball, and once a point is
+    ///   scored, serves the next ball toward the player who conceded it.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused).
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated. A point ends the round instead
+    ///   (see `winner`).
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        if let Some(scorer) = self.move_ball() {
+            self.serve_to = 2 - scorer;
+            self.scorer = Some(scorer);
+        }
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The net is a dashed line down the center of the court. The screen is drawn onto
+    ///   the back of the frame buffer, which is then flipped to the front to be shown.
+    fn render(&mut self) {
+        let ball = self.ball_dot();
+        let screen = self.frame.back();
+        screen.clear();
+        for y in 0..DotScreen::HEIGHT {
+            screen.add(&Dot { x: HALF_COURT - 1 + y % 2, y });
+        }
+        for (paddle, &height) in self.paddles.iter().zip(self.paddle_heights.iter()) {
+            screen.draw_v_line(paddle, height);
+        }
+        // The ball is inverted, so it remains visible as it crosses the net.
+        screen.toggle(&ball);
+        self.frame.flip();
+    }
+
+    /// Show the front screen of the frame buffer on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(self.frame.front());
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        *self.frame.front()
+    }
+
+    /// Restart the point, resetting the court.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+}
+
+
+impl RoundPlay for HockeyGame {
+
+    /// Reset the court, to begin a new point.
+    fn begin(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the player who scored the point (1 or 2), once it has been scored.
+    fn winner(&self) -> Option<usize> {
+        self.scorer
+    }
+}
@@ -0,0 +1,270 @@
+/// Implementation of the Lunar Lander game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Lunar Lander game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'L';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 100;
+//   The width of the play area. The rightmost column is the fuel gauge.
+const PLAY_WIDTH: usize = DotScreen::WIDTH - 1;
+//   The x-index (column) of the fuel gauge.
+const FUEL_GAUGE_X: usize = DotScreen::WIDTH - 1;
+//   The width of the landing pad.
+const PAD_WIDTH: usize = 2;
+//   The y-index (row) of the landing pad. The lander touches down on the row above.
+const PAD_ROW: usize = 0;
+//   The number of sub-dot units in the height of a dot, for smooth falling.
+const UNITS_PER_DOT: isize = 8;
+//   The downward acceleration of gravity and the upward acceleration of the thruster,
+//   in units per tick per tick.
+const GRAVITY: isize = 1;
+const THRUST: isize = 2;
+//   The fastest the lander may fall or rise, in units per tick.
+const MAX_SPEED: isize = UNITS_PER_DOT;
+//   The fastest the lander may be falling at touch down without crashing, in units per tick.
+const SAFE_LANDING_SPEED: isize = 3;
+//   The amount of fuel given for each landing, in ticks of thrust.
+const FUEL_PER_LANDING: usize = 40;
+//   The number of game ticks the landed lander is displayed before the next descent.
+const LANDED_TICKS: usize = 10;
+
+
+/// The Title Screen for the Lunar Lander Game ("L").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00000000,
+            0b01111110,
+            0b00000010,
+            0b00000010,
+            0b00000010,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(lander_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Lunar Lander game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn lander_game_loop(components: &mut Components) {
+    let mut game = LanderGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The outcome of a single game tick of a descent.
+#[derive(Copy, Clone, PartialEq)]
+enum Descent {
+    Falling,
+    Landed,
+    Crashed,
+}
+
+
+/// The LanderGame object.
+///
+/// The lander falls under gravity from the top of the screen. Holding the JoyStick Up
+///   fires the thruster, slowing the fall at the cost of fuel, and Left and Right
+///   nudge the lander sideways. A press of the button pauses the game. The lander must touch down gently on the landing pad.
+///   Each landing scores a point and begins a new descent, while a crash ends the game.
+struct LanderGame {
+    /// The x-index of the lander.
+    lander_x: usize,
+    /// The height of the lander above the bottom of the screen, in units.
+    altitude: isize,
+    /// The vertical velocity of the lander (positive is upward), in units per tick.
+    velocity: isize,
+    /// The remaining fuel, in ticks of thrust.
+    fuel: usize,
+    /// Whether the thruster is being fired.
+    thrusting: bool,
+    /// The countdown until the next descent, while the lander is landed.
+    landed: Option<Countdown>,
+    /// The x-index of the leftmost dot of the landing pad.
+    pad_x: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of successful landings.
+    landings: usize,
+}
+
+impl LanderGame {
+
+    /// Construct a new LanderGame object.
+    fn new() -> Self {
+        Self {
+            lander_x: 0,
+            altitude: 0,
+            velocity: 0,
+            fuel: 0,
+            thrusting: false,
+            landed: None,
+            pad_x: 0,
+            screen: DotScreen::new_empty(),
+            landings: 0,
+        }
+    }
+
+    /// Descend for a single game tick, accelerating the lander and checking whether
+    ///   it has touched down.
+    ///
+    /// # Returns
+    /// The outcome of the game tick.
+    fn descend(&mut self) -> Descent {
+        self.velocity -= GRAVITY;
+        if self.thrusting && self.fuel > 0 {
+            self.fuel -= 1;
+            self.velocity += THRUST;
+        }
+        self.velocity = self.velocity.max(-MAX_SPEED).min(MAX_SPEED);
+
+        let ceiling = (DotScreen::HEIGHT as isize - 1) * UNITS_PER_DOT;
+        self.altitude = (self.altitude + self.velocity).min(ceiling);
+        if self.altitude > (PAD_ROW as isize + 1) * UNITS_PER_DOT { return Descent::Falling }
+
+        // Touch down.
+        let on_pad = (self.lander_x >= self.pad_x) & (self.lander_x < self.pad_x + PAD_WIDTH);
+        let gentle = -self.velocity <= SAFE_LANDING_SPEED;
+        self.altitude = if on_pad { (PAD_ROW as isize + 1) * UNITS_PER_DOT } else { 0 };
+        self.velocity = 0;
+        if on_pad & gentle { Descent::Landed } else { Descent::Crashed }
+    }
+
+    /// Returns the y-index of the row the lander is on.
+    fn lander_row(&self) -> usize {
+        (self.altitude.max(0) / UNITS_PER_DOT) as usize
+    }
+
+    /// Begin a new descent from the top of the screen, with a freshly placed landing pad
+    ///   and a full tank of fuel.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to place the landing pad and lander.
+    fn begin_descent(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.pad_x = utils::random_below(rng, PLAY_WIDTH - PAD_WIDTH + 1);
+        self.lander_x = utils::random_below(rng, PLAY_WIDTH);
+        self.altitude = (DotScreen::HEIGHT as isize - 1) * UNITS_PER_DOT;
+        self.velocity = 0;
+        self.fuel = FUEL_PER_LANDING;
+        self.landed = None;
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.landings);
+        utils::show_tally(&mut components.display, self.landings);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to place the landing pad and lander.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.landings = 0;
+        self.begin_descent(rng);
+    }
+}
+
+
+impl GamePlay for LanderGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick, nudging the lander sideways
+    ///   and firing the thruster while it is held Up.
+    fn handle_input(&mut self, input: TickInput) {
+        self.thrusting = input.direction == Some(Direction::Up);
+        if self.landed.is_some() { return }
+        match input.direction {
+            Some(Direction::Left) if self.lander_x > 0 => { self.lander_x -= 1 },
+            Some(Direction::Right) if self.lander_x < PLAY_WIDTH - 1 => { self.lander_x += 1 },
+            _ => {}
+        }
+    }
+
+    /// The button is not used for input, so a press of it pauses the game.
+    fn pauses_on_press(&self) -> bool {
+        true
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This descends the lander, or, once it has
+    ///   landed (and been displayed for a while), begins the next descent.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to place the landing pad and lander.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated, i.e. false once the lander crashes.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        if let Some(landed) = self.landed.as_mut() {
+            if landed.tick() { self.begin_descent(rng) }
+            return true
+        }
+        match self.descend() {
+            Descent::Falling => { true },
+            Descent::Landed => {
+                self.landings += 1;
+                self.landed = Some(Countdown::new(LANDED_TICKS));
+                true
+            },
+            Descent::Crashed => { false },
+        }
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        self.screen.draw_h_line(&Dot { x: self.pad_x, y: PAD_ROW }, PAD_WIDTH);
+        self.screen.add(&Dot { x: self.lander_x, y: self.lander_row() });
+
+        // The fuel gauge, rounded up so it is only empty once the fuel has run out.
+        let gauge = (self.fuel * DotScreen::HEIGHT + FUEL_PER_LANDING - 1) / FUEL_PER_LANDING;
+        self.screen.draw_v_line(&Dot { x: FUEL_GAUGE_X, y: 0 }, gauge);
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the number of successful landings.
+    fn score(&self) -> usize {
+        self.landings
+    }
+}
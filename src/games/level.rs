@@ -0,0 +1,117 @@
+/// Game-play divided into levels (stages), and the progression from one level to the next.
+///
+/// A game describes each of its levels as a `Level`, and the progression (see `play`) runs
+///   them in turn: a splash screen with the number of the level, then the game loop until
+///   the goal of the level is met (advancing to the next level), or the game is over.
+use crate::Components;
+use crate::peripherals::{Dot, DotScreen};
+use crate::graphics::Transition;
+use super::{digits, game::{self, GamePlay, Outcome}, utils::{self, Countdown}};
+
+
+/// The goal of a level, which completes the level once met.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Goal {
+    /// Reach the dot (e.g. the exit of a maze).
+    Reach(Dot),
+    /// Collect a number of items (e.g. gems or eggs).
+    Collect(usize),
+}
+
+
+/// A level of a game.
+#[derive(Copy, Clone)]
+pub struct Level {
+    /// The obstacles of the level. The lit dots are obstacles (e.g. walls).
+    pub obstacles: DotScreen,
+    /// The length of a game tick during the level, in milliseconds.
+    pub tick_interval_ms: usize,
+    /// The number of game ticks the player has to meet the goal, if limited.
+    ///   Running out of time is the end of the game.
+    pub time_limit: Option<usize>,
+    /// The goal of the level.
+    pub goal: Goal,
+}
+
+
+/// The game-play of a game divided into levels, see `play`.
+pub trait LevelPlay: GamePlay {
+
+    /// Create a level of the game. Its creation may be animated on the display.
+    ///
+    /// # Arguments
+    /// * number     - The number of the level, the first level being 1.
+    /// * components - The peripheral components.
+    fn level(&mut self, number: usize, components: &mut Components) -> Level;
+
+    /// Begin playing a level, e.g. placing the player at its start.
+    ///
+    /// # Arguments
+    /// * number - The number of the level, the first level being 1.
+    /// * level  - The level.
+    fn begin(&mut self, number: usize, level: &Level);
+
+    /// Returns whether the goal of the current level has been met.
+    fn is_goal_met(&self, goal: &Goal) -> bool;
+}
+
+
+/// Play a game divided into levels, from the first level until the game is over.
+///
+/// Each level begins with a splash screen showing its number, and the game-play of the
+///   first level starts with a countdown (see `utils::countdown`). The game is over once
+///   the game state fails to update, or the time limit of a level runs out.
+///
+/// # Arguments
+/// * game       - The game.
+/// * components - The peripheral components.
+///
+/// # Returns
+/// Whether the game is over, i.e. false if the player quit the game instead.
+pub fn play<G: LevelPlay>(game: &mut G, components: &mut Components) -> bool {
+    play_from(game, components, 1)
+}
+
+/// Play a game divided into levels, as `play`, but beginning from a later level
+///   (e.g. that of a saved game).
+///
+/// # Arguments
+/// * game       - The game.
+/// * components - The peripheral components.
+/// * number     - The number of the level to begin from.
+pub fn play_from<G: LevelPlay>(game: &mut G, components: &mut Components, mut number: usize) -> bool {
+    let first = number;
+    loop {
+        show_splash(components, number);
+        let level = game.level(number, components);
+        game.begin(number, &level);
+        if number == first { utils::countdown(components) }
+
+        let mut timer = level.time_limit.map(Countdown::new);
+        let outcome = game::run(game, components, |game| {
+            if game.is_goal_met(&level.goal) { return Some(Outcome::Complete) }
+            let out_of_time = timer.as_mut().map_or(false, Countdown::tick);
+            if out_of_time { Some(Outcome::Over) } else { None }
+        });
+        match outcome {
+            Outcome::Complete => { number += 1 },
+            Outcome::Over => { return true },
+            Outcome::Quit => { return false },
+        }
+    }
+}
+
+/// Show the splash screen of a level: its number, wiped onto the screen.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * number     - The number of the level. Numbers of more than two digits aren't shown.
+fn show_splash(components: &mut Components, number: usize) {
+    const FRAME_MS: u16 = 40;
+    const SPLASH_MS: usize = 800;
+    let mut screen = DotScreen::new_empty();
+    digits::draw_number(&mut screen, number);
+    Transition::WipeLeft.play(&mut components.display, &DotScreen::new_empty(), &screen, FRAME_MS);
+    // Input during the splash screen is ignored.
+    components.analog.poll_joystick(SPLASH_MS);
+}
@@ -0,0 +1,240 @@
+/// Implementation of the Mastermind (code-breaker) game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Mastermind game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'K';
+//   The number of positions in the secret pattern, out of the width of the top row.
+const PATTERN_SIZE: u32 = 4;
+//   The y-index (row) along which the guess is entered.
+const GUESS_ROW: usize = DotScreen::HEIGHT - 1;
+//   The number of guesses allowed. Each guess is recorded on its own row beneath the guess row.
+const MAX_GUESSES: usize = DotScreen::HEIGHT - 1;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 150;
+//   The number of game ticks a blinking dot spends lit, and then unlit.
+const BLINK_TICKS: usize = 2;
+//   The time the secret pattern is revealed once the game is over.
+const REVEAL_MS: u16 = 1500;
+
+
+/// The Title Screen for the Mastermind Game ("K").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b00011000,
+            0b00100100,
+            0b01000010,
+            0b00000000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(mastermind_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Mastermind game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn mastermind_game_loop(components: &mut Components) {
+    let mut game = MastermindGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The MastermindGame object.
+///
+/// A secret pattern of 4 positions along the top row is hidden from the player.
+///   The player moves the cursor along the top row, toggling dots with the JoyStick
+///   button, and submits a guess of 4 dots by pushing the JoyStick down. Each guess is
+///   recorded on the next row down, where one dot is lit solid for each correct position
+///   and the remaining dots blink, without revealing which positions were correct.
+///   The game ends once the pattern is found, or the rows run out.
+struct MastermindGame {
+    /// The secret pattern, with a bit set for the x-index of each position.
+    secret: u8,
+    /// The guess being entered, with a bit set for the x-index of each position.
+    guess: u8,
+    /// The submitted guesses, in the order they were made.
+    guesses: [u8; MAX_GUESSES],
+    /// The number of guesses submitted.
+    guess_count: usize,
+    /// The x-index of the cursor along the guess row.
+    cursor: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl MastermindGame {
+
+    /// Construct a new MastermindGame object.
+    fn new() -> Self {
+        Self {
+            secret: 0,
+            guess: 0,
+            guesses: [0; MAX_GUESSES],
+            guess_count: 0,
+            cursor: 0,
+            screen: DotScreen::new_empty(),
+            tick: 0,
+        }
+    }
+
+    /// Submit the guess being entered, if it has exactly as many positions as the pattern.
+    fn submit(&mut self) {
+        if self.guess.count_ones() != PATTERN_SIZE { return }
+        self.guesses[self.guess_count] = self.guess;
+        self.guess_count += 1;
+        self.guess = 0;
+    }
+
+    /// Returns whether the most recent guess matched the secret pattern.
+    fn is_solved(&self) -> bool {
+        (self.guess_count > 0) && (self.guesses[self.guess_count - 1] == self.secret)
+    }
+
+    /// Draw the guesses onto the screen.
+    ///
+    /// # Arguments
+    /// * show_cursor - Whether to draw the blinking cursor.
+    ///                 The dot under the cursor is inverted on alternating ticks.
+    fn draw_guesses(&mut self, show_cursor: bool) {
+        self.screen.clear();
+        let blink_on = (self.tick / BLINK_TICKS) % 2 == 0;
+
+        for x in 0..DotScreen::WIDTH {
+            if self.guess & (1 << x) != 0 { self.screen.add(&Dot { x, y: GUESS_ROW }) }
+        }
+        if show_cursor && blink_on {
+            self.screen.toggle(&Dot { x: self.cursor, y: GUESS_ROW });
+        }
+
+        // The first dots of each recorded guess, one per correct position, are lit solid.
+        for (row, &guess) in self.guesses[..self.guess_count].iter().enumerate() {
+            let mut solid = (guess & self.secret).count_ones();
+            for x in 0..DotScreen::WIDTH {
+                if guess & (1 << x) == 0 { continue }
+                if (solid > 0) | blink_on {
+                    self.screen.add(&Dot { x, y: GUESS_ROW - 1 - row });
+                }
+                solid = solid.saturating_sub(1);
+            }
+        }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///   The secret pattern is revealed on the guess row before the score is shown.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.guess = self.secret;
+        self.draw_guesses(false);
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.display.show(&self.screen);
+        arduino_uno::delay_ms(REVEAL_MS);
+        components.report_score(GAME_ID, self.score());
+        utils::show_tally(&mut components.display, self.score());
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state,
+    ///   choosing a new secret pattern.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to choose the secret pattern.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.secret = 0;
+        while self.secret.count_ones() < PATTERN_SIZE {
+            self.secret |= 1 << utils::random_below(rng, DotScreen::WIDTH);
+        }
+        self.guess = 0;
+        self.guesses = [0; MAX_GUESSES];
+        self.guess_count = 0;
+        self.cursor = 0;
+        self.tick = 0;
+    }
+}
+
+
+impl GamePlay for MastermindGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick, moving the cursor, submitting
+    ///   the guess, or toggling the dot under the cursor.
+    fn handle_input(&mut self, input: TickInput) {
+        match input.direction {
+            Some(Direction::Left) if self.cursor > 0 => { self.cursor -= 1 },
+            Some(Direction::Right) if self.cursor < DotScreen::WIDTH - 1 => { self.cursor += 1 },
+            Some(Direction::Down) => { self.submit() },
+            _ => {}
+        }
+        if input.pressed {
+            self.guess ^= 1 << self.cursor;
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused).
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once the pattern is found or the rows run out.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        !self.is_solved() & (self.guess_count < MAX_GUESSES)
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.draw_guesses(true);
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns whether the player has won the game, by finding the pattern.
+    fn is_won(&self) -> bool {
+        self.is_solved()
+    }
+
+    /// Returns the score, which is one point for each guess left unused, plus one,
+    ///   if the pattern was found. Otherwise the score is zero.
+    fn score(&self) -> usize {
+        if self.is_solved() { MAX_GUESSES - self.guess_count + 1 } else { 0 }
+    }
+}
@@ -0,0 +1,281 @@
+/// Implementation of the Math Flash Cards game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Display, DotScreen};
+use super::{digits, game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Math Flash Cards game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'+';
+//   The largest operand of a problem. Answers are at most two digits.
+const MAX_OPERAND: usize = 9;
+//   The largest distance between the wrong answer and the right answer.
+const MAX_WRONG_OFFSET: usize = 3;
+//   The time taken to scroll the problem by a single column.
+const SCROLL_MS: usize = 90;
+//   The length of a single tick, while waiting for an answer.
+const TICK_INTERVAL_MS: usize = 100;
+//   The number of game ticks the right answer is lit, and then unlit, each time it flashes.
+const FLASH_TICKS: usize = 3;
+//   The number of times the right answer flashes once an answer is picked.
+const FLASHES: usize = 2;
+//   The column of a two-digit answer which is lit for a tens digit of 1.
+const TENS_COLUMN: u8 = 0b11111;
+
+
+/// The Title Screen for the Math Flash Cards Game (a plus sign).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00011000,
+            0b00011000,
+            0b01111110,
+            0b01111110,
+            0b00011000,
+            0b00011000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(math_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Math Flash Cards game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn math_game_loop(components: &mut Components) {
+    let mut game = MathGame::new(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// A single addition problem, with a right and a wrong answer to choose between.
+struct Problem {
+    /// The operands of the addition.
+    operands: [usize; 2],
+    /// The wrong answer.
+    wrong: usize,
+    /// Whether the right answer is shown on the left half of the screen.
+    right_on_left: bool,
+}
+
+impl Problem {
+
+    /// Generate a random problem.
+    ///
+    /// The wrong answer is close to the right answer, so that it can't be
+    ///   dismissed at a glance.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    fn random(rng: &mut dyn rand_core::RngCore) -> Self {
+        let operands = [
+            utils::random_below(rng, MAX_OPERAND + 1),
+            utils::random_below(rng, MAX_OPERAND + 1),
+        ];
+        let answer = operands[0] + operands[1];
+        let offset = 1 + utils::random_below(rng, MAX_WRONG_OFFSET);
+        let below = (answer >= offset) & (utils::random_below(rng, 2) == 0);
+        let wrong = if below | (answer + offset > 2 * MAX_OPERAND) { answer - offset } else { answer + offset };
+        Self { operands, wrong, right_on_left: utils::random_below(rng, 2) == 0 }
+    }
+
+    /// Returns the right answer.
+    fn answer(&self) -> usize {
+        self.operands[0] + self.operands[1]
+    }
+
+    /// Returns the problem as ASCII text, e.g. "3+4=?".
+    fn text(&self) -> [u8; 5] {
+        [b'0' + self.operands[0] as u8, b'+', b'0' + self.operands[1] as u8, b'=', b'?']
+    }
+
+    /// Returns the answers shown on the left and right halves of the screen, respectively.
+    fn choices(&self) -> [usize; 2] {
+        if self.right_on_left { [self.answer(), self.wrong] } else { [self.wrong, self.answer()] }
+    }
+}
+
+
+/// The phase of a single problem.
+enum Phase {
+    /// The problem is scrolling across the screen, having scrolled the provided
+    ///   number of columns.
+    Scrolling(usize),
+    /// The answers are shown, waiting for the player to pick one. The JoyStick must
+    ///   first be released, so an answer is not picked accidentally.
+    Choosing { released: bool },
+    /// An answer was picked, and the right answer is flashing until the countdown expires.
+    Answered { right: bool, flashing: Countdown },
+}
+
+
+/// The MathGame object.
+///
+/// An addition problem scrolls across the screen, after which two answers are shown,
+///   one on each half of the screen. The player picks an answer by pushing the JoyStick
+///   Left or Right. Each right answer extends the streak, and the game ends at the
+///   first wrong answer.
+struct MathGame {
+    /// The problem being answered.
+    problem: Problem,
+    /// The phase of the problem being answered.
+    phase: Phase,
+    /// The number of problems answered correctly in a row.
+    streak: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+}
+
+impl MathGame {
+
+    /// Construct a new MathGame object.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the first problem.
+    fn new(rng: &mut dyn rand_core::RngCore) -> Self {
+        Self {
+            problem: Problem::random(rng),
+            phase: Phase::Scrolling(0),
+            streak: 0,
+            screen: DotScreen::new_empty(),
+        }
+    }
+
+    /// Pick an answer, extending the streak if it was the right answer.
+    ///
+    /// # Arguments
+    /// * left - Whether the answer on the left half of the screen was picked.
+    fn pick(&mut self, left: bool) {
+        let right = left == self.problem.right_on_left;
+        if right { self.streak += 1 }
+        self.phase = Phase::Answered { right, flashing: Countdown::new(2 * FLASH_TICKS * FLASHES) };
+    }
+
+    /// Draw an answer onto one half of the screen.
+    ///
+    /// The tens digit is at most 1, so it is drawn as a single lit column.
+    ///
+    /// # Arguments
+    /// * screen  - The screen to draw onto.
+    /// * answer  - The answer to draw.
+    /// * on_left - Whether to draw the answer on the left half of the screen.
+    fn draw_answer(screen: &mut DotScreen, answer: usize, on_left: bool) {
+        let left = if on_left { 0 } else { DotScreen::WIDTH / 2 };
+        if answer >= 10 { digits::draw_column(screen, left, TENS_COLUMN) }
+        digits::draw_glyph(screen, b'0' + (answer % 10) as u8, left + 1);
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        components.report_score(GAME_ID, self.streak);
+        utils::show_tally(&mut components.display, self.streak);
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+impl GamePlay for MathGame {
+
+    /// Returns the length of a game tick, which is shorter while the problem scrolls.
+    fn tick_interval_ms(&self) -> usize {
+        if let Phase::Scrolling(_) = self.phase { SCROLL_MS } else { TICK_INTERVAL_MS }
+    }
+
+    /// Handle the JoyStick input received over a game tick, picking an answer by
+    ///   pushing the JoyStick Left or Right once it has been released.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Phase::Choosing { released } = &mut self.phase {
+            match input.direction {
+                Some(Direction::Left) if *released => { self.pick(true) },
+                Some(Direction::Right) if *released => { self.pick(false) },
+                None => { *released = true },
+                _ => {}
+            }
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This scrolls the problem onto the screen by
+    ///   a single column,
+    ///   and, once the right answer has finished flashing, moves onto the next problem.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to generate the next problem.
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once a wrong answer has finished flashing.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        match &mut self.phase {
+            Phase::Scrolling(column) => {
+                match digits::scroll_column(&self.problem.text(), *column) {
+                    Some(shown) => {
+                        self.screen.shift_left(1, false);
+                        self.screen.columns[DotScreen::WIDTH - 1] = shown;
+                        *column += 1;
+                    },
+                    None => { self.phase = Phase::Choosing { released: false } },
+                }
+            },
+            Phase::Choosing { .. } => {},
+            Phase::Answered { right, flashing } => {
+                if flashing.tick() {
+                    if !*right { return false }
+                    self.problem = Problem::random(rng);
+                    self.phase = Phase::Scrolling(0);
+                }
+            },
+        }
+        true
+    }
+
+    /// Draw the current game state onto the screen: the scrolling problem, both answers,
+    ///   or the flashing right answer.
+    fn render(&mut self) {
+        match &self.phase {
+            // The screen is scrolled as the game state is updated.
+            Phase::Scrolling(_) => {},
+            Phase::Choosing { .. } => {
+                let choices = self.problem.choices();
+                self.screen.clear();
+                Self::draw_answer(&mut self.screen, choices[0], true);
+                Self::draw_answer(&mut self.screen, choices[1], false);
+            },
+            Phase::Answered { flashing, .. } => {
+                self.screen.clear();
+                if (flashing.remaining() / FLASH_TICKS) % 2 == 1 {
+                    Self::draw_answer(&mut self.screen, self.problem.answer(), self.problem.right_on_left);
+                }
+            },
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning, with a new problem and no streak.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        *self = Self::new(rng);
+    }
+
+    /// Returns the current score: the number of problems answered correctly in a row.
+    fn score(&self) -> usize {
+        self.streak
+    }
+}
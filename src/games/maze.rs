@@ -0,0 +1,350 @@
+/// Implementation of a Maze game for an 8x8 LED Dot Screen,
+///   along with the incremental maze generator.
+use crate::Components;
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::GamePlay, level::{self, Goal, Level, LevelPlay}, utils::{self, TickInput}};
+
+// Constants for the Maze game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'Z';
+//   The number of cells along each side of the maze. Cells sit on the even
+//   coordinates of the screen, with the odd coordinates between them being walls.
+const CELLS_PER_SIDE: usize = DotScreen::WIDTH / 2;
+//   The total number of cells in the maze.
+const TOTAL_CELLS: usize = CELLS_PER_SIDE * CELLS_PER_SIDE;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 100;
+//   The delay between each step of the carving animation.
+const CARVE_DELAY_MS: u16 = 60;
+//   The initial number of game ticks the player has to solve a maze.
+const INITIAL_TIME_LIMIT: usize = 250;
+//   The minimum number of game ticks the player has to solve a maze.
+const MIN_TIME_LIMIT: usize = 60;
+//   The dot from which the player starts, the top left cell.
+const START: Dot = Dot { x: 0, y: DotScreen::HEIGHT - 2 };
+//   The dot the player must reach, the bottom right cell.
+const EXIT: Dot = Dot { x: DotScreen::WIDTH - 2, y: 0 };
+
+
+/// The Title Screen for the Maze Game.
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b11111110,
+            0b10000000,
+            0b10111110,
+            0b10100010,
+            0b10101010,
+            0b10111010,
+            0b10000010,
+            0b11111110,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(maze_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Maze game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn maze_game_loop(components: &mut Components) {
+    let mut game = MazeGame::new();
+    if level::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The result of a single step of the MazeGenerator.
+pub enum CarveStep {
+    /// A passage was carved from the current cell into a new cell.
+    /// The inner Dot is the newly carved cell.
+    Carved(Dot),
+    /// The current cell had no uncarved neighbours, so the generator backtracked.
+    /// The inner Dot is the cell the generator returned to.
+    Backtracked(Dot),
+    /// Every cell has been carved, so the maze is complete.
+    Complete,
+}
+
+
+/// Generator of mazes, using the randomized depth-first search ("recursive backtracker")
+///   algorithm.
+///
+/// The maze is carved one step at a time, so that the carving process can be animated.
+///   The maze begins as a fully lit screen, and the cells and the passages
+///   between them are turned off as they are carved.
+pub struct MazeGenerator {
+    /// The walls of the maze. The lit dots are walls.
+    walls: DotScreen,
+    /// The bitmask of the cells that have been carved.
+    visited: u16,
+    /// The stack of cell indices describing the current path from the start cell.
+    stack: [u8; TOTAL_CELLS],
+    /// The number of cells on the stack.
+    depth: usize,
+}
+
+impl MazeGenerator {
+
+    /// Create a new MazeGenerator, which begins carving from the provided cell.
+    ///
+    /// # Arguments
+    /// * start - The dot of the cell from which to begin carving.
+    ///           Cells sit on the even coordinates of the screen.
+    pub fn new(start: &Dot) -> Self {
+        let mut generator = Self {
+            walls: DotScreen::new_full(),
+            visited: 0,
+            stack: [0; TOTAL_CELLS],
+            depth: 0,
+        };
+        let index = Self::cell_index(start);
+        generator.walls.remove(start);
+        generator.visited |= 1 << index;
+        generator.stack[0] = index as u8;
+        generator.depth = 1;
+        generator
+    }
+
+    /// Generate an entire maze at once, beginning from the provided cell.
+    ///
+    /// # Returns
+    /// The walls of the maze. The lit dots are walls.
+    pub fn generate(start: &Dot, rng: &mut dyn rand_core::RngCore) -> DotScreen {
+        let mut generator = Self::new(start);
+        while !generator.is_complete() {
+            generator.step(rng);
+        }
+        generator.walls
+    }
+
+    /// Generate an entire maze, animating the carving process on the display.
+    ///
+    /// # Returns
+    /// The walls of the maze. The lit dots are walls.
+    pub fn animate(
+        start: &Dot,
+        rng: &mut dyn rand_core::RngCore,
+        display: &mut dyn Display,
+        step_delay_ms: u16,
+    ) -> DotScreen {
+        let mut generator = Self::new(start);
+        display.show(&generator.walls);
+        loop {
+            match generator.step(rng) {
+                CarveStep::Carved(_) => {
+                    display.show(&generator.walls);
+                    arduino_uno::delay_ms(step_delay_ms);
+                },
+                CarveStep::Backtracked(_) => {},
+                CarveStep::Complete => { break generator.walls },
+            }
+        }
+    }
+
+    /// Returns the walls of the maze, as carved so far.
+    pub fn walls(&self) -> &DotScreen {
+        &self.walls
+    }
+
+    /// Returns whether every cell has been carved.
+    pub fn is_complete(&self) -> bool {
+        self.depth == 0
+    }
+
+    /// Perform a single step of the carving process.
+    ///
+    /// From the cell on top of the stack, a random uncarved neighbour is chosen,
+    ///   and the wall between them is carved away. If there are no uncarved
+    ///   neighbours, the generator backtracks to the previous cell.
+    pub fn step(&mut self, rng: &mut dyn rand_core::RngCore) -> CarveStep {
+        if self.depth == 0 { return CarveStep::Complete }
+        let current = self.stack[self.depth - 1] as usize;
+        let (x, y) = (current % CELLS_PER_SIDE, current / CELLS_PER_SIDE);
+
+        // Collect the uncarved neighbours of the current cell.
+        let mut neighbours = [0usize; 4];
+        let mut count = 0;
+        let candidates = [
+            (x > 0, current.wrapping_sub(1)),
+            (x < CELLS_PER_SIDE - 1, current + 1),
+            (y > 0, current.wrapping_sub(CELLS_PER_SIDE)),
+            (y < CELLS_PER_SIDE - 1, current + CELLS_PER_SIDE),
+        ];
+        for &(valid, index) in candidates.iter() {
+            if valid && self.visited & (1 << index) == 0 {
+                neighbours[count] = index;
+                count += 1;
+            }
+        }
+
+        if count == 0 {
+            self.depth -= 1;
+            return
+                if self.depth == 0 { CarveStep::Complete }
+                else { CarveStep::Backtracked(Self::cell_dot(self.stack[self.depth - 1] as usize)) }
+        }
+
+        // Carve the passage into a randomly chosen neighbour.
+        let next = neighbours[utils::random_below(rng, count)];
+        let (from, to) = (Self::cell_dot(current), Self::cell_dot(next));
+        self.walls.remove(&Dot { x: (from.x + to.x) / 2, y: (from.y + to.y) / 2 });
+        self.walls.remove(&to);
+        self.visited |= 1 << next;
+        self.stack[self.depth] = next as u8;
+        self.depth += 1;
+        CarveStep::Carved(to)
+    }
+
+    /// Convert the dot of a cell into its index.
+    fn cell_index(dot: &Dot) -> usize {
+        (dot.y / 2) * CELLS_PER_SIDE + (dot.x / 2)
+    }
+
+    /// Convert the index of a cell into its dot.
+    fn cell_dot(index: usize) -> Dot {
+        Dot { x: (index % CELLS_PER_SIDE) * 2, y: (index / CELLS_PER_SIDE) * 2 }
+    }
+}
+
+
+/// The MazeGame object.
+///
+/// The player navigates from the top left of a randomly generated maze to the exit
+///   at the bottom right before time runs out. Each maze is a level: solving it awards
+///   a point, and the time limit for the next maze gets shorter.
+struct MazeGame {
+    /// The walls of the current maze. The lit dots are walls.
+    walls: DotScreen,
+    /// The character that the player controls.
+    player: Dot,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of mazes solved.
+    score: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+    /// The length of a game tick during the current maze.
+    tick_interval_ms: usize,
+}
+
+impl MazeGame {
+
+    /// Construct a new MazeGame object.
+    fn new() -> Self {
+        Self {
+            walls: DotScreen::new_full(),
+            player: START,
+            screen: DotScreen::new_empty(),
+            score: 0,
+            tick: 0,
+            tick_interval_ms: TICK_INTERVAL_MS,
+        }
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The player blinks quickly, and the exit blinks slowly.
+    fn render(&mut self) {
+        self.screen = self.walls;
+        if self.tick % 2 == 0 { self.screen.add(&self.player) }
+        if (self.tick / 4) % 2 == 0 { self.screen.add(&EXIT) }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.score);
+        utils::show_tally(&mut components.display, self.score);
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+impl GamePlay for MazeGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        self.tick_interval_ms
+    }
+
+    /// Handle the JoyStick input received over a game tick, moving the player through the maze.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction {
+            let next = self.player.step(direction);
+            if self.walls.is_dot_off(&next) { self.player = next }
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// The time limit of the maze is kept by the level progression, so this always succeeds.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        MazeGame::render(self);
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the maze, with the player back at its start.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.player = START;
+        self.tick = 0;
+    }
+
+    /// Returns the current score: the number of levels completed.
+    fn score(&self) -> usize {
+        self.score
+    }
+}
+
+
+impl LevelPlay for MazeGame {
+
+    /// Generate a new maze, animating the carving process on the display.
+    ///
+    /// Every cell of the maze is carved, so there is always a path from the start to the exit.
+    ///   The time limit shrinks by an eighth with each maze, down to a minimum.
+    fn level(&mut self, number: usize, components: &mut Components) -> Level {
+        let walls = MazeGenerator::animate(&START, &mut components.analog, &mut components.display, CARVE_DELAY_MS);
+        let time_limit = (1..number).fold(INITIAL_TIME_LIMIT, |limit, _| (limit - limit / 8).max(MIN_TIME_LIMIT));
+        Level { obstacles: walls, tick_interval_ms: TICK_INTERVAL_MS, time_limit: Some(time_limit), goal: Goal::Reach(EXIT) }
+    }
+
+    /// Begin a maze, with the player at its start. Each maze before it was solved.
+    fn begin(&mut self, number: usize, level: &Level) {
+        self.walls = level.obstacles;
+        self.tick_interval_ms = level.tick_interval_ms;
+        self.player = START;
+        self.score = number - 1;
+    }
+
+    /// Returns whether the player has reached the exit.
+    fn is_goal_met(&self, goal: &Goal) -> bool {
+        match goal {
+            Goal::Reach(dot) => { self.player == *dot },
+            Goal::Collect(_) => { false },
+        }
+    }
+}
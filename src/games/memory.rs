@@ -0,0 +1,317 @@
+/// Implementation of the Memory Match (pairs) game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, Countdown, TickInput}};
+
+// Constants for the Memory Match game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'P';
+//   The number of cards along each side of the board. Each card is a 2x2 block of dots.
+const CARDS_PER_SIDE: usize = 4;
+//   The total number of cards on the board.
+const TOTAL_CARDS: usize = CARDS_PER_SIDE * CARDS_PER_SIDE;
+//   The total number of pairs on the board.
+const TOTAL_PAIRS: usize = TOTAL_CARDS / 2;
+//   The number of mismatched guesses after which the game is lost.
+const MAX_MISSES: usize = 10;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 150;
+//   The number of game ticks both flipped cards remain visible before being judged,
+//   long enough to show a whole cycle of their blinks.
+const REVEAL_TICKS: usize = 12;
+//   The patterns of the dots of a card, ordered (top left, top right, bottom left,
+//   bottom right) from most to least significant bit: a single cover dot in the top
+//   left of a card which is face down, and the entire block of a card face up (or matched).
+const COVER_PATTERN: u8 = 0b1000;
+const BLOCK_PATTERN: u8 = 0b1111;
+//   The dot of the card under the cursor which is inverted (the bottom right).
+const CURSOR_PATTERN: u8 = 0b0001;
+//   The number of game ticks in a cycle of a blink.
+const BLINK_STEPS: usize = 8;
+//   The blink of the cover dots, as whether they are lit on each tick of the cycle,
+//   from the most significant bit.
+const COVER_BLINK: u8 = 0b11110000;
+//   The blinks identifying each pair, while face up, told apart by their rate and rhythm.
+const PAIR_BLINKS: [u8; TOTAL_PAIRS] = [
+    0b10101010, 0b11001100, 0b11110000, 0b10000000,
+    0b10100000, 0b10101000, 0b11111110, 0b11101110,
+];
+
+
+/// The Title Screen for the Memory Match Game ("P").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b01001000,
+            0b01001000,
+            0b01001000,
+            0b00110000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(memory_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Memory Match game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn memory_game_loop(components: &mut Components) {
+    let mut game = MemoryGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The state of a single card on the board.
+#[derive(Copy, Clone, PartialEq)]
+enum CardState {
+    FaceDown,
+    FaceUp,
+    Matched,
+}
+
+
+/// The MemoryGame object.
+///
+/// The board consists of 8 pairs of cards, laid face down in a 4x4 grid, each covered
+///   by a blinking dot. The player moves the cursor between the cards, flipping two at a
+///   time. A card face up blinks in the rhythm of its pair, so a pair blinks in unison.
+///   Matched pairs remain lit, and the game ends once every pair has been found
+///   or the player has made too many mismatched guesses.
+struct MemoryGame {
+    /// The pair each card belongs to, indexed by card.
+    pairs: [u8; TOTAL_CARDS],
+    /// The state of each card, indexed by card.
+    states: [CardState; TOTAL_CARDS],
+    /// The card indices of the cards currently flipped face up.
+    flipped: [Option<usize>; 2],
+    /// The countdown until the two flipped cards are judged, while they are revealed.
+    revealing: Option<Countdown>,
+    /// The card the cursor is on. Cards are indexed left to right, top to bottom.
+    cursor: usize,
+    /// The number of pairs matched.
+    matches: usize,
+    /// The number of mismatched guesses.
+    misses: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl MemoryGame {
+
+    /// Construct a new MemoryGame object.
+    fn new() -> Self {
+        Self {
+            pairs: [0; TOTAL_CARDS],
+            states: [CardState::FaceDown; TOTAL_CARDS],
+            flipped: [None; 2],
+            revealing: None,
+            cursor: 0,
+            matches: 0,
+            misses: 0,
+            screen: DotScreen::new_empty(),
+            tick: 0,
+        }
+    }
+
+    /// Move the cursor to the neighbouring card in the provided direction.
+    fn move_cursor(&mut self, direction: Direction) {
+        let (x, y) = (self.cursor % CARDS_PER_SIDE, self.cursor / CARDS_PER_SIDE);
+        self.cursor = match direction {
+            Direction::Left if x > 0 => { self.cursor - 1 },
+            Direction::Right if x < CARDS_PER_SIDE - 1 => { self.cursor + 1 },
+            Direction::Up if y > 0 => { self.cursor - CARDS_PER_SIDE },
+            Direction::Down if y < CARDS_PER_SIDE - 1 => { self.cursor + CARDS_PER_SIDE },
+            _ => { self.cursor },
+        };
+    }
+
+    /// Flip the card under the cursor face up.
+    ///
+    /// Once two cards are face up, they are revealed for a while and then judged
+    ///   (see `judge`).
+    fn flip(&mut self) {
+        if self.states[self.cursor] != CardState::FaceDown { return }
+        self.states[self.cursor] = CardState::FaceUp;
+
+        match self.flipped {
+            [None, _] => { self.flipped[0] = Some(self.cursor) },
+            [Some(_), _] => {
+                self.flipped[1] = Some(self.cursor);
+                // Show both cards blinking from the start of their cycle, without the cursor.
+                self.revealing = Some(Countdown::new(REVEAL_TICKS));
+                self.tick = 0;
+            },
+        }
+    }
+
+    /// Judge the two flipped cards, which remain matched if they are a pair and
+    ///   are otherwise flipped back face down.
+    fn judge(&mut self) {
+        if let [Some(first), Some(second)] = self.flipped {
+            let judgement = if self.pairs[first] == self.pairs[second] {
+                self.matches += 1;
+                CardState::Matched
+            } else {
+                self.misses += 1;
+                CardState::FaceDown
+            };
+            self.states[first] = judgement;
+            self.states[second] = judgement;
+        }
+        self.flipped = [None; 2];
+        self.revealing = None;
+    }
+
+    /// Draw the board onto the screen.
+    ///
+    /// # Arguments
+    /// * show_cursor - Whether to draw the cursor.
+    ///                 The card under the cursor has its bottom right dot inverted.
+    fn draw_board(&mut self, show_cursor: bool) {
+        let step = self.tick % BLINK_STEPS;
+        let is_lit = |blink: u8| blink & (0b10000000 >> step) != 0;
+        self.screen.clear();
+        for card in 0..TOTAL_CARDS {
+            let mut pattern = match self.states[card] {
+                CardState::FaceDown if is_lit(COVER_BLINK) => { COVER_PATTERN },
+                CardState::FaceUp if is_lit(PAIR_BLINKS[self.pairs[card] as usize]) => { BLOCK_PATTERN },
+                CardState::Matched => { BLOCK_PATTERN },
+                _ => { 0 },
+            };
+            if show_cursor && card == self.cursor {
+                pattern ^= CURSOR_PATTERN;
+            }
+
+            // The top left dot of the card's 2x2 block.
+            let x = (card % CARDS_PER_SIDE) * 2;
+            let y = DotScreen::HEIGHT - 1 - (card / CARDS_PER_SIDE) * 2;
+            let block = [(x, y), (x + 1, y), (x, y - 1), (x + 1, y - 1)];
+            for (bit, &(x, y)) in block.iter().enumerate() {
+                if pattern & (0b1000 >> bit) != 0 {
+                    self.screen.add(&Dot { x, y });
+                }
+            }
+        }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.draw_board(false);
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.matches);
+        utils::show_tally(&mut components.display, self.matches);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state,
+    ///   shuffling the cards.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to shuffle the cards.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        for card in 0..TOTAL_CARDS {
+            self.pairs[card] = (card / 2) as u8;
+        }
+        // Fisher-Yates shuffle.
+        for card in (1..TOTAL_CARDS).rev() {
+            let other = utils::random_below(rng, card + 1);
+            self.pairs.swap(card, other);
+        }
+        self.states = [CardState::FaceDown; TOTAL_CARDS];
+        self.flipped = [None; 2];
+        self.revealing = None;
+        self.cursor = 0;
+        self.matches = 0;
+        self.misses = 0;
+        self.tick = 0;
+    }
+}
+
+
+impl GamePlay for MemoryGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    ///
+    /// The input is ignored while the two flipped cards are revealed.
+    fn handle_input(&mut self, input: TickInput) {
+        if self.revealing.is_some() { return }
+        if let Some(direction) = input.direction {
+            self.move_cursor(direction);
+        }
+        if input.pressed {
+            self.flip();
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This judges the two flipped cards once they
+    ///   have been revealed for long enough.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused).
+    ///
+    /// # Returns
+    /// Whether the game continues, i.e. false once every pair has been found
+    ///   or the player has made too many mismatched guesses.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        if self.revealing.as_mut().map_or(false, |revealing| revealing.tick()) {
+            self.judge();
+        }
+        (self.matches < TOTAL_PAIRS) & (self.misses < MAX_MISSES)
+    }
+
+    /// Draw the current game state onto the screen, with the cursor unless the two
+    ///   flipped cards are being revealed.
+    fn render(&mut self) {
+        self.draw_board(self.revealing.is_none());
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the number of pairs matched.
+    fn score(&self) -> usize {
+        self.matches
+    }
+
+    /// Returns whether the player has won the game, by finding every pair.
+    fn is_won(&self) -> bool {
+        self.matches == TOTAL_PAIRS
+    }
+}
@@ -0,0 +1,274 @@
+/// Implementation of the Missile Command game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Missile Command game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'M';
+//   The y-index (row) of the base which the player defends.
+const BASE_ROW: usize = 0;
+//   The maximum number of missiles that may be in flight at once.
+const MAX_MISSILES: usize = 6;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 100;
+//   The initial number of game ticks between each descent of the missiles.
+const INITIAL_DESCENT_TICKS: usize = 8;
+//   The minimum number of game ticks between each descent of the missiles.
+const MIN_DESCENT_TICKS: usize = 2;
+//   The initial number of game ticks between each missile launch.
+const INITIAL_LAUNCH_TICKS: usize = 20;
+//   The minimum number of game ticks between each missile launch.
+const MIN_LAUNCH_TICKS: usize = 6;
+//   The number of intercepted missiles after which the game speeds up.
+const SPEED_UP_INTERVAL: usize = 5;
+
+
+/// The Title Screen for the Missile Command Game ("M").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b00100000,
+            0b00010000,
+            0b00010000,
+            0b00100000,
+            0b01111110,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(missile_command_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Missile Command game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn missile_command_game_loop(components: &mut Components) {
+    let mut game = MissileCommandGame::new();
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The MissileCommandGame object.
+///
+/// Missiles descend from the top of the screen toward the base along the bottom row.
+///   The player moves a blinking crosshair and fires to intercept the missiles
+///   before they land. The game ends when every dot of the base has been destroyed.
+struct MissileCommandGame {
+    /// The bitmask of the surviving base dots, where bit `x` is the dot at column `x`.
+    base: u8,
+    /// The missiles currently in flight.
+    missiles: [Option<Dot>; MAX_MISSILES],
+    /// The crosshair that the player controls.
+    crosshair: Dot,
+    /// The center of the blast fired during the current game tick, if one was.
+    blast: Option<Dot>,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of missiles intercepted.
+    score: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+    /// The number of game ticks between each descent of the missiles.
+    descent_ticks: usize,
+    /// The number of game ticks between each missile launch.
+    launch_ticks: usize,
+}
+
+impl MissileCommandGame {
+
+    /// Construct a new MissileCommandGame object.
+    fn new() -> Self {
+        let mut game = Self {
+            base: 0,
+            missiles: [None; MAX_MISSILES],
+            crosshair: Dot { x: 0, y: 0 },
+            blast: None,
+            screen: DotScreen::new_empty(),
+            score: 0,
+            tick: 0,
+            descent_ticks: INITIAL_DESCENT_TICKS,
+            launch_ticks: INITIAL_LAUNCH_TICKS,
+        };
+        game.reset();
+        return game
+    }
+
+    /// Move the crosshair in the provided direction.
+    ///
+    /// The crosshair is not allowed to enter the base row.
+    fn move_crosshair(&mut self, direction: Option<Direction>) {
+        match direction {
+            Some(Direction::Left) => { self.crosshair.move_left() },
+            Some(Direction::Right) => { self.crosshair.move_right() },
+            Some(Direction::Up) => { self.crosshair.move_up() },
+            Some(Direction::Down) => {
+                if self.crosshair.y > BASE_ROW + 1 { self.crosshair.move_down() }
+            },
+            None => {}
+        }
+    }
+
+    /// Fire at the crosshair.
+    ///
+    /// The blast destroys every missile at, or directly adjacent to, the crosshair,
+    ///   and is shown for the rest of the game tick.
+    fn fire(&mut self) {
+        let center = self.crosshair;
+        self.blast = Some(center);
+
+        let mut intercepted = 0;
+        for slot in self.missiles.iter_mut() {
+            if let Some(missile) = slot {
+                let dx = (missile.x as isize - center.x as isize).abs();
+                let dy = (missile.y as isize - center.y as isize).abs();
+                if dx + dy <= 1 {
+                    *slot = None;
+                    intercepted += 1;
+                }
+            }
+        }
+        for _ in 0..intercepted {
+            self.score += 1;
+            if self.score % SPEED_UP_INTERVAL == 0 { self.increase_speed() }
+        }
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.score);
+        utils::show_tally(&mut components.display, self.score);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    fn reset(&mut self) {
+        self.base = 0b11111111;
+        self.missiles = [None; MAX_MISSILES];
+        self.crosshair = Dot { x: DotScreen::WIDTH / 2, y: DotScreen::HEIGHT / 2 };
+        self.blast = None;
+        self.score = 0;
+        self.tick = 0;
+        self.descent_ticks = INITIAL_DESCENT_TICKS;
+        self.launch_ticks = INITIAL_LAUNCH_TICKS;
+        self.render();
+    }
+
+    /// Decrease the time between missile descents and launches.
+    fn increase_speed(&mut self) {
+        self.descent_ticks = (self.descent_ticks - 1).max(MIN_DESCENT_TICKS);
+        self.launch_ticks = (self.launch_ticks - 2).max(MIN_LAUNCH_TICKS);
+    }
+}
+
+
+impl GamePlay for MissileCommandGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    ///
+    /// A single press fires a single shot.
+    fn handle_input(&mut self, input: TickInput) {
+        self.blast = None;
+        self.move_crosshair(input.direction);
+        if input.pressed {
+            self.fire();
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This descends the missiles toward the base,
+    ///   resolves any that land, and launches new missiles.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+
+        if self.tick % self.descent_ticks == 0 {
+            for slot in self.missiles.iter_mut() {
+                if let Some(missile) = slot {
+                    missile.move_down();
+                    if missile.y == BASE_ROW {
+                        // The missile has landed, destroying the base beneath it.
+                        self.base &= !(1 << missile.x);
+                        *slot = None;
+                    }
+                }
+            }
+        }
+        if self.base == 0 { return false }
+
+        if self.tick % self.launch_ticks == 0 {
+            if let Some(slot) = self.missiles.iter_mut().find(|slot| slot.is_none()) {
+                let x = utils::random_below(rng, DotScreen::WIDTH);
+                *slot = Some(Dot { x, y: DotScreen::HEIGHT - 1 });
+            }
+        }
+        return true
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The crosshair blinks on alternating game ticks so that it can be distinguished
+    ///   from the missiles, and a blast fired during the tick is drawn around it.
+    fn render(&mut self) {
+        self.screen.clear();
+        for x in 0..DotScreen::WIDTH {
+            if self.base & (1 << x) != 0 {
+                self.screen.add(&Dot { x, y: BASE_ROW });
+            }
+        }
+        for missile in self.missiles.iter().flatten() {
+            self.screen.add(missile);
+        }
+        if self.tick % 2 == 0 {
+            self.screen.add(&self.crosshair);
+        }
+        if let Some(center) = self.blast {
+            crate::graphics::Stamp::Plus.draw(&mut self.screen, &center, 1);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score: the number of missiles intercepted.
+    fn score(&self) -> usize {
+        self.score
+    }
+}
@@ -1,4 +1,49 @@
+mod animations;
+#[macro_use]
+mod assets;
+// Declared before the games, which use its `game!` macro.
+#[macro_use]
+mod game;
+mod asteroids;
+mod bomberman;
+mod boulder;
+mod cave;
+mod climber;
+mod clock;
+mod difficulty;
+mod digits;
+mod falldown;
+mod gallery;
+mod gauntlet;
+mod hockey;
+mod lander;
+mod level;
+mod mastermind;
+mod math;
+mod maze;
+mod memory;
+mod missile_command;
+mod overlay;
+mod puzzles;
+mod qix;
+mod replay;
+mod runner;
+mod sand;
+mod saved_game;
 mod selection;
+mod settings_menu;
 mod snake;
+mod tanks;
+mod theremin;
+mod tug_of_war;
+mod tunnel;
+mod utils;
+mod versus;
 
-pub use selection::SelectionScreen;
+pub use assets::{Demo, GameAssets};
+pub use difficulty::{choose_difficulty, Difficulty};
+pub use game::Game;
+pub use maze::{CarveStep, MazeGenerator};
+pub use replay::{arm as arm_replay, ReplayKind};
+pub use selection::{configure_game, switch_to_game, GameSwitch, SelectionScreen};
+pub use versus::{choose_handicaps, Handicaps, VersusMatch};
@@ -0,0 +1,252 @@
+/// The in-game overlay menu, which any game can open to pause the game-play.
+///
+/// The menu offers the following items, each shown as an icon filling the screen:
+///   * Resume     - Return to the game (the icon alternates with the paused game).
+///   * Restart    - Return to the game, which should reset itself.
+///   * Save       - Save the game to the EEPROM, then return to the selection screen.
+///                  This is only offered by games which can be saved (see `open_with_save`).
+///   * Quit       - Return to the selection screen.
+///   * Brightness - Up and Down adjust the brightness of the display, shown as a ramp.
+///                  This lasts until the next reset; the settings menu saves it for good.
+///
+/// Left and Right choose the item and a button press selects it.
+use crate::{sound, Components, Direction};
+use crate::peripherals::{progmem, DotDisplay, DotScreen};
+use super::utils;
+
+
+/// The icon of the Resume item (a play symbol).
+#[link_section = ".progmem.data"]
+static RESUME_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00000000,
+            0b01111111,
+            0b00111110,
+            0b00011100,
+            0b00001000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+/// The icon of the Restart item (a circular arrow).
+#[link_section = ".progmem.data"]
+static RESTART_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00111100,
+            0b01000010,
+            0b01000010,
+            0b01010010,
+            0b00110010,
+            0b01110100,
+            0b00000000,
+        ]
+    );
+
+/// The icon of the Save item (an arrow down into a tray).
+#[link_section = ".progmem.data"]
+static SAVE_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00001110,
+            0b00000010,
+            0b00010010,
+            0b11111010,
+            0b00010010,
+            0b00000010,
+            0b00001110,
+            0b00000000,
+        ]
+    );
+
+/// The icon of the Quit item (a cross).
+#[link_section = ".progmem.data"]
+static QUIT_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01000010,
+            0b00100100,
+            0b00011000,
+            0b00011000,
+            0b00100100,
+            0b01000010,
+            0b00000000,
+        ]
+    );
+
+
+/// Read an icon of the menu from program memory.
+fn read_icon(icon: &'static DotScreen) -> DotScreen {
+    // Every icon is placed in the `.progmem.data` section.
+    unsafe { progmem::read_value(icon) }
+}
+
+
+/// The choices on the overlay menu, which the game carries out.
+#[derive(Copy, Clone, PartialEq)]
+pub enum OverlayChoice {
+    /// Continue the game from where it was paused.
+    Resume,
+    /// Start the game over from the beginning.
+    Restart,
+    /// Save the game, then leave it, returning to the selection screen.
+    SaveAndQuit,
+    /// Leave the game, returning to the selection screen.
+    Quit,
+}
+
+
+/// The items of the overlay menu, in the order they are shown.
+#[derive(Copy, Clone, PartialEq)]
+enum Item {
+    Resume,
+    Restart,
+    Save,
+    Quit,
+    Brightness,
+}
+
+impl Item {
+
+    /// The items offered by every game, in the order they are shown.
+    const ALL: [Item; 4] = [Item::Resume, Item::Restart, Item::Quit, Item::Brightness];
+
+    /// The items offered by games which can be saved, in the order they are shown.
+    const WITH_SAVE: [Item; 5] = [Item::Resume, Item::Restart, Item::Save, Item::Quit, Item::Brightness];
+
+    /// Draw the icon of the item.
+    ///
+    /// # Arguments
+    /// * paused     - The screen of the paused game.
+    /// * brightness - The current brightness level of the display.
+    /// * tick       - The number of menu ticks elapsed, used to animate the icons.
+    fn icon(&self, paused: &DotScreen, brightness: u8, tick: usize) -> DotScreen {
+        match self {
+            Item::Resume => { if (tick / 4) % 2 == 0 { read_icon(&RESUME_ICON) } else { *paused } },
+            Item::Restart => { read_icon(&RESTART_ICON) },
+            Item::Save => { read_icon(&SAVE_ICON) },
+            Item::Quit => { read_icon(&QUIT_ICON) },
+            Item::Brightness => {
+                let mut screen = DotScreen::new_empty();
+                utils::draw_brightness(&mut screen, brightness);
+                screen
+            },
+        }
+    }
+}
+
+
+/// Open the overlay menu, pausing the game until an item is selected.
+///
+/// This only returns once the button has been released, so the press which
+///   selects an item is not seen by the game. Games should only open the menu
+///   on a fresh press of the button (not while it is held).
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * paused     - The screen of the paused game, which is shown again on returning.
+///
+/// # Returns
+/// Whether to resume, restart, or quit the game. On quitting, the game should return
+///   from its game loop, so the selection screen runs again.
+pub fn open(components: &mut Components, paused: &DotScreen) -> OverlayChoice {
+    run(components, paused, &Item::ALL)
+}
+
+/// Open the overlay menu of a game which can be saved, see `open`.
+///
+/// This also offers to save the game and quit, in which case the game should save itself
+///   (see `saved_game::save`) before returning from its game loop.
+pub fn open_with_save(components: &mut Components, paused: &DotScreen) -> OverlayChoice {
+    run(components, paused, &Item::WITH_SAVE)
+}
+
+/// Run the overlay menu, offering the items, see `open`.
+fn run(components: &mut Components, paused: &DotScreen, items: &[Item]) -> OverlayChoice {
+    const TICK_INTERVAL_MS: usize = 100;
+    const RELEASE_POLL_MS: usize = 50;
+    let mut index = 0;
+    let mut tick = 0usize;
+    let choice = loop {
+        let item = items[index];
+        let brightness = components.display.brightness();
+        components.display.show(&item.icon(paused, brightness, tick));
+        tick += 1;
+
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        // A paused game may be left alone, so go to sleep after a while.
+        if components.sleep_if_idle() { continue }
+        // A switch to another game (requested on the console) quits the paused game.
+        if super::selection::is_switch_requested() { break OverlayChoice::Quit }
+        if input.pressed {
+            match item {
+                Item::Resume | Item::Brightness => { break OverlayChoice::Resume },
+                Item::Restart => { break OverlayChoice::Restart },
+                Item::Save => { break OverlayChoice::SaveAndQuit },
+                Item::Quit => { break OverlayChoice::Quit },
+            }
+        }
+
+        // Each push of the JoyStick moves a single step, however long it is held.
+        match input.pushed {
+            Some(Direction::Left) => {
+                index = (index + items.len() - 1) % items.len();
+                components.play_effect(Some(&sound::BLIP));
+            },
+            Some(Direction::Right) => {
+                index = (index + 1) % items.len();
+                components.play_effect(Some(&sound::BLIP));
+            },
+            Some(Direction::Up) if item == Item::Brightness => {
+                components.settings.brightness = (brightness + 1).min(DotDisplay::BRIGHTNESS_LEVELS - 1);
+                components.apply_settings();
+            },
+            Some(Direction::Down) if item == Item::Brightness => {
+                components.settings.brightness = brightness.saturating_sub(1);
+                components.apply_settings();
+            },
+            _ => {}
+        }
+    };
+    while utils::poll_tick(components, RELEASE_POLL_MS).button {}
+    let quitting = (choice == OverlayChoice::Quit) | (choice == OverlayChoice::SaveAndQuit);
+    if !quitting { components.display.show(paused) }
+    choice
+}
+
+
+/// Ask the player whether to continue a saved game, or to start a new game.
+///
+/// The Resume icon (continue) and the Restart icon (new game) are shown, the chosen one
+///   blinking. Left and Right choose between them, and a button press selects.
+///
+/// # Arguments
+/// * components - The peripheral components.
+///
+/// # Returns
+/// Whether to continue the saved game.
+pub fn choose_continue(components: &mut Components) -> bool {
+    const TICK_INTERVAL_MS: usize = 150;
+    let mut resume = true;
+    let mut tick = 0usize;
+    loop {
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        if input.pressed | super::selection::is_switch_requested() { return resume }
+
+        match input.direction {
+            Some(Direction::Left) => { resume = true },
+            Some(Direction::Right) => { resume = false },
+            _ => {}
+        }
+
+        tick += 1;
+        let icon = read_icon(if resume { &RESUME_ICON } else { &RESTART_ICON });
+        let blank = DotScreen::new_empty();
+        components.display.show(if (tick / 2) % 2 == 0 { &icon } else { &blank });
+    }
+}
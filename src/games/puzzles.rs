@@ -0,0 +1,35 @@
+/// The flood fill shared by the games which divide the board into regions, e.g. Qix,
+///   which claims the region of the board the enemy cannot reach.
+use crate::peripherals::{Dot, DotScreen};
+
+
+/// Compute the set of dots reachable from the start dot, without passing through any wall.
+///
+/// Movement is allowed in the four cardinal directions. The reachable region is grown
+///   one step in every direction at a time (operating on entire columns at once)
+///   until it stops changing.
+///
+/// # Arguments
+/// * walls - The screen whose lit dots are impassable.
+/// * start - The dot from which to begin the fill.
+///
+/// # Returns
+/// A screen with every reachable dot lit. If the start dot is a wall, this is empty.
+pub fn flood_fill(walls: &DotScreen, start: &Dot) -> DotScreen {
+    let mut region = DotScreen::new_empty();
+    if walls.is_dot_on(start) { return region }
+    region.add(start);
+
+    loop {
+        let mut grown = region;
+        for x in 0..DotScreen::WIDTH {
+            let column = region.columns[x];
+            let mut expanded = column | (column << 1) | (column >> 1);
+            if x > 0 { expanded |= region.columns[x - 1] }
+            if x < DotScreen::WIDTH - 1 { expanded |= region.columns[x + 1] }
+            grown.columns[x] = expanded & !walls.columns[x];
+        }
+        if grown.columns == region.columns { return region }
+        region = grown;
+    }
+}
@@ -0,0 +1,257 @@
+/// Implementation of the Qix game for an 8x8 LED Dot Screen.
+use crate::Components;
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, puzzles, utils::{self, TickInput}};
+
+// Constants for the Qix game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'Q';
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 100;
+//   The number of game ticks between each move of the enemy.
+const ENEMY_MOVE_TICKS: usize = 3;
+//   The number of game ticks the player dot spends lit, and then unlit, while blinking.
+const BLINK_TICKS: usize = 2;
+//   The number of dots inside the border, which may be claimed.
+const CLAIMABLE_DOTS: usize = (DotScreen::WIDTH - 2) * (DotScreen::HEIGHT - 2);
+//   The percentage of the claimable dots which must be claimed to win.
+const TARGET_PERCENT: usize = 75;
+
+
+/// The Title Screen for the Qix Game ("Q").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00111100,
+            0b01000010,
+            0b01000010,
+            0b01000110,
+            0b00111101,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(qix_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Qix game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn qix_game_loop(components: &mut Components) {
+    let mut game = QixGame::new();
+    game.reset(&mut components.analog);
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The QixGame object.
+///
+/// The player starts on the border of the screen, which is already claimed. Moving off
+///   the claimed area draws a line, and returning to the claimed area closes the line,
+///   claiming every region which the enemy is not inside. The enemy bounces around the
+///   unclaimed area, and the game ends if it hits the line while it is being drawn, or
+///   once enough of the screen has been claimed.
+struct QixGame {
+    /// The claimed area, including the border.
+    claimed: DotScreen,
+    /// The line currently being drawn through the unclaimed area.
+    trail: DotScreen,
+    /// The position of the player.
+    player: Dot,
+    /// The position of the enemy.
+    enemy: Dot,
+    /// The horizontal and vertical velocity of the enemy, each either -1 or 1.
+    enemy_velocity: (isize, isize),
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl QixGame {
+
+    /// Construct a new QixGame object.
+    fn new() -> Self {
+        Self {
+            claimed: DotScreen::new_empty(),
+            trail: DotScreen::new_empty(),
+            player: Dot { x: 0, y: 0 },
+            enemy: Dot { x: 0, y: 0 },
+            enemy_velocity: (1, 1),
+            screen: DotScreen::new_empty(),
+            tick: 0,
+        }
+    }
+
+    /// Move the player to the next dot, drawing the line through unclaimed dots.
+    ///
+    /// The player may not cross the line they are drawing. Returning to the claimed
+    ///   area closes the line and claims the enclosed regions.
+    ///
+    /// # Arguments
+    /// * next - The dot to which the player is moving.
+    fn move_player(&mut self, next: Dot) {
+        if self.trail.is_dot_on(&next) { return }
+        self.player = next;
+        if self.claimed.is_dot_off(&next) {
+            self.trail.add(&next);
+        } else if self.trail.iter_on().next().is_some() {
+            self.close_trail();
+        }
+    }
+
+    /// Claim the drawn line, along with every region it encloses which the enemy is not inside.
+    fn close_trail(&mut self) {
+        for x in 0..DotScreen::WIDTH {
+            self.claimed.columns[x] |= self.trail.columns[x];
+        }
+        self.trail.clear();
+
+        let enemy_region = puzzles::flood_fill(&self.claimed, &self.enemy);
+        for x in 0..DotScreen::WIDTH {
+            self.claimed.columns[x] = !enemy_region.columns[x];
+        }
+    }
+
+    /// Move the enemy one step diagonally, bouncing off the claimed area.
+    ///
+    /// The enemy reverses its horizontal velocity, its vertical velocity, or both,
+    ///   whichever first leaves it in the unclaimed area. If it is boxed in, it stays put.
+    fn move_enemy(&mut self) {
+        let (dx, dy) = self.enemy_velocity;
+        for &(dx, dy) in &[(dx, dy), (-dx, dy), (dx, -dy), (-dx, -dy)] {
+            let next = Dot {
+                x: (self.enemy.x as isize + dx) as usize,
+                y: (self.enemy.y as isize + dy) as usize,
+            };
+            if self.claimed.is_dot_off(&next) {
+                self.enemy = next;
+                self.enemy_velocity = (dx, dy);
+                return
+            }
+        }
+    }
+
+    /// Returns the percentage of the claimable dots (those inside the border) which are claimed.
+    fn percent_claimed(&self) -> usize {
+        let claimed: usize = self.claimed.columns.iter().map(|c| c.count_ones() as usize).sum();
+        let border = DotScreen::TOTAL_DOTS - CLAIMABLE_DOTS;
+        (claimed - border) * 100 / CLAIMABLE_DOTS
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///   The score tally is shown in proportion to the screen, so a full screen is 100%.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        let percent = self.percent_claimed();
+        components.report_score(GAME_ID, percent);
+        utils::show_tally(&mut components.display, percent * DotScreen::TOTAL_DOTS / 100);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator, used to place the enemy.
+    fn reset(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.claimed = DotScreen::new_full();
+        self.trail.clear();
+        for x in 1..(DotScreen::WIDTH - 1) {
+            for y in 1..(DotScreen::HEIGHT - 1) {
+                self.claimed.remove(&Dot { x, y });
+            }
+        }
+
+        self.player = Dot { x: 0, y: 0 };
+        self.enemy = Dot {
+            x: 1 + utils::random_below(rng, DotScreen::WIDTH - 2),
+            y: 1 + utils::random_below(rng, DotScreen::HEIGHT - 2),
+        };
+        let sign = |coin: usize| if coin == 0 { -1 } else { 1 };
+        self.enemy_velocity = (sign(utils::random_below(rng, 2)), sign(utils::random_below(rng, 2)));
+        self.tick = 0;
+        self.render();
+    }
+}
+
+
+impl GamePlay for QixGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction {
+            self.move_player(self.player.step(direction));
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This moves the enemy and checks whether it
+    ///   has hit the line being drawn, or whether enough of the screen has been claimed.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused, the enemy moves deterministically).
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        if self.tick % ENEMY_MOVE_TICKS == 0 {
+            self.move_enemy();
+        }
+
+        let hit = self.trail.is_dot_on(&self.enemy) | (self.enemy == self.player);
+        !hit & (self.percent_claimed() < TARGET_PERCENT)
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The player blinks, so that they can be seen while on the claimed area.
+    fn render(&mut self) {
+        for x in 0..DotScreen::WIDTH {
+            self.screen.columns[x] = self.claimed.columns[x] | self.trail.columns[x];
+        }
+        self.screen.add(&self.enemy);
+        if (self.tick / BLINK_TICKS) % 2 == 0 {
+            self.screen.toggle(&self.player);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.reset(rng);
+    }
+
+    /// Returns the current score: the percentage of the screen claimed.
+    fn score(&self) -> usize {
+        self.percent_claimed()
+    }
+}
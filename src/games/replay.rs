@@ -0,0 +1,218 @@
+/// Recording and playing back the input of a game over the serial connection, to
+///   reproduce bugs, or to show off a high-score run.
+///
+/// A replay is armed with a seed (see the `record` and `replay` commands of the console),
+///   and begins with the next game selected on the selection screen. The random number
+///   generator is seeded for the whole game, so a run played back with the same seed
+///   (on the same build) unfolds exactly as it was recorded.
+///
+/// The input of each game tick (see `TickInput`) is written as four characters: the
+///   direction held (`L`, `R`, `U`, `D`, or `-`), the direction newly pushed (`l`, `r`,
+///   `u`, `d`, or `-`), `b` if the button was down (or `-`), and `p` if it was newly
+///   pressed, or `h` if it was held for a long press (or `-`). The protocol is line based ASCII:
+///   * Recording writes `REC <game> <seed>`, followed by `@<tick> <input>...` (the input
+///     of each player) for each tick with any input, and `END <ticks>` once the game is over.
+///   * Playing back writes `PLAY <game> <seed>`, followed by `?<tick>` for each tick. Each
+///     is answered by a line of the input of each player for that tick (an empty line for
+///     none), or by `end` to hand the game back to the JoySticks. Playback also ends if no
+///     answer is received within `ANSWER_TIMEOUT_MS`. It is over with `END <ticks>`.
+///
+/// Only the input polled through the tick helpers (see `utils::poll_tick`, `poll_tick2`, and
+///   `animate_tick`) is recorded, so the games which read the JoyStick directly (and the
+///   menus of a game, e.g. the choice of difficulty) can't be replayed. The serial bytes
+///   of the virtual JoyStick, the screen mirror, and the leaderboard share the connection,
+///   so these are best left off meanwhile.
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
+use crate::components::AnalogDevices;
+use crate::peripherals::{clock, usart, watchdog};
+use crate::Direction;
+use super::utils::TickInput;
+
+/// The time to wait for the answer to the request for the input of a tick, in milliseconds.
+const ANSWER_TIMEOUT_MS: u32 = 2000;
+/// The number of bytes of the longest answer (the input of both players).
+const ANSWER_CAPACITY: usize = 12;
+/// The number of characters of the input of a tick.
+const INPUT_LENGTH: usize = 4;
+
+
+/// Whether a replay records or plays back the input.
+#[derive(Copy, Clone, PartialEq)]
+pub enum ReplayKind {
+    /// Record the input of the game.
+    Record,
+    /// Play back the input of a recorded game.
+    Play,
+}
+
+/// The state of the replay.
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    /// No replay.
+    Off,
+    /// A replay begins with the next game selected, with the random number generator seeded.
+    Armed(ReplayKind, u16),
+    /// The replay of the game in progress.
+    Active(ReplayKind),
+}
+
+/// The state of the replay, and the number of ticks of the game replayed so far.
+static STATE: Mutex<Cell<(Mode, u32)>> = Mutex::new(Cell::new((Mode::Off, 0)));
+
+
+/// Arm a replay, which begins with the next game selected.
+///
+/// # Arguments
+/// * kind - Whether to record or play back the input of the game.
+/// * seed - The seed of the random number generator, for the game.
+pub fn arm(kind: ReplayKind, seed: u16) {
+    interrupt::free(|cs| STATE.borrow(cs).set((Mode::Armed(kind, seed), 0)));
+}
+
+/// Begin the armed replay, if any, as a game is selected.
+///
+/// # Arguments
+/// * analog - The analog devices, which contain the random number generator.
+/// * game   - The index of the selected game.
+pub fn begin(analog: &mut AnalogDevices, game: usize) {
+    let (kind, seed) = match interrupt::free(|cs| STATE.borrow(cs).get()).0 {
+        Mode::Armed(kind, seed) => (kind, seed),
+        _ => { return }
+    };
+    analog.seed_rng(Some(seed));
+    let label = if kind == ReplayKind::Record { "REC" } else { "PLAY" };
+    ufmt::uwriteln!(&mut usart::Writer, "{} {} {}\r", label, game, seed).ok();
+    interrupt::free(|cs| STATE.borrow(cs).set((Mode::Active(kind), 0)));
+}
+
+/// End the replay of the game which is over, if any, returning the random number
+///   generator to its unseeded sequence. A replay armed meanwhile is kept.
+///
+/// # Arguments
+/// * analog - The analog devices, which contain the random number generator.
+pub fn end(analog: &mut AnalogDevices) {
+    let (mode, _) = interrupt::free(|cs| STATE.borrow(cs).get());
+    if let Mode::Armed(..) = mode { return }
+    analog.seed_rng(None);
+    if let Mode::Active(_) = mode { stop() }
+}
+
+/// Stop the replay in progress, writing the number of ticks replayed.
+fn stop() {
+    let ticks = interrupt::free(|cs| STATE.borrow(cs).replace((Mode::Off, 0)).1);
+    ufmt::uwriteln!(&mut usart::Writer, "END {}\r", ticks).ok();
+}
+
+/// Pass the input of a game tick through the replay in progress, if any: recording it,
+///   or replacing it with the input played back.
+///
+/// # Arguments
+/// * inputs - The input of each player for the tick.
+pub fn pass(inputs: &mut [TickInput]) {
+    let (mode, tick) = interrupt::free(|cs| STATE.borrow(cs).get());
+    match mode {
+        Mode::Active(ReplayKind::Record) => { record(tick, inputs) },
+        Mode::Active(ReplayKind::Play) => {
+            if !play_back(tick, inputs) { return stop() }
+        },
+        _ => { return }
+    }
+    interrupt::free(|cs| STATE.borrow(cs).set((mode, tick + 1)));
+}
+
+/// Write the input of a tick, unless there was none.
+fn record(tick: u32, inputs: &[TickInput]) {
+    if inputs.iter().all(|input| encode(input) == *b"----") { return }
+    ufmt::uwrite!(&mut usart::Writer, "@{}", tick).ok();
+    for input in inputs.iter() {
+        usart::write_byte(b' ');
+        usart::write(&encode(input));
+    }
+    usart::write(b"\r\n");
+}
+
+/// Request the input of a tick, and replace the input with the answer.
+///
+/// # Returns
+/// Whether the playback goes on: false once the answer is `end` (or never arrives).
+fn play_back(tick: u32, inputs: &mut [TickInput]) -> bool {
+    ufmt::uwriteln!(&mut usart::Writer, "?{}\r", tick).ok();
+    let mut answer = [0u8; ANSWER_CAPACITY];
+    let length = match read_answer(&mut answer) {
+        Some(length) => length,
+        None => { return false }
+    };
+    let answer = &answer[..length];
+    if answer == b"end" { return false }
+    let mut codes = answer.split(|&byte| byte == b' ').filter(|code| !code.is_empty());
+    for input in inputs.iter_mut() {
+        *input = codes.next().and_then(decode).unwrap_or(TickInput {
+            direction: None, button: false, pressed: false, pushed: None, held: false
+        });
+    }
+    true
+}
+
+/// Read a line of the answer (without its line ending), dropping any bytes beyond its capacity.
+///
+/// # Returns
+/// The length of the answer, or None if it isn't received in time.
+fn read_answer(answer: &mut [u8; ANSWER_CAPACITY]) -> Option<usize> {
+    let start_ms = clock::millis();
+    let mut length = 0;
+    loop {
+        watchdog::feed();
+        if clock::elapsed_since(start_ms) >= ANSWER_TIMEOUT_MS { return None }
+        match usart::read_byte() {
+            // A line ending on its own ends the line of a CR LF pair, so is skipped.
+            Some(b'\r') | Some(b'\n') if length == 0 => {},
+            Some(b'\r') | Some(b'\n') => { return Some(length) },
+            Some(byte) => {
+                if length < ANSWER_CAPACITY {
+                    answer[length] = byte;
+                    length += 1;
+                }
+            },
+            None => {},
+        }
+    }
+}
+
+/// Encode the input of a tick as its four characters.
+fn encode(input: &TickInput) -> [u8; INPUT_LENGTH] {
+    let letter = |direction: Option<Direction>| match direction {
+        Some(Direction::Left) => { b'L' },
+        Some(Direction::Right) => { b'R' },
+        Some(Direction::Up) => { b'U' },
+        Some(Direction::Down) => { b'D' },
+        None => { b'-' },
+    };
+    let pushed = letter(input.pushed);
+    [
+        letter(input.direction),
+        if pushed == b'-' { pushed } else { pushed.to_ascii_lowercase() },
+        if input.button { b'b' } else { b'-' },
+        if input.pressed { b'p' } else if input.held { b'h' } else { b'-' },
+    ]
+}
+
+/// Decode the input of a tick from its four characters, or None if they aren't valid.
+fn decode(code: &[u8]) -> Option<TickInput> {
+    if code.len() != INPUT_LENGTH { return None }
+    let direction = |letter: u8| match letter.to_ascii_uppercase() {
+        b'L' => { Some(Some(Direction::Left)) },
+        b'R' => { Some(Some(Direction::Right)) },
+        b'U' => { Some(Some(Direction::Up)) },
+        b'D' => { Some(Some(Direction::Down)) },
+        b'-' => { Some(None) },
+        _ => { None },
+    };
+    Some(TickInput {
+        direction: direction(code[0])?,
+        pushed: direction(code[1])?,
+        button: code[2] == b'b',
+        pressed: code[3] == b'p',
+        held: code[3] == b'h',
+    })
+}
@@ -0,0 +1,241 @@
+/// Implementation of the Runner game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use crate::sound::Melody;
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Runner game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'J';
+//   The x-index (column) along which the player runs.
+const PLAYER_X: usize = 1;
+//   The y-index (row) of the ground. The player and obstacles stand on the row above.
+const GROUND_ROW: usize = 0;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 50;
+//   The number of sub-dot units in the height of a dot, for smooth jumping.
+const UNITS_PER_DOT: isize = 8;
+//   The upward velocity of a jump, and the downward acceleration of gravity,
+//   in units per tick and units per tick per tick respectively.
+const JUMP_VELOCITY: isize = 10;
+const GRAVITY: isize = 2;
+//   The initial and minimum number of game ticks between each scroll of the obstacles.
+const INITIAL_SCROLL_TICKS: usize = 4;
+const MIN_SCROLL_TICKS: usize = 1;
+//   The number of columns scrolled after which the obstacles scroll faster.
+const SPEED_UP_INTERVAL: usize = 80;
+//   The minimum number of empty columns between obstacles, and the random extra spacing.
+const MIN_OBSTACLE_GAP: usize = 4;
+const OBSTACLE_GAP_SPREAD: usize = 4;
+//   The number of columns scrolled for each point scored.
+const DISTANCE_PER_POINT: usize = 4;
+
+
+/// The Title Screen for the Runner Game ("J").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00000100,
+            0b01000010,
+            0b01111100,
+            0b01000000,
+            0b00000000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(runner_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Runner game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn runner_game_loop(components: &mut Components) {
+    let mut game = RunnerGame::new();
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The RunnerGame object.
+///
+/// The player runs along the ground while obstacles scroll in from the right.
+///   Pressing the JoyStick button jumps over the cacti on the ground, and holding
+///   the JoyStick down ducks beneath the birds flying low overhead. The obstacles
+///   scroll faster the further the player runs, and the game ends on any collision.
+struct RunnerGame {
+    /// The obstacles, scrolling from right to left.
+    obstacles: DotScreen,
+    /// The height of the player's feet above the row above the ground, in units.
+    altitude: isize,
+    /// The vertical velocity of the player (positive is upward), in units per tick.
+    velocity: isize,
+    /// Whether the player is ducking.
+    ducking: bool,
+    /// The number of columns to scroll before the next obstacle is generated.
+    next_obstacle: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of columns scrolled.
+    distance: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl RunnerGame {
+
+    /// Construct a new RunnerGame object.
+    fn new() -> Self {
+        let mut game = Self {
+            obstacles: DotScreen::new_empty(),
+            altitude: 0,
+            velocity: 0,
+            ducking: false,
+            next_obstacle: 0,
+            screen: DotScreen::new_empty(),
+            distance: 0,
+            tick: 0,
+        };
+        game.reset();
+        return game
+    }
+
+    /// Returns the dots occupied by the player: two tall while standing or jumping,
+    ///   and one tall while ducking.
+    fn player_dots(&self) -> [Dot; 2] {
+        let feet = GROUND_ROW + 1 + (self.altitude / UNITS_PER_DOT) as usize;
+        let head = if self.ducking { feet } else { feet + 1 };
+        [Dot { x: PLAYER_X, y: feet }, Dot { x: PLAYER_X, y: head }]
+    }
+
+    /// Scroll the obstacles one column left, generating a new column at the right.
+    ///
+    /// An obstacle is either a short cactus, a tall cactus, or a low-flying bird
+    ///   at the height of the standing player's head.
+    fn scroll(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.obstacles.shift_left(1, false);
+        self.distance += 1;
+
+        if self.next_obstacle > 0 {
+            self.next_obstacle -= 1;
+            return
+        }
+        let x = DotScreen::WIDTH - 1;
+        let rows: &[usize] = match utils::random_below(rng, 3) {
+            0 => { &[GROUND_ROW + 1] },
+            1 => { &[GROUND_ROW + 1, GROUND_ROW + 2] },
+            _ => { &[GROUND_ROW + 2] },
+        };
+        for &y in rows {
+            self.obstacles.add(&Dot { x, y });
+        }
+        self.next_obstacle = MIN_OBSTACLE_GAP + utils::random_below(rng, OBSTACLE_GAP_SPREAD);
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.distance / DISTANCE_PER_POINT);
+        utils::show_tally(&mut components.display, self.distance / DISTANCE_PER_POINT);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    fn reset(&mut self) {
+        self.obstacles.clear();
+        self.altitude = 0;
+        self.velocity = 0;
+        self.ducking = false;
+        self.next_obstacle = MIN_OBSTACLE_GAP;
+        self.distance = 0;
+        self.tick = 0;
+        self.render();
+    }
+}
+
+
+impl GamePlay for RunnerGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        let on_ground = self.altitude == 0;
+        self.ducking = on_ground & (input.direction == Some(Direction::Down));
+        if on_ground & input.button & !self.ducking {
+            self.velocity = JUMP_VELOCITY;
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This moves the player through the jump,
+    ///   scrolls the obstacles, and checks whether the player has hit an obstacle.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        self.altitude = (self.altitude + self.velocity).max(0);
+        self.velocity = if self.altitude == 0 { 0 } else { self.velocity - GRAVITY };
+
+        let speed_ups = self.distance / SPEED_UP_INTERVAL;
+        let scroll_ticks = INITIAL_SCROLL_TICKS.saturating_sub(speed_ups).max(MIN_SCROLL_TICKS);
+        if self.tick % scroll_ticks == 0 {
+            self.scroll(rng);
+        }
+        self.player_dots().iter().all(|dot| self.obstacles.is_dot_off(dot))
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen = self.obstacles;
+        self.screen.draw_h_line(&Dot { x: 0, y: GROUND_ROW }, DotScreen::WIDTH);
+        for dot in self.player_dots().iter() {
+            self.screen.add(dot);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score: the distance run.
+    fn score(&self) -> usize {
+        self.distance / DISTANCE_PER_POINT
+    }
+
+    /// The score rises steadily, so its increases play no sound.
+    fn on_score(&self) -> Option<&'static Melody> {
+        None
+    }
+}
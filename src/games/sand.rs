@@ -0,0 +1,270 @@
+/// Implementation of the Falling Sand toy for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Falling Sand toy.
+//   The length of a single frame of the simulation.
+const TICK_INTERVAL_MS: usize = 60;
+//   The y-index (row) along which the cursor moves, and from which sand is poured.
+const POUR_ROW: usize = DotScreen::HEIGHT - 1;
+
+
+/// The Title Screen for the Falling Sand Toy (a pile of sand beneath a falling grain).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000001,
+            0b00000011,
+            0b00000111,
+            0b01001111,
+            0b00001111,
+            0b00000111,
+            0b00000011,
+            0b00000001,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(sand_game_loop);
+
+
+/// The game loop which runs the Falling Sand toy.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn sand_game_loop(components: &mut Components) {
+    let mut toy = SandMode::new();
+    if game::play(&mut toy, components) { toy.overflow(components) }
+}
+
+
+/// The tools with which the sand may be played.
+#[derive(Copy, Clone, PartialEq)]
+enum Tool {
+    /// Pour sand from the cursor.
+    Pour,
+    /// Erase the topmost grain of the column beneath the cursor.
+    Erase,
+    /// Shake the screen, emptying it of sand.
+    Shake,
+}
+
+impl Tool {
+
+    /// Returns the tool which follows this one, when switching tools.
+    fn next(&self) -> Self {
+        match self {
+            Tool::Pour => { Tool::Erase },
+            Tool::Erase => { Tool::Shake },
+            Tool::Shake => { Tool::Pour },
+        }
+    }
+}
+
+
+/// The SandMode object.
+///
+/// Holding the JoyStick down uses the current tool at the cursor along the top row, and
+///   pressing the JoyStick button switches between the tools: pour, erase, and shake.
+///   Each grain falls straight down, or slides diagonally down off the side of a pile.
+///   Moving the cursor with the JoyStick also tilts the screen, shifting the pile in that
+///   direction. There is no score; the toy only ends once the sand overflows the top
+///   of the screen.
+///
+/// The entire screen is simulated every frame.
+struct SandMode {
+    /// The grains of sand.
+    sand: DotScreen,
+    /// The x-index of the cursor along the top row.
+    cursor: usize,
+    /// The current tool.
+    tool: Tool,
+    /// The direction in which the screen is tilted, if any.
+    tilt: Option<Direction>,
+    /// Whether the current tool is being used.
+    using_tool: bool,
+    /// The screen depicting the current state of the toy.
+    screen: DotScreen,
+    /// The number of frames elapsed.
+    tick: usize,
+}
+
+impl SandMode {
+
+    /// Construct a new SandMode object.
+    fn new() -> Self {
+        Self {
+            sand: DotScreen::new_empty(),
+            cursor: DotScreen::WIDTH / 2,
+            tool: Tool::Pour,
+            tilt: None,
+            using_tool: false,
+            screen: DotScreen::new_empty(),
+            tick: 0,
+        }
+    }
+
+    /// Use the current tool at the cursor.
+    ///
+    /// # Returns
+    /// Whether the sand overflowed, because sand was poured onto a full column.
+    fn use_tool(&mut self) -> bool {
+        match self.tool {
+            Tool::Pour => {
+                let grain = Dot { x: self.cursor, y: POUR_ROW };
+                if self.sand.is_dot_on(&grain) { return true }
+                self.sand.add(&grain);
+            },
+            Tool::Erase => {
+                let top = (0..DotScreen::HEIGHT).rev().find(|&y| self.sand.is_on(self.cursor, y));
+                if let Some(y) = top { self.sand.remove(&Dot { x: self.cursor, y }) }
+            },
+            Tool::Shake => { self.sand.clear() },
+        }
+        false
+    }
+
+    /// Advance every grain of sand by a single frame.
+    ///
+    /// The rows are simulated from the bottom up, so that each grain moves at most once.
+    ///   A grain falls straight down if it can, otherwise it slides diagonally down, trying
+    ///   the tilted side first (or alternating sides each frame if untilted). A grain which
+    ///   can do neither slides sideways if the screen is tilted.
+    ///
+    /// # Arguments
+    /// * tilt - The direction in which the screen is tilted, if any.
+    fn simulate(&mut self, tilt: Option<Direction>) {
+        let sides: [isize; 2] = match tilt {
+            Some(Direction::Left) => { [-1, 1] },
+            Some(Direction::Right) => { [1, -1] },
+            _ if self.tick % 2 == 0 => { [-1, 1] },
+            _ => { [1, -1] },
+        };
+        let tilt_side = match tilt {
+            Some(Direction::Left) => { Some(-1) },
+            Some(Direction::Right) => { Some(1) },
+            _ => { None },
+        };
+
+        for y in 0..DotScreen::HEIGHT {
+            for i in 0..DotScreen::WIDTH {
+                // Grains are visited from the tilted side, so a grain slides at most once.
+                let x = if tilt_side == Some(1) { DotScreen::WIDTH - 1 - i } else { i };
+                let grain = Dot { x, y };
+                if self.sand.is_dot_off(&grain) { continue }
+
+                let mut moves = [None; 4];
+                if y > 0 {
+                    moves[0] = Some((x as isize, y - 1));
+                    moves[1] = Some((x as isize + sides[0], y - 1));
+                    moves[2] = Some((x as isize + sides[1], y - 1));
+                }
+                moves[3] = tilt_side.map(|side| (x as isize + side, y));
+
+                let destination = moves.iter()
+                    .filter_map(|&step| step)
+                    .filter(|&(x, _)| (x >= 0) & (x < DotScreen::WIDTH as isize))
+                    .map(|(x, y)| Dot { x: x as usize, y })
+                    .find(|dot| self.sand.is_dot_off(dot));
+                if let Some(destination) = destination {
+                    self.sand.remove(&grain);
+                    self.sand.add(&destination);
+                }
+            }
+        }
+    }
+
+    /// This method is called when the sand overflows the top of the screen.
+    ///
+    /// When the overflow is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn overflow(&mut self, components: &mut Components) {
+        utils::flash_screen(&mut components.display, &self.sand, 2);
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+impl GamePlay for SandMode {
+
+    /// Returns the length of a single frame of the simulation.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a frame, moving the cursor (tilting the
+    ///   screen), using the tool while held down, and switching tools on a press.
+    fn handle_input(&mut self, input: TickInput) {
+        self.tilt = match input.direction {
+            Some(Direction::Left) => {
+                self.cursor = self.cursor.saturating_sub(1);
+                Some(Direction::Left)
+            },
+            Some(Direction::Right) => {
+                self.cursor = (self.cursor + 1).min(DotScreen::WIDTH - 1);
+                Some(Direction::Right)
+            },
+            _ => { None },
+        };
+        self.using_tool = input.direction == Some(Direction::Down);
+        if input.pressed { self.tool = self.tool.next() }
+    }
+
+    /// Update the state of the toy.
+    ///
+    /// This is called for every frame. This simulates the sand, then uses the tool
+    ///   if it is held down.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused).
+    ///
+    /// # Returns
+    /// Whether the toy continues, i.e. false once the sand overflows.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        self.simulate(self.tilt);
+        !(self.using_tool && self.use_tool())
+    }
+
+    /// Draw the current state of the toy onto the screen.
+    ///
+    /// The cursor shows the current tool: it is inverted on alternating frames while
+    ///   pouring, on one frame in four while erasing, and the entire top row is inverted
+    ///   on alternating frames while shaking.
+    fn render(&mut self) {
+        self.screen = self.sand;
+        match self.tool {
+            Tool::Pour if self.tick % 2 == 0 => {
+                self.screen.toggle(&Dot { x: self.cursor, y: POUR_ROW });
+            },
+            Tool::Erase if self.tick % 4 == 0 => {
+                self.screen.toggle(&Dot { x: self.cursor, y: POUR_ROW });
+            },
+            Tool::Shake if self.tick % 2 == 0 => {
+                for x in 0..DotScreen::WIDTH {
+                    self.screen.toggle(&Dot { x, y: POUR_ROW });
+                }
+            },
+            _ => {}
+        }
+    }
+
+    /// Show the screen of the toy on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the toy, with the screen emptied of sand.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        *self = Self::new();
+    }
+}
@@ -0,0 +1,87 @@
+/// A game in progress, saved to the EEPROM so that it can be continued after a power cycle.
+///
+/// A single game is saved at a time, as a record directly after the statistics, holding:
+///   the index of the game on the selection screen, the identifier of the game, and the
+///   state of the game (serialized by the game, see `SaveState`). A saved game is removed
+///   (by erasing the magic byte of its record) once it is loaded, whether or not the
+///   player continues it.
+use crate::stats;
+use crate::peripherals::eeprom;
+use super::selection;
+
+/// The magic byte identifying the record of the saved game.
+const MAGIC: u8 = 0x53;
+/// The address of the EEPROM at which the saved game is stored.
+const ADDRESS: usize = stats::ADDRESS + stats::STORED_SIZE;
+
+
+/// The serialized state of a saved game.
+pub type SaveData = [u8; CAPACITY];
+
+/// The number of bytes available to the state of a saved game.
+pub const CAPACITY: usize = 24;
+
+
+/// The state of a game which can be saved, serialized into (and restored from) bytes.
+pub trait SaveState: Sized {
+
+    /// Serialize the state of the game.
+    ///
+    /// # Arguments
+    /// * data - The bytes to serialize into. Any bytes left unused should be left as they are.
+    fn save(&self, data: &mut SaveData);
+
+    /// Restore the state of a game, as serialized by `save`.
+    ///
+    /// # Returns
+    /// The game, or None if the bytes are not a valid state (e.g. the game has changed since).
+    fn restore(data: &SaveData) -> Option<Self>;
+}
+
+
+/// Save the state of the game currently being played, replacing any saved game.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+/// * game    - The game to save.
+pub fn save<S: SaveState>(game_id: u8, game: &S) {
+    let mut record = [0u8; CAPACITY + 2];
+    let (header, data) = record.split_at_mut(2);
+    header.copy_from_slice(&[selection::current_game() as u8, game_id]);
+    let mut state = [0u8; CAPACITY];
+    game.save(&mut state);
+    data.copy_from_slice(&state);
+    eeprom::write_record(ADDRESS, MAGIC, &record);
+}
+
+/// Load the saved state of the game, removing it from the EEPROM.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+///
+/// # Returns
+/// The saved game, or None if no state of this game is saved.
+pub fn load<S: SaveState>(game_id: u8) -> Option<S> {
+    let record = read()?;
+    if record[1] != game_id { return None }
+    clear();
+    let mut state = [0u8; CAPACITY];
+    state.copy_from_slice(&record[2..]);
+    S::restore(&state)
+}
+
+/// Returns the index on the selection screen of the saved game, if a game is saved.
+pub fn saved_index() -> Option<usize> {
+    read().map(|record| record[0] as usize)
+}
+
+/// Remove the saved game, if any.
+pub fn clear() {
+    eeprom::update(ADDRESS, !MAGIC);
+}
+
+/// Read the record of the saved game, if a game is saved.
+fn read() -> Option<[u8; CAPACITY + 2]> {
+    let mut record = [0u8; CAPACITY + 2];
+    if eeprom::read_record(ADDRESS, MAGIC, &mut record) { Some(record) } else { None }
+}
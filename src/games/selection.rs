@@ -1,17 +1,40 @@
 use crate::{
     common::Direction,
-    peripherals::{DotScreen, JoyStickSignal, InputSignal}
+    graphics::{self, SmallFont, Transition},
+    peripherals::{DotScreen, Fade, FadeKind, InputEvent, JoyStickSignal}
 };
+use super::{animations, replay, settings_menu, utils::Countdown, Difficulty, Game};
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
 
-const NUMBER_OF_GAMES: usize = 1;
+const NUMBER_OF_GAMES: usize = 24;
 
-type GameLoop = fn(crate::Components) -> !;
+/// The index of the game currently being played.
+static CURRENT_GAME: Mutex<Cell<usize>> = Mutex::new(Cell::new(0));
+
+/// The difficulty last chosen by the player, see `configure_game`.
+static CURRENT_DIFFICULTY: Mutex<Cell<Difficulty>> = Mutex::new(Cell::new(Difficulty::Normal));
+
+/// The index of the game requested by the quick-switch shortcut (or the console), to be
+///   switched to once the game being played returns.
+static REQUESTED_GAME: Mutex<Cell<Option<usize>>> = Mutex::new(Cell::new(None));
+
+
+/// A switch directly to another game, skipping the selection screen, as requested by the
+///   quick-switch shortcut (or the console). This is returned by `Game::run`.
+#[derive(Copy, Clone)]
+pub struct GameSwitch {
+    /// The index of the game to switch to, on the selection screen.
+    pub index: usize,
+    /// The difficulty to play the game at (if it offers a choice), i.e. that last chosen.
+    pub difficulty: Difficulty,
+}
 
 
 /// Structure used to select the game to be played.
 pub struct SelectionScreen {
-    /// This is an array of (&TitleScreen, GameLoop) tuples.
-    games: [(&'static DotScreen, GameLoop); NUMBER_OF_GAMES],
+    /// The registry of the games, in the order they are selected.
+    games: [&'static dyn Game; NUMBER_OF_GAMES],
     /// The current index of the selection (indexing over the games array).
     index: usize,
 }
@@ -20,16 +43,42 @@ pub struct SelectionScreen {
 impl SelectionScreen {
 
     /// Creates a new SelectionScreen object.
+    ///
+    /// If a game in progress was saved, the selection begins on that game, so it can be continued.
     pub fn new() -> Self {
-        let games: [(&'static DotScreen, GameLoop); NUMBER_OF_GAMES] = [
-            (&super::snake::TITLE_SCREEN, super::snake::snake_game_loop),
+        let games: [&'static dyn Game; NUMBER_OF_GAMES] = [
+            super::snake::GAME,
+            super::missile_command::GAME,
+            super::bomberman::GAME,
+            super::maze::GAME,
+            super::memory::GAME,
+            super::cave::GAME,
+            super::gallery::GAME,
+            super::climber::GAME,
+            super::asteroids::GAME,
+            super::lander::GAME,
+            super::tug_of_war::GAME,
+            super::falldown::GAME,
+            super::tunnel::GAME,
+            super::qix::GAME,
+            super::mastermind::GAME,
+            super::runner::GAME,
+            super::sand::GAME,
+            super::tanks::GAME,
+            super::math::GAME,
+            super::hockey::GAME,
+            super::boulder::GAME,
+            super::gauntlet::GAME,
+            super::theremin::GAME,
+            super::clock::GAME,
         ];
-        Self { games, index: 0 } 
+        let index = super::saved_game::saved_index().filter(|&index| index < NUMBER_OF_GAMES).unwrap_or(0);
+        Self { games, index }
     }
 
-    /// Gets the title screen DotScreen object for the current game.
-    fn current_title_screen(&mut self) -> &DotScreen {
-        self.games[self.index].0
+    /// Gets the title screen DotScreen object for the current game (read from program memory).
+    fn current_title_screen(&mut self) -> DotScreen {
+        self.games[self.index].assets().title()
     }
 
     /// Move the selection screen to the next game.
@@ -39,48 +88,271 @@ impl SelectionScreen {
 
     /// Move the selection screen to the previous game.
     fn prev(&mut self) {
-        self.index = (self.index - 1) % NUMBER_OF_GAMES;
+        self.index = (self.index + NUMBER_OF_GAMES - 1) % NUMBER_OF_GAMES;
     }
 
     /// Select the previous game. 
     /// 
-    /// This consumes the SelectionScreen object, returning the GameLoop that 
-    ///   runs the selected game.
-    fn select(self) -> GameLoop {
-        self.games[self.index].1
+    /// This returns the selected game, to be run. An armed replay begins with it (see `replay`).
+    fn select(&self, components: &mut crate::Components) -> &'static dyn Game {
+        interrupt::free(|cs| CURRENT_GAME.borrow(cs).set(self.index));
+        replay::begin(&mut components.analog, self.index);
+        self.games[self.index]
+    }
+
+    /// Switch directly to a game, skipping the selection screen (see `GameSwitch`).
+    ///
+    /// This returns the game, to be run, as though it were selected.
+    pub fn switch_to(&mut self, components: &mut crate::Components, index: usize) -> &'static dyn Game {
+        // The game last played is over, and so is its replay.
+        replay::end(&mut components.analog);
+        self.index = index % NUMBER_OF_GAMES;
+        self.select(components)
     }
 
     /// Run the Selection Screen.
     /// 
-    /// This consumes the SelectionScreen object, returning the selected GameLoop
-    ///   that runs the selected game.
+    /// This returns the selected game, to be run. Once the game returns, this may be
+    ///   run again, beginning on the title screen of the game last played.
     /// This will endlessly loop, reacting to inputs from the JoyStick peripheral.
-    pub fn run(mut self, components: &mut crate::Components) -> GameLoop {
-        const NEW_SELECTION_DELAY: u16 = 250;
-        components.display.show(self.current_title_screen());
+    /// If no input is received for a while, the attract loop is run,
+    ///   and after a longer while without input, the microcontroller goes to sleep.
+    pub fn run(&mut self, components: &mut crate::Components) -> &'static dyn Game {
+        const NEW_SELECTION_DELAY: u16 = 130;
+        const TRANSITION_FRAME_MS: u16 = 15;
+        const SELECT_TRANSITION_FRAME_MS: u16 = 40;
+        const IDLE_POLL_MS: usize = 100;
+        const ATTRACT_IDLE_POLLS: usize = 300;
+
+        // The game last played is over, and so is its replay.
+        replay::end(&mut components.analog);
+
+        components.display.show(&self.current_title_screen());
+        let mut idle = Countdown::new(ATTRACT_IDLE_POLLS);
         return loop {
-            match components.analog.poll_joystick_until_any() {
-                InputSignal::JoyStick(signal) => {
-
-                    // If the JoyStick button is pressed, return the GameLoop that runs the selected game.
-                    if let JoyStickSignal { button: true, .. } = signal { break self.select() }
-
-                    // If a horizontal direction is registered, change the current selection.
-                    match signal.to_single_direction() {
-                        Some(Direction::Left) => { 
-                            self.prev();
-                            components.display.show(self.current_title_screen());
-                            arduino_uno::delay_ms(NEW_SELECTION_DELAY);
-                        }
-                        Some(Direction::Right) => {
-                            self.next();
-                            components.display.show(self.current_title_screen());
-                            arduino_uno::delay_ms(NEW_SELECTION_DELAY);
-                        }
-                        _ => {}
+            let signal = match components.analog.poll_joystick(IDLE_POLL_MS).front() {
+                Some(signal) => signal.to_joystick(),
+                None => {
+                    components.sleep_if_idle();
+                    components.adjust_brightness();
+                    components.monitor_battery();
+                    components.poll_console();
+                    // If the console requested a game, run it immediately.
+                    if let Some(index) = take_requested_game() {
+                        self.index = index;
+                        break self.select(components)
                     }
+                    if idle.tick() {
+                        idle = Countdown::new(ATTRACT_IDLE_POLLS);
+                        self.attract(components);
+                        components.display.show(&self.current_title_screen());
+                        // Don't let the input which ended the attract loop select a game.
+                        while !components.analog.poll_joystick(IDLE_POLL_MS).is_empty() {}
+                    }
+                    continue
+                }
+            };
+            idle = Countdown::new(ATTRACT_IDLE_POLLS);
+
+            // If the JoyStick button is pressed, return the selected game.
+            // A long press instead opens the settings menu.
+            if let JoyStickSignal { button: true, .. } = signal {
+                if !is_long_press(components) {
+                    // Close the curtain on the title screen before the game begins.
+                    let title = self.current_title_screen();
+                    Transition::Curtain.play(
+                        &mut components.display, &title, &DotScreen::new_empty(), SELECT_TRANSITION_FRAME_MS
+                    );
+                    break self.select(components)
+                }
+                settings_menu::run(components);
+                components.display.show(&self.current_title_screen());
+                continue
+            }
+
+            // Down shows the lifetime statistics of the current game.
+            if signal.to_single_direction() == Some(Direction::Down) {
+                self.show_stats(components);
+                components.display.show(&self.current_title_screen());
+                continue
+            }
+
+            // If a horizontal direction is registered, change the current selection.
+            // The new title screen wipes in from the side the selection moved toward.
+            let previous_title = self.current_title_screen();
+            let transition = match signal.to_single_direction() {
+                Some(Direction::Left) => { 
+                    self.prev();
+                    Transition::WipeRight
+                }
+                Some(Direction::Right) => {
+                    self.next();
+                    Transition::WipeLeft
                 }
+                _ => { continue }
+            };
+            components.play_effect(self.games[self.index].on_menu_move());
+            transition.play(&mut components.display, &previous_title, &self.current_title_screen(), TRANSITION_FRAME_MS);
+            arduino_uno::delay_ms(NEW_SELECTION_DELAY);
+        }
+    }
+
+    /// Show the lifetime statistics of the current game (see `crate::stats`), scrolling
+    ///   its number of plays, total score, and best score (and its date, if known) across the display.
+    ///
+    /// Games which report no scores have no statistics. Any input ends the marquee.
+    fn show_stats(&self, components: &mut crate::Components) {
+        const POLL_MS: usize = 50;
+        const SCROLL_COLUMN_MS: usize = 70;
+        // Wait for the JoyStick to be released, so the input which opened the
+        //   statistics doesn't end the marquee.
+        while !components.analog.poll_joystick(POLL_MS).is_empty() {}
+
+        let game_id = match self.games[self.index].id() {
+            Some(game_id) => game_id,
+            None => {
+                graphics::scroll(components, &SmallFont, "NO STATS", SCROLL_COLUMN_MS);
+                return
+            }
+        };
+        let stats = crate::stats::of(game_id);
+        let best = crate::high_scores::best(game_id).unwrap_or(0);
+
+        // Compose the line of text, e.g. "PLAYS 12  TOTAL 345  BEST 67 ON 2026-10-16".
+        let mut text = [0u8; 64];
+        let mut length = 0;
+        let entries = [("PLAYS ", stats.plays as u32), ("  TOTAL ", stats.total_score), ("  BEST ", best as u32)];
+        for &(label, value) in entries.iter() {
+            let mut buffer = graphics::NumberBuffer::default();
+            for part in [label, graphics::format_number(value, &mut buffer)].iter() {
+                text[length..(length + part.len())].copy_from_slice(part.as_bytes());
+                length += part.len();
             }
         }
+        if let Some(date) = crate::high_scores::best_date(game_id) {
+            let year = 2000 + date.year as u16;
+            let digits = [
+                b' ', b'O', b'N', b' ',
+                b'0' + (year / 1000) as u8, b'0' + (year / 100 % 10) as u8,
+                b'0' + (year / 10 % 10) as u8, b'0' + (year % 10) as u8, b'-',
+                b'0' + date.month / 10, b'0' + date.month % 10, b'-',
+                b'0' + date.day / 10, b'0' + date.day % 10,
+            ];
+            text[length..(length + digits.len())].copy_from_slice(&digits);
+            length += digits.len();
+        }
+        graphics::scroll(components, &SmallFont, core::str::from_utf8(&text[..length]).unwrap_or(""), SCROLL_COLUMN_MS);
     }
-}
\ No newline at end of file
+
+    /// Run the "insert coin" style attract loop.
+    /// 
+    /// This cycles through the title screen of every game, fading each one in and then pulsing
+    ///   the brightness of the display like an arcade cabinet, followed by a demo of the game if it has one.
+    ///   Once every game has been shown, the animation reel is played.
+    ///   Any input (or going to sleep) ends the attract loop, leaving the selection on the game last shown.
+    fn attract(&mut self, components: &mut crate::Components) {
+        const FADE_STEP_MS: usize = 30;
+        const FADE_IN_MS: u16 = 480;
+        const PULSE_MS: u16 = 720;
+        const FADES: [(FadeKind, u16); 3] = [(FadeKind::In, FADE_IN_MS), (FadeKind::Pulse, PULSE_MS), (FadeKind::Pulse, PULSE_MS)];
+        loop {
+            // Once every title has been shown, play the animation reel.
+            if self.index == 0 {
+                for animation in animations::REEL.iter() {
+                    if animation.play(components) { return }
+                }
+            }
+            let title = self.current_title_screen();
+            for (index, &(kind, duration_ms)) in FADES.iter().enumerate() {
+                let mut fade = Fade::start(&mut components.display, kind, duration_ms);
+                // The title is shown once the display is dimmed, for it to fade in.
+                if index == 0 { components.display.show(&title) }
+                while !fade.update(&mut components.display) {
+                    if !components.analog.poll_joystick(FADE_STEP_MS).is_empty() || components.sleep_if_idle() {
+                        fade.finish(&mut components.display);
+                        return
+                    }
+                }
+            }
+            if let Some(demo) = self.games[self.index].assets().demo() {
+                if demo(components) { return }
+            }
+            self.next();
+        }
+    }
+}
+
+
+/// Returns whether the JoyStick button, which has just been pressed, is held for a long press.
+///
+/// This returns as soon as the button is released, or once it has been held long enough
+///   (see `InputEvent::Held`).
+fn is_long_press(components: &mut crate::Components) -> bool {
+    const POLL_MS: usize = 50;
+    loop {
+        components.analog.poll_joystick(POLL_MS);
+        let events = components.analog.events();
+        if events.contains(InputEvent::Held) { return true }
+        if !events.button() { return false }
+    }
+}
+
+
+/// Returns the index of the game currently being played (or last played).
+pub fn current_game() -> usize {
+    interrupt::free(|cs| CURRENT_GAME.borrow(cs).get())
+}
+
+/// Configure a game with the difficulty chosen by the player, before it runs (see `Game::configure`),
+///   remembering the difficulty for any game switched to from it.
+pub fn configure_game(game: &dyn Game, difficulty: Difficulty) {
+    interrupt::free(|cs| CURRENT_DIFFICULTY.borrow(cs).set(difficulty));
+    game.configure(difficulty);
+}
+
+/// Request a switch directly to the previous or next game, skipping the selection screen.
+///
+/// The game being played returns (see `is_switch_requested`), then the switch is returned
+///   by `Game::run`.
+///
+/// # Arguments
+/// * direction - Left for the previous game, or Right for the next game.
+pub fn quick_switch(direction: Direction) {
+    let current = current_game();
+    let index = match direction {
+        Direction::Left => { (current + NUMBER_OF_GAMES - 1) % NUMBER_OF_GAMES },
+        _ => { (current + 1) % NUMBER_OF_GAMES },
+    };
+    interrupt::free(|cs| REQUESTED_GAME.borrow(cs).set(Some(index)));
+}
+
+/// Request a switch directly to a game, skipping the selection screen, as the quick-switch
+///   shortcut does.
+///
+/// # Arguments
+/// * index - The index of the game on the selection screen.
+///
+/// # Returns
+/// Whether there is a game at the index.
+pub fn switch_to_game(index: usize) -> bool {
+    if index >= NUMBER_OF_GAMES { return false }
+    interrupt::free(|cs| REQUESTED_GAME.borrow(cs).set(Some(index)));
+    true
+}
+
+/// Returns whether a switch to another game has been requested, in which case the game
+///   being played should return as soon as it can.
+pub fn is_switch_requested() -> bool {
+    interrupt::free(|cs| REQUESTED_GAME.borrow(cs).get().is_some())
+}
+
+/// Take the game requested by the quick-switch shortcut (or the console), if any.
+fn take_requested_game() -> Option<usize> {
+    interrupt::free(|cs| REQUESTED_GAME.borrow(cs).take())
+}
+
+/// Take the switch requested while a game was played, if any, to return from `Game::run`.
+pub(super) fn take_switch() -> Option<GameSwitch> {
+    let difficulty = interrupt::free(|cs| CURRENT_DIFFICULTY.borrow(cs).get());
+    take_requested_game().map(|index| GameSwitch { index, difficulty })
+}
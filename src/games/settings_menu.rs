@@ -0,0 +1,146 @@
+/// The settings menu, reached by a long press of the JoyStick button on the selection screen.
+///
+/// The menu offers the following items, each shown as an icon filling the screen:
+///   * Brightness - The brightness of the display, shown as a ramp. Above the highest level,
+///                  if there is a light sensor, the brightness follows the ambient light,
+///                  shown as a sun.
+///   * Difficulty - The default difficulty, shown as three bars (easy, normal, and hard).
+///   * Sound      - Whether sound is enabled, shown as a speaker (with or without sound waves).
+///
+/// Left and Right choose the item, and Up and Down adjust it. A button press saves the
+///   settings to the EEPROM and returns to the selection screen.
+use crate::{sound, Components, Direction, Settings};
+use crate::peripherals::{DotDisplay, DotScreen};
+use super::utils;
+
+
+/// The icon of the Sound item, when sound is enabled (a speaker with sound waves).
+static SOUND_ON_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00011000,
+            0b00011000,
+            0b00111100,
+            0b01111110,
+            0b00000000,
+            0b00111100,
+            0b01000010,
+            0b00011000,
+        ]
+    );
+
+/// The icon of the Sound item, when sound is disabled (a speaker with a cross).
+static SOUND_OFF_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b00011000,
+            0b00011000,
+            0b00111100,
+            0b01111110,
+            0b00000000,
+            0b00010100,
+            0b00001000,
+            0b00010100,
+        ]
+    );
+
+
+/// The icon of the automatic brightness setting (a sun).
+static AUTO_BRIGHTNESS_ICON: DotScreen =
+    DotScreen::new(
+        [
+            0b10010001,
+            0b01000010,
+            0b00011000,
+            0b10111101,
+            0b00111100,
+            0b00011000,
+            0b01000010,
+            0b10001001,
+        ]
+    );
+
+
+/// The items of the settings menu, in the order they are shown.
+#[derive(Copy, Clone, PartialEq)]
+enum Item {
+    Brightness,
+    Difficulty,
+    Sound,
+}
+
+impl Item {
+
+    /// Every item, in the order they are shown.
+    const ALL: [Item; 3] = [Item::Brightness, Item::Difficulty, Item::Sound];
+
+    /// Draw the icon of the item, depicting its current setting.
+    fn icon(&self, settings: &Settings) -> DotScreen {
+        let mut screen = DotScreen::new_empty();
+        match self {
+            Item::Brightness if settings.brightness == Settings::AUTO_BRIGHTNESS => { screen = AUTO_BRIGHTNESS_ICON },
+            Item::Brightness => { utils::draw_brightness(&mut screen, settings.brightness) },
+            Item::Difficulty => { utils::draw_difficulty(&mut screen, settings.difficulty as usize, true) },
+            Item::Sound => { screen = if settings.sound { SOUND_ON_ICON } else { SOUND_OFF_ICON } },
+        }
+        screen
+    }
+
+    /// Adjust the setting of the item by a single step, up or down.
+    ///
+    /// # Arguments
+    /// * settings   - The settings to adjust.
+    /// * up         - Whether to step up, rather than down.
+    /// * auto_light - Whether the brightness may follow the ambient light (i.e. there is a light sensor).
+    fn adjust(&self, settings: &mut Settings, up: bool, auto_light: bool) {
+        match self {
+            Item::Brightness if up => {
+                let highest = if auto_light { Settings::AUTO_BRIGHTNESS } else { DotDisplay::BRIGHTNESS_LEVELS - 1 };
+                settings.brightness = (settings.brightness + 1).min(highest);
+            },
+            Item::Brightness => { settings.brightness = settings.brightness.saturating_sub(1) },
+            Item::Difficulty if up => {
+                settings.difficulty = (settings.difficulty + 1).min(Settings::DIFFICULTY_LEVELS - 1);
+            },
+            Item::Difficulty => { settings.difficulty = settings.difficulty.saturating_sub(1) },
+            Item::Sound => { settings.sound = up },
+        }
+    }
+}
+
+
+/// Run the settings menu, until a button press saves the settings.
+///
+/// Changes to the settings are applied immediately (e.g. the brightness changes
+///   while it is adjusted). This only returns once the button has been released,
+///   so the press which saves the settings is not seen by the selection screen.
+///
+/// # Arguments
+/// * components - The peripheral components, which hold the settings.
+pub fn run(components: &mut Components) {
+    const TICK_INTERVAL_MS: usize = 100;
+    const RELEASE_POLL_MS: usize = 50;
+    let mut index = 0;
+    loop {
+        let item = Item::ALL[index];
+        components.display.show(&item.icon(&components.settings));
+
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        if input.pressed | super::selection::is_switch_requested() { break }
+
+        // Each push of the JoyStick moves a single step, however long it is held.
+        match input.pushed {
+            Some(Direction::Left) => { index = (index + Item::ALL.len() - 1) % Item::ALL.len() },
+            Some(Direction::Right) => { index = (index + 1) % Item::ALL.len() },
+            Some(direction) => {
+                let auto_light = components.light_sensor.is_some();
+                item.adjust(&mut components.settings, direction == Direction::Up, auto_light);
+                components.apply_settings();
+            },
+            None => {}
+        }
+        if input.pushed.is_some() { components.play_effect(Some(&sound::BLIP)) }
+    }
+    components.settings.save();
+    while utils::poll_tick(components, RELEASE_POLL_MS).button {}
+}
@@ -1,8 +1,13 @@
 /// Implementation of the Snake game for an 8x8 LED Dot Screen.
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
 use crate::{Components, Direction};
-use crate::peripherals::{Dot, DotScreen, InputSignal, JoyStickSignal};
+use crate::peripherals::{Color, Display, Dot, DotScreen, Palette, Tint, TintedScreen};
+use super::{animations, game::{self, GamePlay}, utils::{self, TickInput}, Difficulty};
 
 // Constants for the Snake game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'S';
 //   The x-coordinate of the egg starting location.
 const EGG_START_X: usize = 1;
 //   The y-coordinate of the egg starting location.
@@ -11,13 +16,33 @@ const EGG_START_Y: usize = DotScreen::WIDTH - 2;
 const SNAKE_START_Y: usize = DotScreen::WIDTH / 2;
 //   The stating length of the snake.
 const START_LENGTH: usize = (DotScreen::WIDTH / 2) - 1;
-//   The initial polling interval for the SnakeGame.
-const INITIAL_POLL_INTERVAL: usize = 500;
-//   The number of point when the player has won the game (the screen is full).
+//   The tunings of each difficulty level (easy, normal, and hard), chosen before each game.
+const TUNINGS: [Tuning; 3] = [
+    Tuning { initial_interval_ms: 600, speed_up_divisor: 100, eggs_per_growth: 2 },
+    Tuning { initial_interval_ms: 500, speed_up_divisor: 50, eggs_per_growth: 1 },
+    Tuning { initial_interval_ms: 350, speed_up_divisor: 30, eggs_per_growth: 1 },
+];
+//   The amount of growth when the player has won the game (the screen is full).
 const VICTORY: usize = DotScreen::TOTAL_DOTS - START_LENGTH;
+//   The chance (as a percentage) that a poison egg is spawned alongside each new egg.
+const POISON_PERCENT: u32 = 20;
+//   The number of segments the snake loses when it eats a poison egg.
+//   If this would leave the snake shorter than its starting length, the game is over.
+const POISON_SHRINK: usize = 2;
+//   The chance (as a percentage) that a golden egg is spawned alongside each new egg.
+const GOLDEN_PERCENT: u32 = 15;
+//   The number of game ticks a golden egg lasts before disappearing.
+const GOLDEN_TICKS: usize = 16;
+//   The bonus points awarded for eating a golden egg (on top of the growth of the Snake).
+const GOLDEN_BONUS: usize = 3;
+//   The colors of the game, on a color display: a green snake and red eggs.
+const PALETTE: Palette = Palette::new(
+    Color::new(0, 0, 0), Color::new(255, 0, 0), Color::new(160, 0, 255), Color::new(0, 255, 0), Color::new(255, 255, 255),
+);
 
 
 /// The Title Screen for the Snake Game ("S").
+#[link_section = ".progmem.data"]
 pub static TITLE_SCREEN: DotScreen = 
     DotScreen::new(
         [
@@ -32,18 +57,104 @@ pub static TITLE_SCREEN: DotScreen =
         ]
     );
 
+game_assets! {
+    title: TITLE_SCREEN,
+    demo: snake_demo,
+}
+game!(snake_game_loop, id: GAME_ID, configure: configure);
+
+/// The difficulty chosen for the next game, see `configure`.
+static CHOSEN_DIFFICULTY: Mutex<Cell<Difficulty>> = Mutex::new(Cell::new(Difficulty::Normal));
+
+
+/// Configure the next Snake game with the difficulty chosen by the player.
+fn configure(difficulty: Difficulty) {
+    interrupt::free(|cs| CHOSEN_DIFFICULTY.borrow(cs).set(difficulty));
+}
+
 
 /// The game loop which runs the Snake game.
 /// 
 /// # Arguments
-/// components - Consumes the Components object.
-pub fn snake_game_loop(mut components: Components) -> ! {
+/// components - The peripheral components.
+pub fn snake_game_loop(components: &mut Components) {
     let mut game = SnakeGame::new();
-    loop {
-        game.play(&mut components);
-        game.game_over(&mut components);
-        game.reset();
+    game.set_difficulty(interrupt::free(|cs| CHOSEN_DIFFICULTY.borrow(cs).get()));
+    // Quitting from the overlay menu skips the game over screen.
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// Play a short demo of the Snake game, in which the Snake is driven by the autopilot.
+///
+/// This is shown in the attract loop of the selection screen.
+///
+/// # Arguments
+/// components - The peripheral components.
+///
+/// # Returns
+/// Whether the demo was interrupted by input.
+pub fn snake_demo(components: &mut Components) -> bool {
+    const DEMO_TICKS: usize = 60;
+    const DEMO_TICK_MS: usize = 150;
+    let mut game = SnakeGame::new();
+    for tick in 0..DEMO_TICKS {
+        let direction = game.choose_autopilot_direction();
+        game.snake.set_direction(direction);
+        if !game.update(&mut components.analog) { break }
+
+        let mut screen = game.screen;
+        animations::watermark(&mut screen, tick);
+        components.display.show(&screen);
+        if !components.analog.poll_joystick(DEMO_TICK_MS).is_empty() { return true }
     }
+    false
+}
+
+
+/// Race the autopilot against the player's score.
+///
+/// The autopilot plays a full game of Snake (watermarked, so it is not mistaken for the
+///   player's game), after which its score is shown as a tally. Then the winner's half of
+///   the screen flashes: the left half if the player matched or beat the autopilot, and
+///   the right half otherwise.
+///
+/// The autopilot's game is cut short after `RACE_TICKS`, scoring what it has reached by then,
+///   and a press of the button ends the race at once, skipping the result.
+///
+/// # Arguments
+/// components   - The peripheral components.
+/// player_score - The score of the player's game.
+/// difficulty   - The difficulty the player's game was played at.
+fn race_the_autopilot(components: &mut Components, player_score: usize, difficulty: Difficulty) {
+    const SPECTATOR_TICK_MS: usize = 100;
+    const RACE_TICKS: usize = 1200;
+    let mut game = SnakeGame::new();
+    game.set_difficulty(difficulty);
+    // Don't let the press which started the race end it.
+    while utils::poll_tick(components, SPECTATOR_TICK_MS).button {}
+    for tick in 0..RACE_TICKS {
+        let direction = game.choose_autopilot_direction();
+        game.snake.set_direction(direction);
+        if !game.update(&mut components.analog) { break }
+
+        let mut screen = game.screen;
+        animations::watermark(&mut screen, tick);
+        components.display.show(&screen);
+        let input = utils::poll_tick(components, SPECTATOR_TICK_MS);
+        if input.pressed | super::selection::is_switch_requested() { return }
+    }
+
+    let autopilot_score = game.get_score();
+    utils::flash_screen(&mut components.display, &game.screen, 2);
+    utils::show_tally(&mut components.display, autopilot_score);
+    arduino_uno::delay_ms(1000);
+    crate::peripherals::watchdog::feed();
+
+    let mut winner = DotScreen::new_empty();
+    let left = if player_score >= autopilot_score { 0 } else { DotScreen::WIDTH / 2 };
+    winner.draw_rect(&Dot { x: left, y: 0 }, DotScreen::WIDTH / 2, DotScreen::HEIGHT, true);
+    utils::flash_screen(&mut components.display, &winner, 3);
 }
 
 
@@ -185,10 +296,40 @@ impl Snake {
     }
 }
 
+/// The settings which determine how difficult a level of the Snake game is.
+#[derive(Copy, Clone)]
+struct Tuning {
+    /// The initial time between game ticks.
+    initial_interval_ms: usize,
+    /// Each time an egg is eaten, the time between game ticks is shortened
+    ///   by this fraction (one over the divisor) of itself.
+    speed_up_divisor: usize,
+    /// The number of eggs the Snake must eat to grow by a single segment.
+    eggs_per_growth: usize,
+}
+
+
+/// A golden egg, which grants bonus points but only lasts for a short while.
+#[derive(Copy, Clone)]
+struct GoldenEgg {
+    /// The position of the golden egg.
+    position: Dot,
+    /// The game ticks remaining until the golden egg disappears.
+    lifetime: utils::Countdown,
+}
+
+
 /// The SnakeGame object.
 struct SnakeGame {
     /// The Egg that the Snake is trying to eat.
     egg: Dot,
+    /// The poison egg that the Snake should avoid, if there is one.
+    /// This lasts until the next Egg is eaten.
+    poison: Option<Dot>,
+    /// The golden egg that the Snake is racing to eat, if there is one.
+    golden: Option<GoldenEgg>,
+    /// The bonus points earned by eating golden eggs.
+    bonus: usize,
     /// The character that the player controls.
     snake: Snake,
     /// The screen depicting the current state of the game.
@@ -196,6 +337,12 @@ struct SnakeGame {
     /// The interval to poll for user input.
     /// This can be interpreted as the time between game ticks.
     polling_interval_ms: usize,
+    /// The difficulty level of the game.
+    difficulty: Difficulty,
+    /// The settings which determine how difficult the game is, those of its difficulty level.
+    tuning: Tuning,
+    /// The number of eggs eaten.
+    eggs_eaten: usize,
 }
 
 impl SnakeGame {
@@ -205,80 +352,22 @@ impl SnakeGame {
         let egg = Dot { x: EGG_START_X, y: EGG_START_Y};
         let snake = Snake::new();
         let screen = DotScreen::new_empty();
-        let mut game = Self { egg, snake, screen, polling_interval_ms: INITIAL_POLL_INTERVAL };
+        let mut game = Self {
+            egg,
+            poison: None,
+            golden: None,
+            bonus: 0,
+            snake,
+            screen,
+            polling_interval_ms: TUNINGS[Difficulty::Normal as usize].initial_interval_ms,
+            difficulty: Difficulty::Normal,
+            tuning: TUNINGS[Difficulty::Normal as usize],
+            eggs_eaten: 0,
+        };
         game.reset();
         return game
     }
 
-    /// This method is called to begin the game-play.
-    /// 
-    /// This constructs its own game loop. Once the game-play ends, this returns.
-    /// 
-    /// # Args
-    /// * components - The peripheral components for the game display.
-    fn play(&mut self, components: &mut Components) {
-        loop {
-            // Improves the players comprehension of the game.
-            self.twinkle_egg(&mut components.display);
-
-            // Gather user input, for the amount of milliseconds stored in the 
-            //   `self.polling_interval_ms` attribute.
-            // This interval gets shorter and shorter as more eggs are eaten,
-            //   increasing the difficulty of the game.
-            let signal = 
-                components.analog.poll_joystick(self.polling_interval_ms).back();
-            if let Some(InputSignal::JoyStick(signal)) = signal {
-                if let Some(direction) = signal.to_single_direction() {
-                    self.snake.set_direction(direction);
-                };
-            };
-
-            // Update the game state. If unsuccessful, break out the game loop.
-            let update_successful = self.update(&mut components.analog);
-            if !update_successful { break }
-
-            // Display the game state to the LED Dot Display.
-            components.display.show(&self.screen);
-        }
-    }
-
-    /// Update the game state.
-    /// 
-    /// This is called for every game tick. This function will move the Snake
-    ///   in the direction its Head is pointing, and then resolves the games state.
-    /// 
-    /// # Arguments
-    /// * rng - The Random Number Generator.
-    /// 
-    /// # Returns 
-    /// Whether the game state was successfully updated.
-    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
-        match self.snake.slither(&self.egg) {
-            SlitherResult::Moved(dropped_segment) => {
-                self.screen.remove(&dropped_segment.position);
-                self.screen.add(&self.snake.head.position);
-            },
-            SlitherResult::EggEaten => {
-                if self.get_score() == VICTORY { return false }
-                // Place a new egg in an open dot.
-                let index = {
-                    let modulus = DotScreen::TOTAL_DOTS - self.snake.get_length();
-                    (rng.next_u32() as usize) % modulus
-                };
-                self.egg = self.screen.iter_off().nth(index).unwrap();
-                self.screen.add(&self.egg);
-
-                // Decrease the time between game ticks.
-                self.increase_speed();
-            },
-            SlitherResult::Collision => {
-                // If a collision occurred, then the game did not successfully update.
-                return false
-            }
-        }
-        return true
-    }
-
     /// This method is called when the game is over.
     /// 
     /// When the game over state is complete, this method returns.
@@ -288,41 +377,22 @@ impl SnakeGame {
     fn game_over(&self, components: &mut Components) {
         // Flash between the last game state screen and an empty screen,
         //   to indicate that the player has lost the game.
-        let mut game_over_screen = DotScreen::new_empty();
-        components.display.show(&game_over_screen);
-        for _ in 0..2 {
-            arduino_uno::delay_ms(400);
-            components.display.show(&self.screen);
-            arduino_uno::delay_ms(400);
-            components.display.show(&game_over_screen);
-        }
+        utils::flash_screen(&mut components.display, &self.screen, 2);
 
         let score = self.get_score();
+        components.report_score_at(GAME_ID, score, self.difficulty.level());
         if score == 0 {
             components.display.show(&self.screen);
         } else {
-            // Display the game score to the user by displaying a dot for each egg eaten,
-            //   one at a time, from left to right, top to bottom of the screen.
-            let tally = if score == VICTORY { DotScreen::TOTAL_DOTS } else { score };
-            let delay = 3000 / (tally as u16);
-            DotScreen::new_empty()
-                .iter()
-                .take(tally)
-                .for_each(|dot| {
-                    game_over_screen.add(&dot);
-                    components.display.show(&game_over_screen);
-                    arduino_uno::delay_ms(delay);
-                }
-            );
+            // Display the game score to the user by displaying a dot for each point.
+            let tally = if self.get_growth() == VICTORY { DotScreen::TOTAL_DOTS } else { score };
+            utils::show_tally(&mut components.display, tally);
         }
         
         // Loop waiting for a JoyStick button press to end the game over screen.
-        loop {
-            match components.analog.poll_joystick_until_any() {
-                InputSignal::JoyStick(signal) => {
-                    if let JoyStickSignal { button: true, .. } = signal { break }
-                }
-            }
+        // Holding Up while pressing the button races the autopilot against the score.
+        if utils::wait_for_button_press_holding(components) == Some(Direction::Up) {
+            race_the_autopilot(components, score, self.difficulty);
         }
     }
 
@@ -332,6 +402,9 @@ impl SnakeGame {
     fn reset(&mut self) {
         // Reset the Egg.
         self.egg = Dot { x: EGG_START_X, y: EGG_START_Y};
+        self.poison = None;
+        self.golden = None;
+        self.bonus = 0;
 
         // Reset the Snake.
         self.snake.init();
@@ -345,29 +418,327 @@ impl SnakeGame {
         }
 
         // Reset the polling interval.
-        self.polling_interval_ms = INITIAL_POLL_INTERVAL;
+        self.eggs_eaten = 0;
+        self.polling_interval_ms = self.tuning.initial_interval_ms;
     }
 
-    /// Returns the current score for the game.
-    fn get_score(&self) -> usize {
+    /// Shrink the Snake after it has eaten a poison egg, dropping the end of its Tail.
+    ///
+    /// # Returns
+    /// Whether the Snake survived. If the Snake would become shorter than its
+    ///   starting length, it does not survive.
+    fn shrink(&mut self) -> bool {
+        if self.snake.get_length() < START_LENGTH + POISON_SHRINK { return false }
+        for _ in 0..POISON_SHRINK {
+            if let Some(segment) = self.snake.tail.pop_back() {
+                self.screen.remove(&segment.position);
+            }
+        }
+        true
+    }
+
+    /// Returns how much the Snake has grown from its starting length.
+    fn get_growth(&self) -> usize {
         self.snake.get_length() - START_LENGTH
     }
 
+    /// Returns the current score for the game: the growth of the Snake,
+    ///   plus the bonus points from any golden eggs eaten.
+    fn get_score(&self) -> usize {
+        self.get_growth() + self.bonus
+    }
+
+    /// Place a Dot at a random open (unlit) position on the screen.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// The placed Dot, or None if the screen is full.
+    fn place_in_open_dot(&mut self, rng: &mut dyn rand_core::RngCore) -> Option<Dot> {
+        let open_dots = self.screen.iter_off().count();
+        if open_dots == 0 { return None }
+        let dot = self.screen.iter_off().nth(utils::random_below(rng, open_dots)).unwrap();
+        self.screen.add(&dot);
+        Some(dot)
+    }
+
+    /// Count down the lifetime of the golden egg, if there is one,
+    ///   removing it once it has run out.
+    fn count_down_golden_egg(&mut self) {
+        if let Some(golden) = self.golden.as_mut() {
+            if golden.lifetime.tick() {
+                self.screen.remove(&golden.position);
+                self.golden = None;
+            }
+        }
+    }
+
+    /// Change the difficulty level of the game, and reset the game.
+    fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.tuning = TUNINGS[difficulty as usize];
+        self.reset();
+    }
+
     /// Decrease the time between game ticks.
     fn increase_speed(&mut self) {
-        self.polling_interval_ms -= self.polling_interval_ms / 50;
+        self.polling_interval_ms -= self.polling_interval_ms / self.tuning.speed_up_divisor;
+    }
+
+    /// Returns whether the Snake would collide by moving its Head from the position
+    ///   in the direction (into the wall, its Tail, or a poison egg).
+    fn is_blocked(&self, position: Dot, direction: Direction) -> bool {
+        let next = Segment { direction, position }.next().position;
+        // The head does not move when heading into the wall.
+        (next == position) | (self.poison == Some(next))
+            | self.snake.tail.iter().any(|segment| segment.position == next)
+    }
+
+    /// Choose the direction for the Snake when it is driven by the autopilot.
+    /// 
+    /// This searches (breadth first) for the shortest path from the Head to the egg
+    ///   which avoids the Tail, treating the Tail as fixed in place. If there is no
+    ///   such path, this falls back to a greedy choice.
+    fn choose_autopilot_direction(&self) -> Direction {
+        const DIRECTIONS: [Direction; 4] = [Direction::Left, Direction::Right, Direction::Up, Direction::Down];
+        let index = |dot: &Dot| dot.y * DotScreen::WIDTH + dot.x;
+        let dot_at = |index: u8| Dot { x: index as usize % DotScreen::WIDTH, y: index as usize / DotScreen::WIDTH };
+
+        // The first step of the path along which each dot was reached, if it has been.
+        // The queue holds the indices of the dots (rather than the dots) to save memory.
+        let mut first_steps: [Option<Direction>; DotScreen::TOTAL_DOTS] = [None; DotScreen::TOTAL_DOTS];
+        let mut queue = [0u8; DotScreen::TOTAL_DOTS];
+        let (mut front, mut back) = (0, 0);
+
+        let head = self.snake.head.position;
+        for &direction in DIRECTIONS.iter() {
+            if (direction.opposite() == self.snake.head.direction) | self.is_blocked(head, direction) { continue }
+            let next = Segment { direction, position: head }.next().position;
+            first_steps[index(&next)] = Some(direction);
+            queue[back] = index(&next) as u8;
+            back += 1;
+        }
+        while front < back {
+            let dot = dot_at(queue[front]);
+            front += 1;
+            let first_step = first_steps[index(&dot)];
+            if dot == self.egg { return first_step.unwrap() }
+
+            for &direction in DIRECTIONS.iter() {
+                if self.is_blocked(dot, direction) { continue }
+                let next = Segment { direction, position: dot }.next().position;
+                if (next == head) | first_steps[index(&next)].is_some() { continue }
+                first_steps[index(&next)] = first_step;
+                queue[back] = index(&next) as u8;
+                back += 1;
+            }
+        }
+        self.choose_greedy_direction()
+    }
+
+    /// Choose a direction for the Snake greedily.
+    /// 
+    /// This heads toward the egg along the axis furthest from it, falling back
+    ///   to any direction which avoids an immediate collision.
+    fn choose_greedy_direction(&self) -> Direction {
+        let head = self.snake.head.position;
+        let horizontal = if self.egg.x < head.x { Direction::Left } else { Direction::Right };
+        let vertical = if self.egg.y < head.y { Direction::Down } else { Direction::Up };
+        let (first, second) =
+            if self.egg.x != head.x { (horizontal, vertical) } else { (vertical, horizontal) };
+        let candidates = [first, second, Direction::Left, Direction::Right, Direction::Up, Direction::Down];
+        for &direction in candidates.iter() {
+            if direction.opposite() == self.snake.head.direction { continue }
+            if !self.is_blocked(head, direction) { return direction }
+        }
+        self.snake.head.direction
     }
 
     /// Briefly toggle the Dot representing the egg off and on.
     /// 
     /// This should help the player understand which Dot is the egg.
-    fn twinkle_egg(&mut self, display: &mut crate::peripherals::DotDisplay) {
-        const INTERVAL_MS: u16 = 24;
+    /// The poison egg, if there is one, stays off for longer, so it can be told apart.
+    /// The golden egg, if there is one, blinks rapidly first.
+    ///
+    /// # Arguments
+    /// * display    - The display.
+    /// * elapsed_ms - The time elapsed since the twinkle began.
+    fn twinkle_egg(&self, display: &mut dyn Display, elapsed_ms: usize) {
+        const INTERVAL_MS: usize = 24;
+        const POISON_INTERVAL_MS: usize = 96;
+        const GOLDEN_BLINKS: usize = 3;
+
+        let mut screen = self.screen;
+        let mut twinkle_start_ms = 0;
+        if let Some(golden) = self.golden {
+            twinkle_start_ms = 2 * GOLDEN_BLINKS * INTERVAL_MS;
+            if (elapsed_ms < twinkle_start_ms) & ((elapsed_ms / INTERVAL_MS) % 2 == 0) {
+                screen.remove(&golden.position);
+            }
+        }
 
-        self.screen.remove(&self.egg);
-        display.show(&self.screen);
-        arduino_uno::delay_ms(INTERVAL_MS);
-        self.screen.add(&self.egg);
-        display.show(&self.screen);
+        if let Some(twinkle_ms) = elapsed_ms.checked_sub(twinkle_start_ms) {
+            if twinkle_ms < INTERVAL_MS { screen.remove(&self.egg) }
+            if let Some(poison) = self.poison {
+                if twinkle_ms < POISON_INTERVAL_MS { screen.remove(&poison) }
+            }
+        }
+        self.show_screen(display, &screen);
+    }
+
+    /// Show a screen of the game on the display, tinting the eggs apart from the Snake.
+    ///
+    /// # Arguments
+    /// * display - The display.
+    /// * screen - The screen of the game (e.g. with an egg twinkled off).
+    fn show_screen(&self, display: &mut dyn Display, screen: &DotScreen) {
+        let mut tinted = TintedScreen::new_empty();
+        *tinted.layer_mut(Tint::Actor) = *screen;
+        let golden = self.golden.map(|golden| golden.position);
+        for egg in core::iter::once(self.egg).chain(golden) {
+            if screen.is_dot_on(&egg) {
+                tinted.layer_mut(Tint::Actor).remove(&egg);
+                tinted.add(Tint::Pickup, &egg);
+            }
+        }
+        if let Some(poison) = self.poison.filter(|poison| screen.is_dot_on(poison)) {
+            tinted.layer_mut(Tint::Actor).remove(&poison);
+            tinted.add(Tint::Hazard, &poison);
+        }
+        display.show_tinted(&tinted, &PALETTE);
+    }
+}
+
+
+impl GamePlay for SnakeGame {
+
+    /// Returns the length of a game tick, which gets shorter and shorter as more eggs
+    ///   are eaten, increasing the difficulty of the game.
+    fn tick_interval_ms(&self) -> usize {
+        self.polling_interval_ms
+    }
+
+    /// Handle the JoyStick input received over a game tick, steering the Snake.
+    fn handle_input(&mut self, input: TickInput) {
+        if let Some(direction) = input.direction {
+            self.snake.set_direction(direction);
+        }
+    }
+
+    /// The egg twinkles throughout each game tick, which improves the players
+    ///   comprehension of the game.
+    fn is_animated(&self) -> bool {
+        true
+    }
+
+    /// Twinkle the egg (see `twinkle_egg`).
+    fn animate(&self, display: &mut dyn Display, elapsed_ms: usize) {
+        self.twinkle_egg(display, elapsed_ms);
+    }
+
+    /// A button press pauses the game.
+    fn pauses_on_press(&self) -> bool {
+        true
+    }
+
+    /// Update the game state.
+    /// 
+    /// This is called for every game tick. This function will move the Snake
+    ///   in the direction its Head is pointing, and then resolves the games state.
+    /// 
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    /// 
+    /// # Returns 
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.count_down_golden_egg();
+        match self.snake.slither(&self.egg) {
+            SlitherResult::Moved(dropped_segment) => {
+                self.screen.remove(&dropped_segment.position);
+                self.screen.add(&self.snake.head.position);
+                if self.poison == Some(self.snake.head.position) {
+                    self.poison = None;
+                    return self.shrink()
+                }
+                if let Some(golden) = self.golden {
+                    if golden.position == self.snake.head.position {
+                        // The golden egg grows the Snake by keeping the dropped segment.
+                        self.golden = None;
+                        self.bonus += GOLDEN_BONUS;
+                        self.snake.tail.push_back(dropped_segment);
+                        self.screen.add(&dropped_segment.position);
+                    }
+                }
+            },
+            SlitherResult::EggEaten => {
+                // The Snake only grows once it has eaten enough eggs.
+                self.eggs_eaten += 1;
+                if self.eggs_eaten % self.tuning.eggs_per_growth != 0 {
+                    if let Some(segment) = self.snake.tail.pop_back() {
+                        self.screen.remove(&segment.position);
+                    }
+                }
+                if self.get_growth() == VICTORY { return false }
+                // Any poison egg disappears along with the eaten egg.
+                if let Some(poison) = self.poison.take() { self.screen.remove(&poison) }
+
+                // Place a new egg in an open dot. If the golden egg occupies the
+                //   only open dot, it makes way for the new egg.
+                if self.screen.iter_off().next().is_none() {
+                    if let Some(golden) = self.golden.take() { self.screen.remove(&golden.position) }
+                }
+                self.egg = self.place_in_open_dot(rng).unwrap();
+
+                // Occasionally place a poison egg in another open dot.
+                if rng.next_u32() % 100 < POISON_PERCENT {
+                    self.poison = self.place_in_open_dot(rng);
+                }
+
+                // Occasionally place a golden egg in another open dot, if there isn't one.
+                if self.golden.is_none() && (rng.next_u32() % 100 < GOLDEN_PERCENT) {
+                    self.golden = self.place_in_open_dot(rng)
+                        .map(|position| GoldenEgg { position, lifetime: utils::Countdown::new(GOLDEN_TICKS) });
+                }
+
+                // Decrease the time between game ticks.
+                self.increase_speed();
+            },
+            SlitherResult::Collision => {
+                // If a collision occurred, then the game did not successfully update.
+                return false
+            }
+        }
+        return true
+    }
+
+
+    /// The screen is kept up to date as the game state is updated, so there is nothing to draw.
+    fn render(&mut self) {}
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        self.show_screen(display, &self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score (see `get_score`).
+    fn score(&self) -> usize {
+        self.get_score()
+    }
+
+    /// Returns whether the player has won the game, by filling the screen.
+    fn is_won(&self) -> bool {
+        self.get_growth() == VICTORY
     }
 }
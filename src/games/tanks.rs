@@ -0,0 +1,348 @@
+/// Implementation of the two-player Tank Battle game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::GamePlay, utils::{self, TickInput}};
+use super::versus::{self, Handicaps, RoundPlay, VersusMatch};
+
+// Constants for the Tank Battle game.
+//   The number of rounds in a match.
+const ROUNDS: usize = 3;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 50;
+//   The number of game ticks a tank waits after turning or driving before it may do so again.
+const TANK_MOVE_TICKS: usize = 4;
+//   The number of game ticks between each move of a shell.
+const SHELL_MOVE_TICKS: usize = 2;
+//   The number of shells each player may have in flight at once.
+const SHELLS_PER_PLAYER: usize = 2;
+//   The total number of shells which may be in flight at once.
+const MAX_SHELLS: usize = 2 * SHELLS_PER_PLAYER;
+//   The destructible walls in the middle of the battlefield, restored each round.
+const WALLS: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00000000,
+            0b01000010,
+            0b00111100,
+            0b00111100,
+            0b01000010,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+
+/// The Title Screen for the Tank Battle Game (a tank).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b00000110,
+            0b00001110,
+            0b00011110,
+            0b00101110,
+            0b01001110,
+            0b00000110,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(tanks_game_loop);
+
+
+/// The game loop which runs the Tank Battle game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn tanks_game_loop(components: &mut Components) {
+    let mut game = TanksGame::new();
+    game.handicaps = versus::choose_handicaps(components, Handicaps::default());
+    if VersusMatch::best_of(ROUNDS).play(&mut game, components).is_some() {
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+/// A tank, controlled by one of the players.
+#[derive(Copy, Clone)]
+struct Tank {
+    /// The position of the tank.
+    position: Dot,
+    /// The direction in which the tank's barrel points.
+    facing: Direction,
+    /// The number of game ticks before the tank may turn or drive again.
+    cooldown: usize,
+}
+
+impl Tank {
+
+    /// Returns the dot at the end of the tank's barrel,
+    ///   or None if the tank faces the edge of the screen.
+    fn barrel(&self) -> Option<Dot> {
+        let barrel = self.position.step(self.facing);
+        if barrel == self.position { None } else { Some(barrel) }
+    }
+}
+
+
+/// A shell fired by one of the tanks.
+#[derive(Copy, Clone)]
+struct Shell {
+    /// The position of the shell.
+    position: Dot,
+    /// The direction in which the shell travels.
+    direction: Direction,
+    /// The index of the player who fired the shell (0 or 1).
+    owner: usize,
+}
+
+
+/// The TanksGame object.
+///
+/// Each player drives a tank around the battlefield, the first player's starting on the
+///   left and the second player's on the right. Pushing the JoyStick turns the tank to
+///   face that direction, or drives it forward if it already does, and pressing the
+///   JoyStick button fires a shell from the barrel. Shells destroy the walls they hit,
+///   and the first player to hit the other's tank wins the round.
+struct TanksGame {
+    /// The tanks of each player (the first player first).
+    tanks: [Tank; 2],
+    /// The shells in flight.
+    shells: [Option<Shell>; MAX_SHELLS],
+    /// The remaining walls.
+    walls: DotScreen,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of game ticks elapsed.
+    tick: usize,
+    /// Whether the button of each player was held during the previous game tick.
+    buttons_held: [bool; 2],
+    /// The winner of the round (1 or 2), once a tank has been hit.
+    winner: Option<usize>,
+    /// The handicaps of the players, each level of which lets their tank turn and
+    ///   drive more quickly.
+    handicaps: Handicaps,
+}
+
+impl TanksGame {
+
+    /// Construct a new TanksGame object.
+    fn new() -> Self {
+        let tank = Tank { position: Dot { x: 0, y: 0 }, facing: Direction::Right, cooldown: 0 };
+        let mut game = Self {
+            tanks: [tank; 2],
+            shells: [None; MAX_SHELLS],
+            walls: WALLS,
+            screen: DotScreen::new_empty(),
+            tick: 0,
+            buttons_held: [true; 2],
+            winner: None,
+            handicaps: Handicaps::default(),
+        };
+        game.reset();
+        return game
+    }
+
+    /// Handle the input of the player received over a game tick, driving their tank and
+    ///   firing a shell on a fresh press of their button.
+    ///
+    /// # Arguments
+    /// * player - The index of the player (0 or 1).
+    /// * input  - The input of the player received over the game tick.
+    fn command(&mut self, player: usize, input: TickInput) {
+        if self.winner.is_some() { return }
+        if let Some(direction) = input.direction {
+            let move_ticks = TANK_MOVE_TICKS - self.handicaps.level(player + 1) as usize;
+            self.drive(player, direction, move_ticks);
+        }
+        if input.button && !self.buttons_held[player] {
+            self.winner = self.fire(player);
+        }
+        self.buttons_held[player] = input.button;
+    }
+
+    /// Turn the player's tank to face the direction, or drive it forward if it already does.
+    ///
+    /// A tank may not drive into a wall or the other tank.
+    ///
+    /// # Arguments
+    /// * player     - The index of the player (0 or 1).
+    /// * direction  - The direction in which the player pushed the JoyStick.
+    /// * move_ticks - The number of game ticks the tank waits before it may move again.
+    fn drive(&mut self, player: usize, direction: Direction, move_ticks: usize) {
+        let other = self.tanks[1 - player].position;
+        let tank = &mut self.tanks[player];
+        if tank.cooldown > 0 { return }
+        tank.cooldown = move_ticks;
+
+        if tank.facing != direction {
+            tank.facing = direction;
+        } else if let Some(next) = tank.barrel() {
+            if self.walls.is_dot_off(&next) && (next != other) { tank.position = next }
+        }
+    }
+
+    /// Fire a shell from the player's tank, if they do not already have too many in flight.
+    ///
+    /// # Arguments
+    /// * player - The index of the player (0 or 1).
+    ///
+    /// # Returns
+    /// The winner of the round (1 or 2), if the shell hit the other tank point-blank.
+    fn fire(&mut self, player: usize) -> Option<usize> {
+        let in_flight = self.shells.iter().flatten().filter(|shell| shell.owner == player).count();
+        if in_flight >= SHELLS_PER_PLAYER { return None }
+        let tank = self.tanks[player];
+        let position = tank.barrel()?;
+
+        let shell = Shell { position, direction: tank.facing, owner: player };
+        let slot = self.shells.iter_mut().find(|slot| slot.is_none())?;
+        *slot = Some(shell);
+        self.resolve_shells()
+    }
+
+    /// Advance the tanks and shells by a single game tick, cooling down the tanks and
+    ///   moving the shells.
+    ///
+    /// # Returns
+    /// The winner of the round (1 or 2), if a tank was hit.
+    fn advance(&mut self) -> Option<usize> {
+        self.tick += 1;
+        for tank in self.tanks.iter_mut() {
+            tank.cooldown = tank.cooldown.saturating_sub(1);
+        }
+        if self.tick % SHELL_MOVE_TICKS != 0 { return None }
+
+        for slot in self.shells.iter_mut() {
+            if let Some(shell) = slot {
+                let next = shell.position.step(shell.direction);
+                // A shell which cannot step further has left the screen.
+                if next == shell.position { *slot = None } else { shell.position = next }
+            }
+        }
+        self.resolve_shells()
+    }
+
+    /// Resolve the collisions of each shell in flight with the walls and the tanks.
+    ///
+    /// A shell which hits a wall destroys the wall dot, and is itself destroyed.
+    ///
+    /// # Returns
+    /// The winner of the round (1 or 2), if a tank was hit.
+    fn resolve_shells(&mut self) -> Option<usize> {
+        for slot in self.shells.iter_mut() {
+            if let Some(shell) = *slot {
+                if let Some(hit) = self.tanks.iter().position(|tank| tank.position == shell.position) {
+                    return Some(2 - hit)
+                }
+                if self.walls.is_dot_on(&shell.position) {
+                    self.walls.remove(&shell.position);
+                    *slot = None;
+                }
+            }
+        }
+        None
+    }
+
+    /// This method is called to reset the round to its initial state.
+    fn reset(&mut self) {
+        self.tanks[0] = Tank { position: Dot { x: 0, y: 3 }, facing: Direction::Right, cooldown: 0 };
+        self.tanks[1] = Tank {
+            position: Dot { x: DotScreen::WIDTH - 1, y: 4 },
+            facing: Direction::Left,
+            cooldown: 0,
+        };
+        self.shells = [None; MAX_SHELLS];
+        self.walls = WALLS;
+        self.tick = 0;
+        self.buttons_held = [true; 2];
+        self.winner = None;
+    }
+}
+
+
+impl GamePlay for TanksGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the input of the first player received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        self.command(0, input);
+    }
+
+    /// The game is played by two players.
+    fn is_versus(&self) -> bool {
+        true
+    }
+
+    /// Handle the input of the second player received over a game tick.
+    fn handle_input2(&mut self, input: TickInput) {
+        self.command(1, input);
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick, until a tank has been hit.
+    ///
+    /// # Arguments
+    /// * _rng - The Random Number Generator (unused).
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated. A hit ends the round instead
+    ///   (see `winner`).
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        if self.winner.is_none() { self.winner = self.advance() }
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    ///
+    /// The barrel of each tank is lit on alternating ticks, showing which way it faces.
+    fn render(&mut self) {
+        self.screen = self.walls;
+        for tank in self.tanks.iter() {
+            self.screen.add(&tank.position);
+            if let (Some(barrel), true) = (tank.barrel(), (self.tick / 2) % 2 == 0) {
+                self.screen.add(&barrel);
+            }
+        }
+        for shell in self.shells.iter().flatten() {
+            self.screen.add(&shell.position);
+        }
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the round, resetting the battlefield.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+}
+
+
+impl RoundPlay for TanksGame {
+
+    /// Reset the battlefield, to begin a new round.
+    fn begin(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the winner of the round (1 or 2), once a tank has been hit.
+    fn winner(&self) -> Option<usize> {
+        self.winner
+    }
+}
@@ -0,0 +1,127 @@
+/// Implementation of the Theremin toy for an 8x8 LED Dot Screen.
+///
+/// The toy plays a tone on the buzzer whose pitch follows the height of a hand held over
+///   the ultrasonic distance sensor (see `Ultrasonic`): the higher the hand, the higher
+///   the pitch. The display shows the height of the hand (and, while gliding between
+///   pitches, a trail fading away behind it).
+use crate::{graphics, Components};
+use crate::peripherals::{DistanceSignal, Dot, DotScreen, GreyScreen, Note};
+use super::{overlay::{self, OverlayChoice}, utils};
+
+// Constants for the Theremin toy.
+//   The length of a single tick, between readings of the distance sensor.
+const TICK_INTERVAL_MS: usize = 60;
+//   The time each tone is held for, outlasting the tick so that the tone is unbroken.
+const HOLD_MS: u16 = 2 * TICK_INTERVAL_MS as u16;
+//   The pitch of the tone with the hand at the nearest, and at the farthest distance read,
+//     while gliding between pitches.
+const LOW_HZ: u16 = 262;
+const HIGH_HZ: u16 = 1047;
+//   The notes of the scale (a major scale from C5), for the hand heights from the nearest up.
+const SCALE_HZ: [u16; DotScreen::HEIGHT] = [523, 587, 659, 698, 784, 880, 988, 1047];
+
+
+/// The Title Screen for the Theremin toy (a speaker sounding).
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00011000,
+            0b00111100,
+            0b01111110,
+            0b00000000,
+            0b01000010,
+            0b00111100,
+            0b10000001,
+            0b01111110,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN, help: "Raise a hand over the sensor. Press to glide." }
+game!(theremin_loop);
+
+
+/// The loop which runs the Theremin toy.
+///
+/// The tone steps through the notes of a scale, one for each row of the display, or glides
+///   between pitches, the button switching between the two. A long press of the button opens
+///   the overlay menu, and pushing the JoyStick in any direction quits. Without a distance
+///   sensor, this only says so.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn theremin_loop(components: &mut Components) {
+    const SCROLL_COLUMN_MS: usize = 70;
+    if !components.analog.has_ultrasonic() {
+        graphics::scroll(components, &graphics::SmallFont, "NO SENSOR", SCROLL_COLUMN_MS);
+        return
+    }
+
+    let mut glide = false;
+    let mut playing_hz = None;
+    let mut screen = GreyScreen::new_empty();
+    loop {
+        let input = utils::animate_tick(components, TICK_INTERVAL_MS, |display, _| display.show_grey(&screen));
+        if input.pushed.is_some() { break }
+        if input.pressed { glide = !glide }
+        if input.held {
+            // The press which began the long press switched the tone, so switch it back.
+            glide = !glide;
+            components.buzzer.stop();
+            playing_hz = None;
+            match overlay::open(components, &screen.flatten()) {
+                OverlayChoice::Resume => {},
+                OverlayChoice::Restart => {
+                    glide = false;
+                    screen.clear();
+                },
+                OverlayChoice::Quit | OverlayChoice::SaveAndQuit => { break },
+            }
+        }
+        if super::selection::is_switch_requested() { break }
+
+        let position = components.analog.measure_distance()
+            .map(|millimeters| DistanceSignal { millimeters }.position());
+        let frequency_hz = position.map(|position| {
+            if glide {
+                LOW_HZ + ((HIGH_HZ - LOW_HZ) as u32 * position as u32 / u8::MAX as u32) as u16
+            } else {
+                SCALE_HZ[position as usize * DotScreen::HEIGHT / (u8::MAX as usize + 1)]
+            }
+        });
+        play(components, frequency_hz, &mut playing_hz);
+
+        // Light the row of the height of the hand (and, for the scale, every row below it).
+        let mut lit = DotScreen::new_empty();
+        if let Some(position) = position {
+            let row = position as usize * DotScreen::HEIGHT / (u8::MAX as usize + 1);
+            let bottom = if glide { row } else { 0 };
+            lit.draw_rect(&Dot { x: 0, y: bottom }, DotScreen::WIDTH, row + 1 - bottom, true);
+        }
+        // While gliding, the rows the hand has left fade away behind it.
+        if glide { screen.fade() } else { screen.clear() }
+        for dot in lit.iter_on() {
+            screen.set(&dot, GreyScreen::MAX_LEVEL);
+        }
+    }
+    components.buzzer.stop();
+}
+
+
+/// Play the tone of a pitch on the buzzer, unbroken while the pitch stays the same.
+///
+/// # Arguments
+/// * components   - The peripheral components.
+/// * frequency_hz - The pitch of the tone, or None for silence.
+/// * playing_hz   - The pitch of the tone being played, if any, which is updated.
+fn play(components: &mut Components, frequency_hz: Option<u16>, playing_hz: &mut Option<u16>) {
+    let frequency_hz = frequency_hz.filter(|_| components.cue_outputs.sound);
+    if frequency_hz != *playing_hz {
+        components.buzzer.stop();
+        *playing_hz = frequency_hz;
+    }
+    // Queue the next tone behind the current one, so that it carries on without a break.
+    if let Some(frequency_hz) = frequency_hz {
+        if components.buzzer.queued() == 0 { components.buzzer.play(Note::new(frequency_hz, HOLD_MS)); }
+    }
+}
@@ -0,0 +1,179 @@
+/// Implementation of the two-player Tug-of-War (button masher) game for an 8x8 LED Dot Screen.
+use crate::Components;
+use crate::peripherals::{Dot, Display, DotScreen};
+use super::{game::GamePlay, utils::{self, TickInput}};
+use super::versus::{self, Handicaps, RoundPlay, VersusMatch};
+
+// Constants for the Tug-of-War game.
+//   The number of rounds in a match.
+const ROUNDS: usize = 3;
+//   The length of a single game tick. This is short, so no button press is missed.
+const TICK_INTERVAL_MS: usize = 20;
+//   The number of sub-dot units in the width of a dot, for smooth pulling.
+const UNITS_PER_DOT: usize = 16;
+//   The width of the lit bar.
+const BAR_WIDTH: usize = 2;
+//   The position of the bar at which the second player has won the round, in units.
+//   The first player has won the round once the bar reaches position 0.
+const FAR_RIGHT: usize = (DotScreen::WIDTH - BAR_WIDTH) * UNITS_PER_DOT;
+//   The distance the bar is pulled with each button press, in units.
+const PULL: usize = 4;
+//   The additional distance pulled with each press for each handicap level, in units.
+const PULL_PER_HANDICAP: usize = 1;
+
+
+/// The Title Screen for the Tug-of-War Game ("T").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01000000,
+            0b01000000,
+            0b01111110,
+            0b01111110,
+            0b01000000,
+            0b01000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(tug_of_war_game_loop);
+
+
+/// The game loop which runs the Tug-of-War game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn tug_of_war_game_loop(components: &mut Components) {
+    let mut game = TugOfWarGame::new();
+    game.handicaps = versus::choose_handicaps(components, Handicaps::default());
+    if VersusMatch::best_of(ROUNDS).play(&mut game, components).is_some() {
+        utils::wait_for_button_press(components);
+    }
+}
+
+
+/// The TugOfWarGame object.
+///
+/// A lit bar sits in the middle of a rope stretched across the screen. Each button press
+///   of a player pulls the bar toward their side of the screen (the first player's on the
+///   left, the second player's on the right), and the first player to drag the bar fully
+///   to their side wins the round.
+struct TugOfWarGame {
+    /// The position of the left edge of the bar, in units.
+    position: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// Whether the button of each player was held during the previous game tick.
+    buttons_held: [bool; 2],
+    /// The handicaps of the players, each level of which strengthens their pull.
+    handicaps: Handicaps,
+}
+
+impl TugOfWarGame {
+
+    /// Construct a new TugOfWarGame object.
+    fn new() -> Self {
+        Self {
+            position: FAR_RIGHT / 2,
+            screen: DotScreen::new_empty(),
+            buttons_held: [true; 2],
+            handicaps: Handicaps::default(),
+        }
+    }
+
+    /// Pull the bar toward the player's side of the screen, once for each fresh press
+    ///   of their button.
+    ///
+    /// # Arguments
+    /// * index - The index of the player (0 or 1).
+    /// * input - The input of the player received over the game tick.
+    fn pull(&mut self, index: usize, input: TickInput) {
+        if input.button && !self.buttons_held[index] {
+            let pull = PULL + PULL_PER_HANDICAP * self.handicaps.level(index + 1) as usize;
+            self.position = if index == 0 {
+                self.position.saturating_sub(pull)
+            } else {
+                (self.position + pull).min(FAR_RIGHT)
+            };
+        }
+        self.buttons_held[index] = input.button;
+    }
+
+    /// This method is called to reset the round to its initial state.
+    fn reset(&mut self) {
+        self.position = FAR_RIGHT / 2;
+        self.buttons_held = [true; 2];
+    }
+}
+
+
+impl GamePlay for TugOfWarGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the input of the first player received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        self.pull(0, input);
+    }
+
+    /// The game is played by two players.
+    fn is_versus(&self) -> bool {
+        true
+    }
+
+    /// Handle the input of the second player received over a game tick.
+    fn handle_input2(&mut self, input: TickInput) {
+        self.pull(1, input);
+    }
+
+    /// Update the game state. The bar only moves as it is pulled, so a round never ends here.
+    fn update(&mut self, _rng: &mut dyn rand_core::RngCore) -> bool {
+        true
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        // The rope, across the middle of the screen.
+        self.screen.draw_h_line(&Dot { x: 0, y: DotScreen::HEIGHT / 2 }, DotScreen::WIDTH);
+        // The bar, rounded to the nearest dot.
+        let left = (self.position + UNITS_PER_DOT / 2) / UNITS_PER_DOT;
+        self.screen.draw_rect(&Dot { x: left, y: 1 }, BAR_WIDTH, DotScreen::HEIGHT - 2, true);
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the round, with the bar back in the middle of the rope.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+}
+
+
+impl RoundPlay for TugOfWarGame {
+
+    /// Reset the bar to the middle of the rope, to begin a new round.
+    fn begin(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the winner of the round (1 or 2), once the bar has been dragged fully
+    ///   to their side.
+    fn winner(&self) -> Option<usize> {
+        if self.position == 0 { Some(1) } else if self.position == FAR_RIGHT { Some(2) } else { None }
+    }
+}
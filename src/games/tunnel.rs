@@ -0,0 +1,209 @@
+/// Implementation of the Tunnel Runner game for an 8x8 LED Dot Screen.
+use crate::{Components, Direction};
+use crate::peripherals::{Dot, Display, DotScreen};
+use crate::sound::Melody;
+use super::{game::{self, GamePlay}, utils::{self, TickInput}};
+
+// Constants for the Tunnel Runner game.
+//   The identifier of the game, used when reporting scores.
+const GAME_ID: u8 = b'R';
+//   The y-index (row) along which the player runs.
+const PLAYER_Y: usize = 1;
+//   The width of the tunnel.
+const TUNNEL_WIDTH: usize = 3;
+//   The length of a single game tick.
+const TICK_INTERVAL_MS: usize = 60;
+//   The initial and minimum number of game ticks between each scroll of the tunnel.
+const INITIAL_SCROLL_TICKS: usize = 5;
+const MIN_SCROLL_TICKS: usize = 2;
+//   The number of rows scrolled after which the tunnel scrolls faster.
+const SPEED_UP_INTERVAL: usize = 48;
+//   The number of rows scrolled for each point scored.
+const DISTANCE_PER_POINT: usize = 4;
+
+
+/// The Title Screen for the Tunnel Runner Game ("R").
+#[link_section = ".progmem.data"]
+pub static TITLE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b00000000,
+            0b01111110,
+            0b01001000,
+            0b01001100,
+            0b00110010,
+            0b00000000,
+            0b00000000,
+            0b00000000,
+        ]
+    );
+
+game_assets! { title: TITLE_SCREEN }
+game!(tunnel_game_loop, id: GAME_ID);
+
+
+/// The game loop which runs the Tunnel Runner game.
+///
+/// # Arguments
+/// components - The peripheral components.
+pub fn tunnel_game_loop(components: &mut Components) {
+    let mut game = TunnelGame::new();
+    if game::play(&mut game, components) { game.game_over(components) }
+}
+
+
+/// The TunnelGame object.
+///
+/// A winding tunnel scrolls down the screen, while the player steers left and right
+///   to stay inside it. The tunnel scrolls faster the further the player runs, and the
+///   game ends when the player hits the tunnel wall.
+struct TunnelGame {
+    /// The x-index of the leftmost open dot of the tunnel, indexed by y-index.
+    tunnel: [usize; DotScreen::HEIGHT],
+    /// The x-index of the player.
+    player_x: usize,
+    /// The screen depicting the current state of the game.
+    screen: DotScreen,
+    /// The number of rows scrolled.
+    distance: usize,
+    /// The number of game ticks elapsed.
+    tick: usize,
+}
+
+impl TunnelGame {
+
+    /// Construct a new TunnelGame object.
+    fn new() -> Self {
+        let mut game = Self {
+            tunnel: [0; DotScreen::HEIGHT],
+            player_x: 0,
+            screen: DotScreen::new_empty(),
+            distance: 0,
+            tick: 0,
+        };
+        game.reset();
+        return game
+    }
+
+    /// Returns whether the dot at the position is inside the tunnel.
+    fn is_open(&self, x: usize, y: usize) -> bool {
+        (x >= self.tunnel[y]) & (x < self.tunnel[y] + TUNNEL_WIDTH)
+    }
+
+    /// Scroll the tunnel one row down, generating a new row at the top.
+    ///
+    /// The tunnel wanders at most one dot from the row beneath, bounded by the edges
+    ///   of the screen, so the tunnel is always passable.
+    fn scroll(&mut self, rng: &mut dyn rand_core::RngCore) {
+        self.tunnel.rotate_left(1);
+        self.distance += 1;
+
+        let last = self.tunnel[DotScreen::HEIGHT - 2];
+        let left = match utils::random_below(rng, 3) {
+            0 => { last.saturating_sub(1) },
+            1 => { last },
+            _ => { last + 1 },
+        };
+        self.tunnel[DotScreen::HEIGHT - 1] = left.min(DotScreen::WIDTH - TUNNEL_WIDTH);
+    }
+
+    /// This method is called when the game is over.
+    ///
+    /// When the game over state is complete, this method returns.
+    ///
+    /// # Args
+    /// * components - The peripheral components for the game display.
+    fn game_over(&mut self, components: &mut Components) {
+        self.render();
+        utils::flash_screen(&mut components.display, &self.screen, 2);
+        components.report_score(GAME_ID, self.distance / DISTANCE_PER_POINT);
+        utils::show_tally(&mut components.display, self.distance / DISTANCE_PER_POINT);
+        utils::wait_for_button_press(components);
+    }
+
+    /// This method is called to reset the game to its initial state.
+    ///
+    /// After this method is called, the game should be ready to be played again.
+    fn reset(&mut self) {
+        let left = (DotScreen::WIDTH - TUNNEL_WIDTH) / 2;
+        self.tunnel = [left; DotScreen::HEIGHT];
+        self.player_x = left + TUNNEL_WIDTH / 2;
+        self.distance = 0;
+        self.tick = 0;
+        self.render();
+    }
+}
+
+
+impl GamePlay for TunnelGame {
+
+    /// Returns the length of a game tick.
+    fn tick_interval_ms(&self) -> usize {
+        TICK_INTERVAL_MS
+    }
+
+    /// Handle the JoyStick input received over a game tick.
+    fn handle_input(&mut self, input: TickInput) {
+        match input.direction {
+            Some(Direction::Left) if self.player_x > 0 => { self.player_x -= 1 },
+            Some(Direction::Right) if self.player_x < DotScreen::WIDTH - 1 => { self.player_x += 1 },
+            _ => {}
+        }
+    }
+
+    /// Update the game state.
+    ///
+    /// This is called for every game tick. This scrolls the tunnel and checks whether
+    ///   the player has hit the tunnel wall.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    ///
+    /// # Returns
+    /// Whether the game state was successfully updated.
+    fn update(&mut self, rng: &mut dyn rand_core::RngCore) -> bool {
+        self.tick += 1;
+        let speed_ups = self.distance / SPEED_UP_INTERVAL;
+        let scroll_ticks = INITIAL_SCROLL_TICKS.saturating_sub(speed_ups).max(MIN_SCROLL_TICKS);
+        if self.tick % scroll_ticks == 0 {
+            self.scroll(rng);
+        }
+        self.is_open(self.player_x, PLAYER_Y)
+    }
+
+    /// Draw the current game state onto the screen.
+    fn render(&mut self) {
+        self.screen.clear();
+        for y in 0..DotScreen::HEIGHT {
+            for x in 0..DotScreen::WIDTH {
+                if !self.is_open(x, y) { self.screen.add(&Dot { x, y }) }
+            }
+        }
+        self.screen.toggle(&Dot { x: self.player_x, y: PLAYER_Y });
+    }
+
+    /// Show the screen of the game on the display.
+    fn show(&self, display: &mut dyn Display) {
+        display.show(&self.screen);
+    }
+
+    /// Returns the screen of the game, shown on the overlay menu while the game is paused.
+    fn paused_screen(&self) -> DotScreen {
+        self.screen
+    }
+
+    /// Restart the game from the beginning.
+    fn restart(&mut self, _rng: &mut dyn rand_core::RngCore) {
+        self.reset();
+    }
+
+    /// Returns the current score: the distance travelled.
+    fn score(&self) -> usize {
+        self.distance / DISTANCE_PER_POINT
+    }
+
+    /// The score rises steadily, so its increases play no sound.
+    fn on_score(&self) -> Option<&'static Melody> {
+        None
+    }
+}
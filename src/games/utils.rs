@@ -0,0 +1,403 @@
+/// Helper functionality shared between the games.
+use crate::{cues, Components, Direction};
+use crate::graphics::{self, SmallFont, Transition};
+use super::replay;
+use crate::peripherals::{
+    clock, sampler, watchdog, Dot, Display, DotScreen, InputEvent, InputEvents, JoyStickSignal
+};
+
+
+/// Summary of the JoyStick input received over a single game tick.
+#[derive(Copy, Clone)]
+pub struct TickInput {
+    /// The last direction registered by the JoyStick during the tick, if any.
+    pub direction: Option<Direction>,
+    /// Whether the JoyStick button was pressed at any point during the tick.
+    pub button: bool,
+    /// Whether the JoyStick button was pressed down during the tick, rather than held
+    ///   down since an earlier tick. This is how a single press triggers a single action.
+    pub pressed: bool,
+    /// The direction the JoyStick was newly pushed in during the tick, if any (not a
+    ///   direction held since an earlier tick). This is how a menu moves a single step per push.
+    pub pushed: Option<Direction>,
+    /// Whether the JoyStick button was held down for a long press during the tick
+    ///   (see `InputEvent::Held`). This is how a game pauses when its presses are input.
+    pub held: bool,
+}
+
+impl TickInput {
+
+    /// Summarize the input events of a game tick (see `InputEvents`).
+    pub fn from_events(events: &InputEvents) -> Self {
+        let mut input = Self {
+            direction: events.direction(),
+            button: events.button(),
+            pressed: false,
+            pushed: None,
+            held: false,
+        };
+        for &event in events.iter() {
+            match event {
+                InputEvent::Pressed => {
+                    input.button = true;
+                    input.pressed = true;
+                },
+                InputEvent::Held => { input.held = true },
+                InputEvent::DirectionChanged(Some(direction)) => {
+                    input.pushed = Some(direction);
+                    // A direction pushed and released within the tick still registers.
+                    if events.direction().is_none() { input.direction = Some(direction) }
+                },
+                _ => {}
+            }
+        }
+        input
+    }
+}
+
+/// Poll the JoyStick for a single game tick, summarizing the received input.
+///
+/// Ticks are scheduled in real time: the tick ends its length after the previous tick
+///   ended, so the time taken between polls (e.g. to update and render) is part of the tick.
+/// The input passes through the replay in progress, if any (see `replay`), and the
+///   components are tended once the JoyStick has been polled (see `tend`).
+///
+/// # Arguments
+/// * components  - The peripheral components, which contain the JoyStick.
+/// * duration_ms - The length of the game tick in milliseconds.
+pub fn poll_tick(components: &mut Components, duration_ms: usize) -> TickInput {
+    let remaining_ms = components.analog.schedule_tick(duration_ms);
+    components.analog.poll_joystick(remaining_ms);
+    tend(components);
+    let mut input = TickInput::from_events(components.analog.events());
+    replay::pass(core::slice::from_mut(&mut input));
+    input
+}
+
+/// Poll the JoySticks of both players for a single game tick, summarizing the
+///   received input of each. Ticks are scheduled in real time, as `poll_tick`.
+///
+/// # Arguments
+/// * components  - The peripheral components, which contain the JoySticks.
+/// * duration_ms - The length of the game tick in milliseconds.
+///
+/// # Returns
+/// The input of the first player and the second player, respectively.
+pub fn poll_tick2(components: &mut Components, duration_ms: usize) -> [TickInput; 2] {
+    let remaining_ms = components.analog.schedule_tick(duration_ms);
+    components.analog.poll_joysticks(remaining_ms);
+    tend(components);
+    let analog = &mut components.analog;
+    let mut inputs = [TickInput::from_events(analog.events()), TickInput::from_events(analog.events2())];
+    replay::pass(&mut inputs);
+    inputs
+}
+
+/// Wait out a single game tick while animating the display, summarizing the JoyStick
+///   input sampled in the background meanwhile (see `peripherals::sampler`).
+///
+/// Unlike `poll_tick`, this does not block on the JoyStick, so the display can be
+///   animated throughout the tick without missing any input. Ticks are scheduled in
+///   real time, as `poll_tick`.
+///
+/// # Arguments
+/// * components  - The peripheral components.
+/// * duration_ms - The length of the game tick in milliseconds.
+/// * animate     - Called repeatedly throughout the tick, with the display and the time
+///                   elapsed since it was first called.
+pub fn animate_tick<F>(components: &mut Components, duration_ms: usize, mut animate: F) -> TickInput
+  where F: FnMut(&mut dyn Display, usize)
+{
+    let remaining_ms = components.analog.schedule_tick(duration_ms) as u32;
+    let start_ms = clock::millis();
+    sampler::start();
+    loop {
+        let elapsed_ms = clock::elapsed_since(start_ms);
+        if elapsed_ms >= remaining_ms { break }
+        animate(&mut components.display, elapsed_ms as usize);
+    }
+    sampler::stop();
+
+    let events = components.analog.events();
+    events.clear();
+    let mut sampled = false;
+    let button = sampler::take(|signal| {
+        events.track(Some(signal));
+        sampled = true;
+    });
+    if !sampled { events.track(None) }
+    let mut input = TickInput::from_events(events);
+    // A press may have been overwritten by newer samples, but is never missed.
+    if button & !input.button {
+        input.button = true;
+        input.pressed = true;
+    }
+    tend(components);
+    replay::pass(core::slice::from_mut(&mut input));
+    input
+}
+
+/// Tend to the components once every game tick, whichever game (or menu) is running:
+///   adjusting the brightness to the ambient light (see `Components::adjust_brightness`),
+///   monitoring the battery (see `Components::monitor_battery`), and running any commands
+///   received by the console (see `Components::poll_console`).
+///
+/// # Arguments
+/// * components - The peripheral components.
+fn tend(components: &mut Components) {
+    components.adjust_brightness();
+    components.monitor_battery();
+    components.poll_console();
+}
+
+/// A countdown of game ticks.
+///
+/// This is used to time the entities of a game, such as fuses or animation frames.
+#[derive(Copy, Clone)]
+pub struct Countdown {
+    /// The number of game ticks remaining until the countdown expires.
+    remaining: usize,
+}
+
+impl Countdown {
+
+    /// Create a new Countdown which expires after the provided number of game ticks.
+    pub const fn new(ticks: usize) -> Self {
+        Self { remaining: ticks }
+    }
+
+    /// Advance the countdown by a single game tick.
+    ///
+    /// # Returns
+    /// Whether the countdown has expired.
+    pub fn tick(&mut self) -> bool {
+        if self.remaining > 0 { self.remaining -= 1 }
+        self.is_expired()
+    }
+
+    /// Returns the number of game ticks remaining until the countdown expires.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Returns whether the countdown has expired.
+    pub fn is_expired(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// A camera which views a vertical slice of a world taller than the screen.
+///
+/// World y-coordinates grow upward without bound, and the camera shows the
+///   rows from `bottom` up to `bottom + DotScreen::HEIGHT - 1`. The camera only
+///   ever scrolls upward, following its target.
+#[derive(Copy, Clone)]
+pub struct VerticalCamera {
+    /// The world y-coordinate of the bottom row of the screen.
+    bottom: usize,
+}
+
+impl VerticalCamera {
+
+    /// Create a new VerticalCamera, with the bottom of the screen at the bottom of the world.
+    pub const fn new() -> Self {
+        Self { bottom: 0 }
+    }
+
+    /// Returns the world y-coordinate of the bottom row of the screen.
+    pub fn bottom(&self) -> usize {
+        self.bottom
+    }
+
+    /// Returns whether the world y-coordinate has scrolled off the bottom of the screen.
+    pub fn is_below(&self, world_y: usize) -> bool {
+        world_y < self.bottom
+    }
+
+    /// Convert world coordinates into the Dot on the screen, if it is in view.
+    pub fn to_screen(&self, x: usize, world_y: usize) -> Option<Dot> {
+        if (world_y < self.bottom) | (world_y >= self.bottom + DotScreen::HEIGHT) { return None }
+        Some(Dot { x, y: world_y - self.bottom })
+    }
+
+    /// Scroll the camera upward so that the target is displayed no higher than the
+    ///   provided row of the screen.
+    ///
+    /// # Arguments
+    /// * world_y - The world y-coordinate of the target to follow.
+    /// * max_row - The highest screen row at which the target may be displayed.
+    pub fn follow(&mut self, world_y: usize, max_row: usize) {
+        if world_y > self.bottom + max_row {
+            self.bottom = world_y - max_row;
+        }
+    }
+}
+
+/// Generate a random number in the range [0, modulus).
+///
+/// # Arguments
+/// * rng     - The Random Number Generator.
+/// * modulus - The (exclusive) upper bound of the generated number.
+pub fn random_below(rng: &mut dyn rand_core::RngCore, modulus: usize) -> usize {
+    (rng.next_u32() as usize) % modulus
+}
+
+/// Count down to the start of the game-play, showing "3", "2", "1", and then "GO".
+///
+/// Games run this as their game-play starts, and again on resuming it from the overlay
+///   menu, so the player isn't caught off guard. Input during the countdown is ignored.
+///   The display is left showing "GO", so the game should show its screen afterwards.
+///   A switch to another game (see `selection::is_switch_requested`) skips the countdown.
+///
+/// # Arguments
+/// * components - The peripheral components.
+pub fn countdown(components: &mut Components) {
+    const COUNT_MS: usize = 600;
+    if super::selection::is_switch_requested() { return }
+    for count in (1..=3).rev() {
+        let mut screen = DotScreen::new_empty();
+        super::digits::draw_number(&mut screen, count);
+        cues::play(components, &cues::COUNT, &screen);
+        components.analog.poll_joystick(COUNT_MS);
+    }
+    let mut screen = DotScreen::new_empty();
+    graphics::draw_text(&mut screen, &SmallFont, "GO", 0);
+    cues::play(components, &cues::GO, &screen);
+    components.analog.poll_joystick(COUNT_MS);
+}
+
+/// Loop waiting for a JoyStick button press.
+///
+/// Pressing the button while holding Left or Right instead switches directly to the
+///   previous or next game (the quick-switch shortcut of the game over screen), once
+///   the game returns (see `selection::quick_switch`).
+pub fn wait_for_button_press(components: &mut Components) {
+    wait_for_button_press_holding(components);
+}
+
+/// Loop waiting for a JoyStick button press, as `wait_for_button_press`.
+///
+/// # Returns
+/// The direction held (Up or Down) while the button was pressed, if any.
+///   Games may use this to offer extra options on their game over screen.
+pub fn wait_for_button_press_holding(components: &mut Components) -> Option<Direction> {
+    const POLL_MS: usize = 100;
+    loop {
+        // A switch to another game ends the game over screen.
+        if super::selection::is_switch_requested() { return None }
+        // The game over screen may be left alone, so go to sleep after a while.
+        components.sleep_if_idle();
+        match components.analog.poll_joystick(POLL_MS).front() {
+            None => {},
+            Some(signal) => {
+                let signal = signal.to_joystick();
+                if let JoyStickSignal { button: true, .. } = signal {
+                    // Holding Left or Right switches directly to the previous or next game.
+                    match signal.to_single_direction() {
+                        Some(direction @ Direction::Left) | Some(direction @ Direction::Right) => {
+                            super::selection::quick_switch(direction);
+                            return None
+                        },
+                        direction => { return direction },
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Draw a brightness level of the display, as a ramp rising to the right
+///   with a column for each level up to the provided one.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * level  - The brightness level, see `DotDisplay::set_brightness`.
+pub fn draw_brightness(screen: &mut DotScreen, level: u8) {
+    for x in 0..=(level as usize).min(DotScreen::WIDTH - 1) {
+        for y in 0..=x {
+            screen.add(&Dot { x, y });
+        }
+    }
+}
+
+/// Draw a difficulty level, as three bars of increasing height (easy, normal, and hard)
+///   with the bars up to the provided level lit. Unlit bars are drawn as a single row.
+///
+/// # Arguments
+/// * screen    - The screen to draw onto.
+/// * level     - The difficulty level (0 is easy, 1 is normal, 2 is hard).
+/// * lit_level - Whether the bar of the level itself is lit (e.g. unlit to blink it).
+pub fn draw_difficulty(screen: &mut DotScreen, level: usize, lit_level: bool) {
+    const BAR_HEIGHTS: [usize; 3] = [3, 5, 8];
+    // Each bar is two columns wide, with a column between the bars.
+    for (bar, &height) in BAR_HEIGHTS.iter().enumerate() {
+        let lit = (bar < level) | ((bar == level) & lit_level);
+        let height = if lit { height } else { 1 };
+        for x in (3 * bar)..(3 * bar + 2) {
+            for y in 0..height {
+                screen.add(&Dot { x, y });
+            }
+        }
+    }
+}
+
+/// Flash between the provided screen and an empty screen, fading the screen in and out.
+///
+/// This is used to indicate to the player that the game is over.
+/// The display is left empty once this returns, the last flash dissolving away.
+///
+/// # Arguments
+/// * display - The display.
+/// * screen  - The screen to flash.
+/// * times   - The number of times to flash the screen.
+pub fn flash_screen(display: &mut dyn Display, screen: &DotScreen, times: usize) {
+    const FADE_MS: u16 = 300;
+    const HOLD_MS: u16 = 300;
+    const DISSOLVE_FRAME_MS: u16 = 50;
+    // The game over screen runs several seconds without polling, so feeds the watchdog itself.
+    watchdog::feed();
+    let empty_screen = DotScreen::new_empty();
+    display.show(&empty_screen);
+    for flash in 0..times {
+        display.fade_in(screen, FADE_MS);
+        arduino_uno::delay_ms(HOLD_MS);
+        if flash + 1 == times {
+            Transition::Dissolve.play(display, screen, &empty_screen, DISSOLVE_FRAME_MS);
+        } else {
+            display.fade_out(FADE_MS);
+        }
+    }
+}
+
+/// Display a score to the user by displaying a dot for each point,
+///   one at a time, from left to right, top to bottom of the screen.
+///
+/// The tally is capped at the number of dots on the screen. A larger tally
+///   fills the screen, and then the actual number is shown in digits.
+///
+/// # Arguments
+/// * display - The display.
+/// * tally   - The number of dots to display.
+pub fn show_tally(display: &mut dyn Display, tally: usize) {
+    watchdog::feed();
+    let capped_tally = tally.min(DotScreen::TOTAL_DOTS);
+    let mut tally_screen = DotScreen::new_empty();
+    display.show(&tally_screen);
+    if capped_tally == 0 { return }
+
+    let delay = 3000 / (capped_tally as u16);
+    DotScreen::new_empty()
+        .iter()
+        .take(capped_tally)
+        .for_each(|dot| {
+            tally_screen.add(&dot);
+            display.show(&tally_screen);
+            arduino_uno::delay_ms(delay);
+        }
+    );
+
+    if tally > capped_tally {
+        super::digits::show_score(display, tally);
+        display.show(&tally_screen);
+    }
+}
@@ -0,0 +1,213 @@
+/// Functionality shared by the two-player (versus) games.
+use crate::{cues, Components, Direction};
+use crate::peripherals::{Dot, DotScreen};
+use super::{game::{self, GamePlay, Outcome}, utils};
+
+
+/// The handicap levels of both players of a versus game.
+///
+/// A handicap level gives a player an advantage, allowing mismatched players to
+///   have competitive games. Level 0 is no advantage, up to `MAX_LEVEL`.
+///   Each game decides what the advantage is (e.g. a larger paddle, faster
+///   movement, a longer starting length).
+#[derive(Copy, Clone, Default)]
+pub struct Handicaps {
+    /// The handicap level of the first (left) player.
+    pub player1: u8,
+    /// The handicap level of the second (right) player.
+    pub player2: u8,
+}
+
+impl Handicaps {
+
+    /// The highest handicap level.
+    pub const MAX_LEVEL: u8 = 3;
+
+    /// Returns the handicap level of the player (1 or 2).
+    pub fn level(&self, player: usize) -> u8 {
+        if player == 1 { self.player1 } else { self.player2 }
+    }
+
+    /// Returns a mutable reference to the handicap level of the player (1 or 2).
+    fn level_mut(&mut self, player: usize) -> &mut u8 {
+        if player == 1 { &mut self.player1 } else { &mut self.player2 }
+    }
+
+    /// Draw the handicap levels onto a screen, as a bar for each player
+    ///   (the first player on the left, the second player on the right).
+    ///
+    /// # Arguments
+    /// * screen   - The screen to draw onto.
+    /// * selected - The player whose bar to leave out (for blinking), if any.
+    fn draw(&self, screen: &mut DotScreen, selected: Option<usize>) {
+        for &(player, left) in [(1, 0), (2, 5)].iter() {
+            if selected == Some(player) { continue }
+            let height = 2 * (self.level(player) as usize + 1);
+            for x in left..(left + 3) {
+                for y in 0..height {
+                    screen.add(&Dot { x, y });
+                }
+            }
+        }
+    }
+}
+
+
+/// Run the pre-match screen on which the players choose their handicaps.
+///
+/// Each player's handicap is displayed as a bar, the first player's on the left and
+///   the second player's on the right. Left and Right choose the player, whose bar
+///   blinks, Up and Down adjust their handicap, and a button press starts the match.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * handicaps  - The handicaps to begin with (e.g. those of the previous match).
+///
+/// # Returns
+/// The chosen handicaps.
+pub fn choose_handicaps(components: &mut Components, mut handicaps: Handicaps) -> Handicaps {
+    const TICK_INTERVAL_MS: usize = 150;
+    let mut selected = 1;
+    let mut tick = 0usize;
+    loop {
+        let input = utils::poll_tick(components, TICK_INTERVAL_MS);
+        // A switch to another game (requested on the console) leaves the handicaps as they are.
+        if input.pressed | super::selection::is_switch_requested() { return handicaps }
+
+        match input.direction {
+            Some(Direction::Left) => { selected = 1 },
+            Some(Direction::Right) => { selected = 2 },
+            Some(Direction::Up) => {
+                let level = handicaps.level_mut(selected);
+                if *level < Handicaps::MAX_LEVEL { *level += 1 }
+            },
+            Some(Direction::Down) => {
+                let level = handicaps.level_mut(selected);
+                if *level > 0 { *level -= 1 }
+            },
+            None => {}
+        }
+
+        tick += 1;
+        let mut screen = DotScreen::new_empty();
+        handicaps.draw(&mut screen, if tick % 2 == 0 { Some(selected) } else { None });
+        components.display.show(&screen);
+    }
+}
+
+
+/// The game-play of a round of a versus game, run by the game loop (see `VersusMatch::play`).
+///
+/// The game loop runs each round until it has been won. Versus games are played by two
+///   players, so each also returns true from `GamePlay::is_versus`.
+pub trait RoundPlay: GamePlay {
+
+    /// Reset the game to its initial state, to begin a new round.
+    ///
+    /// # Arguments
+    /// * rng - The Random Number Generator.
+    fn begin(&mut self, rng: &mut dyn rand_core::RngCore);
+
+    /// Returns the winner of the round (1 or 2), once it has been won.
+    fn winner(&self) -> Option<usize>;
+}
+
+
+/// A match of a versus game, played as the best of a number of rounds.
+pub struct VersusMatch {
+    /// The number of round wins needed to win the match.
+    wins_needed: usize,
+    /// The number of rounds won by each player (the first player first).
+    wins: [usize; 2],
+}
+
+impl VersusMatch {
+
+    /// The time the tally of round wins is shown between rounds.
+    const TALLY_MS: u16 = 1500;
+    /// The time the starting position is shown before each round begins.
+    const ROUND_START_MS: u16 = 1000;
+    /// The most rounds of a match, so that the tally (a dot on every other row per round won) fits the screen.
+    pub const MAX_ROUNDS: usize = DotScreen::HEIGHT - 1;
+
+    /// Construct a new VersusMatch, played as the best of the provided number of rounds.
+    ///
+    /// # Arguments
+    /// * rounds - The (odd) number of rounds, e.g. 3 or 5, at most `MAX_ROUNDS` (more are clamped to it).
+    pub fn best_of(rounds: usize) -> Self {
+        debug_assert!(rounds <= Self::MAX_ROUNDS);
+        let rounds = rounds.min(Self::MAX_ROUNDS);
+        Self { wins_needed: rounds / 2 + 1, wins: [0; 2] }
+    }
+
+    /// Returns the number of rounds won by the player (1 or 2).
+    pub fn wins(&self, player: usize) -> usize {
+        self.wins[player - 1]
+    }
+
+    /// Returns the winner of the match (1 or 2), if the match has been won.
+    pub fn winner(&self) -> Option<usize> {
+        (1..=2).find(|&player| self.wins(player) >= self.wins_needed)
+    }
+
+    /// Play the match to completion, running the game loop for each round.
+    ///
+    /// The match starts with a countdown (see `utils::countdown`), and each round begins
+    ///   by showing its starting position. Between rounds the tally of round wins is shown,
+    ///   and once a player has won enough rounds their side of the screen celebrates the
+    ///   match win.
+    ///
+    /// # Arguments
+    /// * game       - The game, played a round at a time.
+    /// * components - The peripheral components.
+    ///
+    /// # Returns
+    /// The winner of the match (1 or 2), or None if the players quit the match.
+    pub fn play<G: RoundPlay>(&mut self, game: &mut G, components: &mut Components) -> Option<usize> {
+        self.wins = [0; 2];
+        utils::countdown(components);
+        loop {
+            game.begin(&mut components.analog);
+            game.render();
+            game.show(&mut components.display);
+            arduino_uno::delay_ms(Self::ROUND_START_MS);
+
+            let outcome = game::run(game, components, |game| game.winner().map(|_| Outcome::Complete));
+            if outcome != Outcome::Complete { return None }
+            let round_winner = game.winner()?;
+            self.wins[round_winner - 1] += 1;
+
+            let mut screen = DotScreen::new_empty();
+            self.draw_tally(&mut screen);
+            cues::play(components, &cues::POINT, &screen);
+            arduino_uno::delay_ms(Self::TALLY_MS);
+
+            if let Some(winner) = self.winner() {
+                self.celebrate(components, winner);
+                return Some(winner)
+            }
+        }
+    }
+
+    /// Draw the tally of round wins onto a screen, as a column of dots for each player
+    ///   (the first player on the left, the second player on the right).
+    fn draw_tally(&self, screen: &mut DotScreen) {
+        for &(player, x) in [(1, 1), (2, DotScreen::WIDTH - 2)].iter() {
+            for round in 0..self.wins(player) {
+                screen.add(&Dot { x, y: 2 * round });
+            }
+        }
+    }
+
+    /// Celebrate the match winner by flashing their half of the screen.
+    fn celebrate(&self, components: &mut Components, winner: usize) {
+        let mut screen = DotScreen::new_empty();
+        let left = if winner == 1 { 0 } else { DotScreen::WIDTH / 2 };
+        for x in left..(left + DotScreen::WIDTH / 2) {
+            for y in 0..DotScreen::HEIGHT {
+                screen.add(&Dot { x, y });
+            }
+        }
+        utils::flash_screen(&mut components.display, &screen, 3);
+    }
+}
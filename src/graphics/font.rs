@@ -0,0 +1,184 @@
+/// Fonts, mapping characters to their glyphs, and the drawing of glyphs onto a screen.
+use crate::peripherals::{progmem, DotScreen};
+
+// Constants for the small font.
+//   The width and height of each glyph.
+const SMALL_WIDTH: usize = 3;
+const SMALL_HEIGHT: usize = 5;
+//   The characters of the small font (other than the letters and digits), in the order
+//   of their glyphs after those of the digits and the letters.
+const SMALL_SYMBOLS: [char; 8] = ['!', '?', '.', ':', '-', '+', '=', '/'];
+//   The glyphs of the small font: the digits 0-9, the letters A-Z, and then the symbols.
+//   Within each column, bit 4 is the top of the glyph.
+#[link_section = ".progmem.data"]
+static SMALL_GLYPHS: [[u8; SMALL_WIDTH]; 10 + 26 + 8] = [
+    [0b11111, 0b10001, 0b11111], // 0
+    [0b01001, 0b11111, 0b00001], // 1
+    [0b10111, 0b10101, 0b11101], // 2
+    [0b10101, 0b10101, 0b11111], // 3
+    [0b11100, 0b00100, 0b11111], // 4
+    [0b11101, 0b10101, 0b10111], // 5
+    [0b11111, 0b10101, 0b10111], // 6
+    [0b10000, 0b10000, 0b11111], // 7
+    [0b11111, 0b10101, 0b11111], // 8
+    [0b11101, 0b10101, 0b11111], // 9
+    [0b01111, 0b10100, 0b01111], // A
+    [0b11111, 0b10101, 0b01010], // B
+    [0b01110, 0b10001, 0b10001], // C
+    [0b11111, 0b10001, 0b01110], // D
+    [0b11111, 0b10101, 0b10001], // E
+    [0b11111, 0b10100, 0b10000], // F
+    [0b01110, 0b10001, 0b10111], // G
+    [0b11111, 0b00100, 0b11111], // H
+    [0b10001, 0b11111, 0b10001], // I
+    [0b00010, 0b00001, 0b11110], // J
+    [0b11111, 0b00100, 0b11011], // K
+    [0b11111, 0b00001, 0b00001], // L
+    [0b11111, 0b01100, 0b11111], // M
+    [0b11111, 0b10000, 0b01111], // N
+    [0b01110, 0b10001, 0b01110], // O
+    [0b11111, 0b10100, 0b01000], // P
+    [0b01110, 0b10011, 0b01101], // Q
+    [0b11111, 0b10100, 0b01011], // R
+    [0b01001, 0b10101, 0b10010], // S
+    [0b10000, 0b11111, 0b10000], // T
+    [0b11111, 0b00001, 0b11111], // U
+    [0b11110, 0b00001, 0b11110], // V
+    [0b11111, 0b00110, 0b11111], // W
+    [0b11011, 0b00100, 0b11011], // X
+    [0b11000, 0b00111, 0b11000], // Y
+    [0b10011, 0b10101, 0b11001], // Z
+    [0b00000, 0b11101, 0b00000], // !
+    [0b10000, 0b10101, 0b11000], // ?
+    [0b00000, 0b00001, 0b00000], // .
+    [0b00000, 0b01010, 0b00000], // :
+    [0b00100, 0b00100, 0b00100], // -
+    [0b00100, 0b01110, 0b00100], // +
+    [0b01010, 0b01010, 0b01010], // =
+    [0b00011, 0b00100, 0b11000], // /
+];
+
+
+/// The glyph of a single character, as its columns ordered left to right.
+///
+/// Within each column, bit (height - 1) is the top of the glyph, where the height
+///   is that of the font the glyph belongs to. The glyph is held by value, so that
+///   fonts may store their glyphs wherever they like.
+#[derive(Copy, Clone)]
+pub struct Glyph {
+    /// The columns of the glyph. Only the first `width` columns are used.
+    columns: [u8; Glyph::MAX_WIDTH],
+    /// The number of columns of the glyph.
+    width: usize,
+}
+
+impl Glyph {
+
+    /// The widest a glyph may be.
+    pub const MAX_WIDTH: usize = 5;
+
+    /// Create a new Glyph from its columns. Any columns beyond `MAX_WIDTH` are dropped.
+    pub fn new(columns: &[u8]) -> Self {
+        let width = columns.len().min(Self::MAX_WIDTH);
+        let mut glyph = Self { columns: [0; Self::MAX_WIDTH], width };
+        glyph.columns[..width].copy_from_slice(&columns[..width]);
+        glyph
+    }
+
+    /// Returns the columns of the glyph, ordered left to right.
+    pub fn columns(&self) -> &[u8] {
+        &self.columns[..self.width]
+    }
+}
+
+
+/// A font, mapping characters to their glyphs.
+pub trait Font {
+
+    /// Returns the height of every glyph of the font, at most the height of the screen.
+    fn height(&self) -> usize;
+
+    /// Returns the glyph of a character, if the font has one.
+    fn glyph(&self, character: char) -> Option<Glyph>;
+}
+
+
+/// The small font: 3x5 glyphs of the digits, the (upper case) letters, and a few symbols.
+///
+/// Lower case letters are drawn with the glyphs of the upper case letters.
+///   The glyphs are kept in program memory, rather than RAM.
+pub struct SmallFont;
+
+impl Font for SmallFont {
+    fn height(&self) -> usize {
+        SMALL_HEIGHT
+    }
+
+    fn glyph(&self, character: char) -> Option<Glyph> {
+        let character = character.to_ascii_uppercase();
+        let index = match character {
+            '0'..='9' => { character as usize - '0' as usize },
+            'A'..='Z' => { 10 + character as usize - 'A' as usize },
+            _ => { 10 + 26 + SMALL_SYMBOLS.iter().position(|&symbol| symbol == character)? },
+        };
+        let mut columns = [0; SMALL_WIDTH];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = unsafe { progmem::read_byte(&SMALL_GLYPHS[index][x]) };
+        }
+        Some(Glyph::new(&columns))
+    }
+}
+
+
+/// Convert a column of a glyph into a column of a DotScreen, with the glyph centered vertically.
+///
+/// # Arguments
+/// * column - The column of the glyph.
+/// * height - The height of the font of the glyph.
+pub fn to_screen_column(column: u8, height: usize) -> u8 {
+    let top = (DotScreen::HEIGHT - height) / 2;
+    (column << (DotScreen::HEIGHT - height)) >> top
+}
+
+/// Draw the glyph of a character onto a screen, centered vertically, lighting the dots of the glyph.
+///
+/// Characters without a glyph are not drawn, and columns off the screen are ignored.
+///
+/// # Arguments
+/// * screen    - The screen to draw onto.
+/// * font      - The font of the character.
+/// * character - The character to draw.
+/// * left      - The x-index of the leftmost column of the glyph.
+///
+/// # Returns
+/// The width of the glyph drawn (0 if the character has no glyph).
+pub fn draw_character<F: Font>(screen: &mut DotScreen, font: &F, character: char, left: usize) -> usize {
+    let glyph = match font.glyph(character) {
+        Some(glyph) => glyph,
+        None => { return 0 },
+    };
+    for (offset, &column) in glyph.columns().iter().enumerate() {
+        if left + offset < DotScreen::WIDTH {
+            screen.columns[left + offset] |= to_screen_column(column, font.height());
+        }
+    }
+    glyph.columns().len()
+}
+
+/// Draw a short line of text onto a screen, with a blank column between characters.
+///
+/// Only about two characters fit on the screen; any beyond the right edge are ignored
+///   (see the marquee, for scrolling longer text).
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * font   - The font of the text.
+/// * text   - The text to draw.
+/// * left   - The x-index of the leftmost column of the text.
+pub fn draw_text<F: Font>(screen: &mut DotScreen, font: &F, text: &str, left: usize) {
+    let mut x = left;
+    for character in text.chars() {
+        if x >= DotScreen::WIDTH { break }
+        x += draw_character(screen, font, character, x) + 1;
+    }
+}
@@ -0,0 +1,46 @@
+/// A double-buffered screen, so that a frame drawn in several steps (e.g. cleared, and then
+///   redrawn) is only ever shown once it is complete.
+use crate::peripherals::{Display, DotScreen};
+
+
+/// A pair of screens: the back screen, which is drawn onto, and the front screen, the one
+///   last presented to the display.
+#[derive(Copy, Clone)]
+pub struct FrameBuffer {
+    /// The screen being drawn.
+    back: DotScreen,
+    /// The screen last presented.
+    front: DotScreen,
+}
+
+impl FrameBuffer {
+
+    /// Create a new FrameBuffer, with both of its screens empty.
+    pub const fn new() -> Self {
+        Self { back: DotScreen::new_empty(), front: DotScreen::new_empty() }
+    }
+
+    /// Returns the back screen, to draw onto.
+    pub fn back(&mut self) -> &mut DotScreen {
+        &mut self.back
+    }
+
+    /// Returns the front screen, the one last presented (e.g. to flash it as the game ends).
+    pub fn front(&self) -> &DotScreen {
+        &self.front
+    }
+
+    /// Make the back screen the front screen, without showing it (see `present`).
+    ///
+    /// The back screen keeps its dots, so the next frame may be drawn from them (moving
+    ///   a few dots), or from a cleared screen.
+    pub fn flip(&mut self) {
+        self.front = self.back;
+    }
+
+    /// Make the back screen the front screen (see `flip`), and show it on the display.
+    pub fn present(&mut self, display: &mut dyn Display) {
+        self.flip();
+        display.show(&self.front);
+    }
+}
@@ -0,0 +1,52 @@
+/// A marquee, which scrolls a line of text across the display from right to left.
+///
+/// The text streams onto the screen a column at a time, with a blank column between
+///   characters. Spaces (if the font has no glyph for them) are two blank columns,
+///   and other characters without a glyph are skipped. The glyphs are centered
+///   vertically on the screen.
+use crate::Components;
+use crate::peripherals::DotScreen;
+use super::{to_screen_column, Font, Glyph};
+
+
+/// Returns the columns of the screen which display a line of text, ordered left to right.
+///
+/// The columns end with a screen's width of blank columns, so that scrolling through
+///   every column leaves the text scrolled off the left edge of the screen.
+///
+/// # Arguments
+/// * font - The font of the text.
+/// * text - The text.
+pub fn columns<'a, F: Font>(font: &'a F, text: &'a str) -> impl Iterator<Item = u8> + 'a {
+    text.chars()
+        .filter_map(move |character| match font.glyph(character) {
+            Some(glyph) => { Some(glyph) },
+            None if character == ' ' => { Some(Glyph::new(&[0, 0])) },
+            None => { None },
+        })
+        .flat_map(|glyph| (0..glyph.columns().len()).map(move |x| glyph.columns()[x]).chain(core::iter::once(0)))
+        .map(move |column| to_screen_column(column, font.height()))
+        .chain(core::iter::repeat(0).take(DotScreen::WIDTH))
+}
+
+/// Scroll a line of text across the display, until it has scrolled off the left
+///   edge of the screen, or any JoyStick input is received.
+///
+/// # Arguments
+/// * components - The peripheral components.
+/// * font       - The font of the text.
+/// * text       - The text to scroll.
+/// * column_ms  - The time taken to scroll by a single column (the speed of the marquee).
+///
+/// # Returns
+/// Whether the marquee was interrupted by input.
+pub fn scroll<F: Font>(components: &mut Components, font: &F, text: &str, column_ms: usize) -> bool {
+    let mut screen = DotScreen::new_empty();
+    for column in columns(font, text) {
+        screen.shift_left(1, false);
+        screen.columns[DotScreen::WIDTH - 1] = column;
+        components.display.show(&screen);
+        if !components.analog.poll_joystick(column_ms).is_empty() { return true }
+    }
+    false
+}
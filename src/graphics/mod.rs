@@ -0,0 +1,39 @@
+/// Functionality for drawing text and sprites onto the LED Dot Display, and animating between screens.
+mod font;
+mod frame_buffer;
+mod marquee;
+mod shapes;
+mod sprite;
+mod transitions;
+
+pub use font::{draw_character, draw_text, to_screen_column, Font, Glyph, SmallFont};
+pub use frame_buffer::FrameBuffer;
+pub use marquee::{columns, scroll};
+pub use shapes::{draw_circle, Stamp};
+pub use sprite::{Animation, Bitmap, BlitMode, Sprite};
+pub use transitions::Transition;
+
+
+/// A buffer to format a number into, see `format_number`.
+///   A u32 has at most 10 digits.
+pub type NumberBuffer = [u8; 10];
+
+/// Format a number as text, in decimal.
+///
+/// # Arguments
+/// * number - The number to format.
+/// * buffer - The buffer to format the number into.
+///
+/// # Returns
+/// The text of the number (without leading zeros), borrowed from the buffer.
+pub fn format_number(number: u32, buffer: &mut NumberBuffer) -> &str {
+    let mut remaining = number;
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (remaining % 10) as u8;
+        remaining /= 10;
+        if (remaining == 0) | (start == 0) { break }
+    }
+    core::str::from_utf8(&buffer[start..]).unwrap_or("")
+}
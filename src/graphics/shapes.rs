@@ -0,0 +1,80 @@
+/// Drawing shapes onto a DotScreen: circles, and small stamps (e.g. for explosions,
+///   cursors, and game over icons).
+///
+/// The shapes may be drawn partly off the screen (e.g. around a dot on its edge), the dots
+///   off the screen being ignored.
+use crate::peripherals::{Dot, DotScreen};
+
+
+/// Light a dot of a screen, given by signed coordinates, if it is on the screen.
+fn plot(screen: &mut DotScreen, x: isize, y: isize) {
+    if (0..DotScreen::WIDTH as isize).contains(&x) & (0..DotScreen::HEIGHT as isize).contains(&y) {
+        screen.add(&Dot { x: x as usize, y: y as usize });
+    }
+}
+
+/// Draw the outline of a circle onto a screen, by the midpoint circle algorithm.
+///
+/// # Arguments
+/// * screen - The screen to draw onto.
+/// * center - The center of the circle.
+/// * radius - The radius of the circle, in dots (a radius of 0 is the center alone).
+pub fn draw_circle(screen: &mut DotScreen, center: &Dot, radius: usize) {
+    let (center_x, center_y) = (center.x as isize, center.y as isize);
+    let (mut x, mut y) = (radius as isize, 0);
+    // The error of the next dot from the true circle, scaled to remain an integer.
+    let mut error = 1 - x;
+    while x >= y {
+        // Each dot of the first octant is mirrored into the other seven.
+        for &(dx, dy) in [(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)].iter() {
+            plot(screen, center_x + dx, center_y + dy);
+        }
+        y += 1;
+        if error < 0 {
+            error += 2 * y + 1;
+        } else {
+            x -= 1;
+            error += 2 * (y - x) + 1;
+        }
+    }
+}
+
+
+/// A small shape, stamped around a dot.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Stamp {
+    /// A plus: the dot, and arms reaching up, down, left, and right.
+    Plus,
+    /// An X: the dot, and arms reaching along the diagonals.
+    Cross,
+    /// The outline of a diamond around the dot (the dots a number of steps away from it,
+    ///   stepping up, down, left, and right).
+    Diamond,
+}
+
+impl Stamp {
+
+    /// Draw the stamp onto a screen.
+    ///
+    /// # Arguments
+    /// * screen - The screen to draw onto.
+    /// * center - The dot the stamp is centered on.
+    /// * size   - The length of the arms (of a plus or an X), or the radius of the diamond.
+    pub fn draw(self, screen: &mut DotScreen, center: &Dot, size: usize) {
+        let (x, y) = (center.x as isize, center.y as isize);
+        let size = size as isize;
+        if self != Stamp::Diamond { plot(screen, x, y) }
+        for step in 1..=size {
+            // The dots of the diamond along each of its four sides.
+            let (along, across) = (step, size - step);
+            let offsets = match self {
+                Stamp::Plus => { [(step, 0), (-step, 0), (0, step), (0, -step)] },
+                Stamp::Cross => { [(step, step), (-step, step), (step, -step), (-step, -step)] },
+                Stamp::Diamond => { [(along, across), (-across, along), (-along, -across), (across, -along)] },
+            };
+            for &(dx, dy) in offsets.iter() {
+                plot(screen, x + dx, y + dy);
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+/// Sprites, small bitmaps drawn at a position on the screen, and their frame animations.
+use crate::peripherals::{Dot, DotScreen};
+
+
+/// A bitmap of a Sprite, as its columns ordered left to right.
+///
+/// Within each column, bit 0 is the row at the y-index of the Sprite's position,
+///   and each higher bit is the row above (the next higher y-index).
+pub type Bitmap = &'static [u8];
+
+
+/// The way the dots of a Sprite are combined with the dots of the screen it is drawn onto.
+///   The dots of the screen outside of the Sprite's bitmap (its transparent dots) are left as they are.
+#[derive(Copy, Clone, PartialEq)]
+pub enum BlitMode {
+    /// The dots of the Sprite are lit (OR).
+    Or,
+    /// The dots of the Sprite are unlit (AND-NOT), erasing it.
+    AndNot,
+    /// The dots of the Sprite are toggled (XOR), so that drawing it twice leaves the screen as it was.
+    Xor,
+}
+
+
+/// A Sprite: a bitmap drawn at a position on the screen.
+///
+/// The position is that of the bottom left corner of the bitmap (the lowest x-index
+///   and y-index). It may lie off the screen, so that a Sprite can enter and leave the
+///   screen gradually; any dots off the screen are not drawn.
+#[derive(Copy, Clone)]
+pub struct Sprite {
+    /// The bitmap of the Sprite.
+    pub bitmap: Bitmap,
+    /// The height of the bitmap (at most 8).
+    pub height: usize,
+    /// The x-index of the left column of the Sprite.
+    pub x: isize,
+    /// The y-index of the bottom row of the Sprite.
+    pub y: isize,
+}
+
+impl Sprite {
+
+    /// Create a new Sprite.
+    ///
+    /// # Arguments
+    /// * bitmap - The bitmap of the Sprite.
+    /// * height - The height of the bitmap.
+    /// * x      - The x-index of the left column of the Sprite.
+    /// * y      - The y-index of the bottom row of the Sprite.
+    pub const fn new(bitmap: Bitmap, height: usize, x: isize, y: isize) -> Self {
+        Self { bitmap, height, x, y }
+    }
+
+    /// Returns the width of the Sprite.
+    pub fn width(&self) -> usize {
+        self.bitmap.len()
+    }
+
+    /// Move the Sprite by an offset.
+    pub fn move_by(&mut self, dx: isize, dy: isize) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    /// Returns the dots of the Sprite which lie on the screen.
+    pub fn dots(&self) -> impl Iterator<Item = Dot> + '_ {
+        self.bitmap.iter().enumerate().flat_map(move |(column_index, &column)| {
+            (0..self.height)
+                .filter(move |&row| column & (1 << row) != 0)
+                .map(move |row| (self.x + column_index as isize, self.y + row as isize))
+                .filter(|&(x, y)| {
+                    (x >= 0) & (x < DotScreen::WIDTH as isize) & (y >= 0) & (y < DotScreen::HEIGHT as isize)
+                })
+                .map(|(x, y)| Dot { x: x as usize, y: y as usize })
+        })
+    }
+
+    /// Draw the Sprite onto a screen, combining its dots with those of the screen.
+    ///
+    /// # Arguments
+    /// * screen - The screen to draw onto.
+    /// * mode   - How the dots of the Sprite are combined with those of the screen.
+    pub fn draw(&self, screen: &mut DotScreen, mode: BlitMode) {
+        for dot in self.dots() {
+            match mode {
+                BlitMode::Or => { screen.add(&dot) },
+                BlitMode::AndNot => { screen.remove(&dot) },
+                BlitMode::Xor => { screen.toggle(&dot) },
+            }
+        }
+    }
+
+    /// Draw the Sprite onto a screen, lighting its dots.
+    pub fn blit(&self, screen: &mut DotScreen) {
+        self.draw(screen, BlitMode::Or);
+    }
+
+    /// Erase the Sprite from a screen, unlighting its dots.
+    pub fn erase(&self, screen: &mut DotScreen) {
+        self.draw(screen, BlitMode::AndNot);
+    }
+
+    /// Toggle the dots of the Sprite on a screen, e.g. to draw a cursor over the screen which
+    ///   is erased by toggling it again.
+    pub fn toggle(&self, screen: &mut DotScreen) {
+        self.draw(screen, BlitMode::Xor);
+    }
+
+    /// Returns whether any dot of the Sprite is lit on a screen (e.g. for collisions).
+    pub fn overlaps(&self, screen: &DotScreen) -> bool {
+        self.dots().any(|dot| screen.is_dot_on(&dot))
+    }
+}
+
+
+/// A frame animation, which cycles through a sequence of bitmaps on a timer of game ticks.
+#[derive(Copy, Clone)]
+pub struct Animation {
+    /// The frames of the animation.
+    frames: &'static [Bitmap],
+    /// The number of game ticks each frame is shown for.
+    frame_ticks: usize,
+    /// Whether the animation starts over after the last frame, rather than finishing.
+    looping: bool,
+    /// The number of game ticks elapsed since the animation started.
+    tick: usize,
+}
+
+impl Animation {
+
+    /// Create a new Animation, starting at the first frame.
+    ///
+    /// # Arguments
+    /// * frames      - The frames of the animation (at least one).
+    /// * frame_ticks - The number of game ticks each frame is shown for (at least one).
+    /// * looping     - Whether the animation starts over after the last frame.
+    pub const fn new(frames: &'static [Bitmap], frame_ticks: usize, looping: bool) -> Self {
+        Self { frames, frame_ticks, looping, tick: 0 }
+    }
+
+    /// Advance the animation by a single game tick.
+    pub fn tick(&mut self) {
+        if !self.is_finished() { self.tick += 1 }
+    }
+
+    /// Start the animation over from the first frame.
+    pub fn restart(&mut self) {
+        self.tick = 0;
+    }
+
+    /// Returns whether the animation has finished (a looping animation never finishes).
+    pub fn is_finished(&self) -> bool {
+        !self.looping & (self.tick >= self.frames.len() * self.frame_ticks)
+    }
+
+    /// Returns the current frame. A finished animation remains on its last frame.
+    pub fn frame(&self) -> Bitmap {
+        let index = (self.tick / self.frame_ticks) % self.frames.len();
+        if self.is_finished() { self.frames[self.frames.len() - 1] } else { self.frames[index] }
+    }
+}
@@ -0,0 +1,107 @@
+/// Transition effects, which animate the display from one screen to another.
+///
+/// Every transition reveals the dots of the new screen in its own order, a fixed number
+///   of dots per frame, so every transition takes the same number of frames.
+use crate::peripherals::{Dot, Display, DotScreen};
+
+
+/// A transition effect between two screens.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Transition {
+    /// The new screen slides over the old one from the right edge.
+    WipeLeft,
+    /// The new screen slides over the old one from the left edge.
+    WipeRight,
+    /// The dots of the new screen appear in a scattered order.
+    Dissolve,
+    /// The new screen closes over the old one from both edges, meeting in the middle.
+    Curtain,
+    /// The new screen spirals in over the old one, from a corner to the middle.
+    Spiral,
+}
+
+impl Transition {
+
+    /// The number of frames of every transition.
+    pub const FRAMES: usize = 8;
+
+    /// The number of dots revealed each frame.
+    const DOTS_PER_FRAME: usize = DotScreen::TOTAL_DOTS / Self::FRAMES;
+
+    /// Play the transition on the display, ending with the new screen displayed.
+    ///
+    /// # Arguments
+    /// * display  - The display.
+    /// * from     - The screen currently displayed.
+    /// * to       - The screen to transition to.
+    /// * frame_ms - The duration of each frame. The transition takes `FRAMES` times this.
+    pub fn play(&self, display: &mut dyn Display, from: &DotScreen, to: &DotScreen, frame_ms: u16) {
+        let mut revealed = DotScreen::new_empty();
+        for frame in 0..Self::FRAMES {
+            for index in (frame * Self::DOTS_PER_FRAME)..((frame + 1) * Self::DOTS_PER_FRAME) {
+                revealed.add(&self.nth_dot(index));
+            }
+            let mut screen = DotScreen::new_empty();
+            for x in 0..DotScreen::WIDTH {
+                screen.columns[x] = (from.columns[x] & !revealed.columns[x]) | (to.columns[x] & revealed.columns[x]);
+            }
+            display.show(&screen);
+            arduino_uno::delay_ms(frame_ms);
+        }
+    }
+
+    /// Returns the dot revealed at the index of the order of the transition.
+    ///
+    /// # Arguments
+    /// * index - The index of the dot within the order, less than `DotScreen::TOTAL_DOTS`.
+    fn nth_dot(&self, index: usize) -> Dot {
+        let (column, row) = (index / DotScreen::HEIGHT, index % DotScreen::HEIGHT);
+        match self {
+            Transition::WipeLeft => { Dot { x: DotScreen::WIDTH - 1 - column, y: row } },
+            Transition::WipeRight => { Dot { x: column, y: row } },
+            Transition::Dissolve => {
+                // Multiplying by a number coprime to the number of dots shuffles every dot exactly once.
+                let shuffled = (index * 37 + 11) % DotScreen::TOTAL_DOTS;
+                Dot { x: shuffled / DotScreen::HEIGHT, y: shuffled % DotScreen::HEIGHT }
+            },
+            Transition::Curtain => {
+                // The columns are revealed in pairs, one from each edge.
+                let pair = column / 2;
+                let x = if column % 2 == 0 { pair } else { DotScreen::WIDTH - 1 - pair };
+                Dot { x, y: row }
+            },
+            Transition::Spiral => { spiral_dot(index) },
+        }
+    }
+}
+
+
+/// Returns the dot at the index of a spiral, from a corner of the screen to the middle.
+///
+/// The spiral runs around each ring of the screen in turn: along the row of the largest
+///   y-index, along the right column, back along the row of the smallest y-index, and
+///   back along the left column.
+fn spiral_dot(index: usize) -> Dot {
+    let (mut left, mut right) = (0, DotScreen::WIDTH - 1);
+    let (mut bottom, mut top) = (0, DotScreen::HEIGHT - 1);
+    let mut remaining = index;
+    loop {
+        let (width, height) = (right - left + 1, top - bottom + 1);
+        let ring = 2 * (width + height) - 4;
+        if remaining < ring { break }
+        remaining -= ring;
+        left += 1;
+        right -= 1;
+        bottom += 1;
+        top -= 1;
+    }
+
+    let (width, height) = (right - left + 1, top - bottom + 1);
+    if remaining < width { return Dot { x: left + remaining, y: top } }
+    remaining -= width;
+    if remaining < height - 1 { return Dot { x: right, y: top - 1 - remaining } }
+    remaining -= height - 1;
+    if remaining < width - 1 { return Dot { x: right - 1 - remaining, y: bottom } }
+    remaining -= width - 1;
+    Dot { x: left, y: bottom + 1 + remaining }
+}
@@ -0,0 +1,133 @@
+/// The best score of each game, persisted to the EEPROM.
+///
+/// The high scores are stored as a record directly after the settings, holding a slot
+///   for each game: the identifier of the game, and its best score (little endian).
+///   An identifier of 0 marks an unused slot. If the EEPROM holds no valid record
+///   (e.g. it has never been written), there are no high scores.
+///
+/// The date each high score was set, if there is a real-time clock (see `Rtc`), is stored
+///   as a separate record at the top of the EEPROM, so that the records below keep their
+///   addresses. It holds the packed date (see `Date::to_packed`) of each slot of the high
+///   scores, in the same order, with 0 marking a score set without a date.
+use crate::Settings;
+use crate::peripherals::{eeprom, Date, DotScreen};
+
+/// The magic byte identifying the record of the high scores.
+const MAGIC: u8 = 0x48;
+/// The address of the EEPROM at which the high scores are stored.
+pub const ADDRESS: usize = Settings::ADDRESS + Settings::STORED_SIZE;
+/// The number of games which may store a high score.
+const SLOTS: usize = 32;
+/// The number of bytes of each slot.
+const SLOT_SIZE: usize = 3;
+/// The number of bytes the high scores occupy in the EEPROM.
+pub const STORED_SIZE: usize = SLOTS * SLOT_SIZE + eeprom::RECORD_OVERHEAD;
+/// The identifier of an unused slot.
+const UNUSED: u8 = 0;
+/// The magic byte identifying the record of the dates of the high scores.
+const DATES_MAGIC: u8 = 0x44;
+/// The number of bytes of the date of each slot.
+const DATE_SIZE: usize = 2;
+/// The address of the EEPROM at which the dates of the high scores are stored.
+const DATES_ADDRESS: usize = eeprom::SIZE - (SLOTS * DATE_SIZE + eeprom::RECORD_OVERHEAD);
+
+
+/// The screen shown when a new high score is set (a trophy).
+pub static NEW_HIGH_SCORE_SCREEN: DotScreen =
+    DotScreen::new(
+        [
+            0b01100000,
+            0b10010000,
+            0b11111001,
+            0b11111111,
+            0b11111111,
+            0b11111001,
+            0b10010000,
+            0b01100000,
+        ]
+    );
+
+
+/// The slots of the high scores, as stored in the EEPROM.
+type Slots = [u8; SLOTS * SLOT_SIZE];
+
+/// Load the slots from the EEPROM, which are all unused if none are stored.
+fn load() -> Slots {
+    let mut slots = [UNUSED; SLOTS * SLOT_SIZE];
+    if !eeprom::read_record(ADDRESS, MAGIC, &mut slots) {
+        slots = [UNUSED; SLOTS * SLOT_SIZE];
+    }
+    slots
+}
+
+/// The dates of the slots, as stored in the EEPROM.
+type Dates = [u8; SLOTS * DATE_SIZE];
+
+/// Load the dates of the slots from the EEPROM, which are all 0 (no date) if none are stored.
+fn load_dates() -> Dates {
+    let mut dates = [0; SLOTS * DATE_SIZE];
+    if !eeprom::read_record(DATES_ADDRESS, DATES_MAGIC, &mut dates) {
+        dates = [0; SLOTS * DATE_SIZE];
+    }
+    dates
+}
+
+/// Returns the slot of the game, if it has one.
+fn find(slots: &Slots, game_id: u8) -> Option<usize> {
+    slots.chunks(SLOT_SIZE).position(|slot| slot[0] == game_id)
+}
+
+/// Returns the score stored in a slot.
+fn score_of(slots: &Slots, index: usize) -> u16 {
+    u16::from_le_bytes([slots[index * SLOT_SIZE + 1], slots[index * SLOT_SIZE + 2]])
+}
+
+/// Returns the best score of the game, if one has been stored.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+pub fn best(game_id: u8) -> Option<u16> {
+    let slots = load();
+    find(&slots, game_id).map(|index| score_of(&slots, index))
+}
+
+/// Returns the date the best score of the game was set, if it is known.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+pub fn best_date(game_id: u8) -> Option<Date> {
+    let index = find(&load(), game_id)?;
+    let dates = load_dates();
+    Date::from_packed(u16::from_le_bytes([dates[index * DATE_SIZE], dates[index * DATE_SIZE + 1]]))
+}
+
+/// Record the score of a finished game, storing it if it is the best score of the game.
+///
+/// If every slot is in use by other games, the score is not stored.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+/// * score   - The final score.
+/// * date    - The date the game was played, if it is known.
+///
+/// # Returns
+/// Whether the score is a new high score (a score of 0 never is).
+pub fn record(game_id: u8, score: u16, date: Option<Date>) -> bool {
+    let mut slots = load();
+    let best = find(&slots, game_id).map(|index| score_of(&slots, index)).unwrap_or(0);
+    if score <= best { return false }
+
+    let index = match find(&slots, game_id).or_else(|| find(&slots, UNUSED)) {
+        Some(index) => index,
+        None => { return false },
+    };
+    let [low, high] = score.to_le_bytes();
+    slots[(index * SLOT_SIZE)..((index + 1) * SLOT_SIZE)].copy_from_slice(&[game_id, low, high]);
+    eeprom::write_record(ADDRESS, MAGIC, &slots);
+
+    let mut dates = load_dates();
+    let packed = date.map_or(0, |date| date.to_packed());
+    dates[(index * DATE_SIZE)..((index + 1) * DATE_SIZE)].copy_from_slice(&packed.to_le_bytes());
+    eeprom::write_record(DATES_ADDRESS, DATES_MAGIC, &dates);
+    true
+}
@@ -0,0 +1,96 @@
+/// Optional broadcasting of scores over the serial connection, to a leaderboard
+///   daemon running on an attached host.
+///
+/// The protocol is line based ASCII, over the serial connection (see `BAUD_RATE`):
+///   * Host to device: `?`
+///       The handshake. A listening host sends this byte periodically (about once a second).
+///   * Device to host: `!DG1`
+///       The acknowledgement of the handshake, including the protocol version.
+///   * Device to host: `#<game id>,<score>,<difficulty>,<initials>`
+///       The score report, sent at game over. For example `#S,12,1,ABC`.
+///
+/// Scores are only transmitted if a handshake has been received since the previous
+///   report, so that nothing is written to the serial connection without a listener.
+use arduino_uno::prelude::*;
+
+/// The protocol version, sent as part of the handshake acknowledgement.
+const PROTOCOL_VERSION: u8 = 1;
+/// The byte sent by the host to signal that it is listening.
+const HANDSHAKE: u8 = b'?';
+/// The initials reported when the player has not entered any.
+pub const NO_INITIALS: [u8; 3] = *b"???";
+
+
+/// The score of a finished game, to be reported to the leaderboard.
+pub struct ScoreReport {
+    /// The identifier of the game, a letter (usually the letter of its title screen).
+    pub game_id: u8,
+    /// The final score.
+    pub score: u16,
+    /// The difficulty level the game was played at.
+    pub difficulty: u8,
+    /// The initials of the player.
+    pub initials: [u8; 3],
+}
+
+
+/// Broadcasts scores to the leaderboard daemon, if one is listening.
+pub struct Leaderboard {
+    /// Whether a handshake has been received since the previous report.
+    listener_present: bool,
+}
+
+impl Leaderboard {
+
+    /// Create a new Leaderboard object. No listener is assumed until a handshake is received.
+    pub const fn new() -> Self {
+        Self { listener_present: false }
+    }
+
+    /// Process any bytes received on the serial connection, acknowledging any handshake.
+    ///
+    /// # Arguments
+    /// * serial - The serial connection.
+    pub fn poll_handshake(&mut self, serial: &mut crate::components::SerialPort) {
+        while let Some(byte) = crate::peripherals::usart::read_byte() {
+            self.receive(serial, byte);
+        }
+    }
+
+    /// Process a byte received on the serial connection (e.g. by the console, which reads
+    ///   the bytes meanwhile), acknowledging it if it is the handshake.
+    ///
+    /// # Arguments
+    /// * serial - The serial connection.
+    /// * byte   - The byte received.
+    ///
+    /// # Returns
+    /// Whether the byte was the handshake.
+    pub fn receive(&mut self, serial: &mut crate::components::SerialPort, byte: u8) -> bool {
+        if byte != HANDSHAKE { return false }
+        self.listener_present = true;
+        ufmt::uwriteln!(serial, "!DG{}\r", PROTOCOL_VERSION).void_unwrap();
+        true
+    }
+
+    /// Report the score of a finished game, if a listener is present.
+    ///
+    /// # Arguments
+    /// * serial - The serial connection.
+    /// * report - The score to report.
+    ///
+    /// # Returns
+    /// Whether the score was transmitted.
+    pub fn report(&mut self, serial: &mut crate::components::SerialPort, report: &ScoreReport) -> bool {
+        self.poll_handshake(serial);
+        if !self.listener_present { return false }
+        self.listener_present = false;
+
+        let initials = core::str::from_utf8(&report.initials).unwrap_or("???");
+        ufmt::uwriteln!(
+            serial, "#{},{},{},{}\r",
+            report.game_id as char, report.score, report.difficulty, initials
+        ).void_unwrap();
+        true
+    }
+}
@@ -1,8 +1,19 @@
 #![no_std]
+#![cfg_attr(target_arch = "avr", feature(llvm_asm))]
 pub mod peripherals;
 pub mod games;
 mod common;
 mod components;
+mod console;
+pub mod cues;
+pub mod graphics;
+pub mod high_scores;
+mod leaderboard;
+mod settings;
+pub mod sound;
+pub mod stats;
 
 pub use common::Direction;
 pub use components::{Components, get_components};
+pub use leaderboard::{Leaderboard, ScoreReport};
+pub use settings::Settings;
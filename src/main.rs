@@ -1,16 +1,38 @@
 #![no_std]
 #![no_main]
 extern crate panic_halt;
-use dot_games::games::SelectionScreen;
+use dot_games::games::{choose_difficulty, configure_game, Difficulty, GameSwitch, SelectionScreen};
 
 
 #[arduino_uno::entry]
 fn main() -> ! {
     let mut components = dot_games::get_components();
+    let mut selection_screen = SelectionScreen::new();
+    let mut switch = None;
 
-    // Run the Selection Screen.
-    let selected_game_loop = SelectionScreen::new().run(&mut components);
+    loop {
+        let game = match switch {
+            // Switch directly to the game requested by the game last played, at the difficulty last chosen.
+            Some(GameSwitch { index, difficulty }) => {
+                let game = selection_screen.switch_to(&mut components, index);
+                if game.offers_difficulty() { configure_game(game, difficulty) }
+                game
+            },
+            None => {
+                // Run the Selection Screen.
+                let game = selection_screen.run(&mut components);
 
-    // Run the Game Loop.
-    selected_game_loop(components)
+                // Let the player choose the difficulty, if the game offers a choice.
+                if game.offers_difficulty() {
+                    let default = Difficulty::from_level(components.settings.difficulty);
+                    let difficulty = choose_difficulty(&mut components, default, game.on_menu_move());
+                    configure_game(game, difficulty);
+                }
+                game
+            },
+        };
+
+        // Run the selected Game, until the player quits it (or switches directly to another).
+        switch = game.run(&mut components);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,152 @@
+/// Functionality for reading the tilt of the board from an I2C accelerometer
+///   (an ADXL345 or an MPU6050), so that tilting it can stand in for the JoyStick.
+///
+/// The accelerometer is connected to the I2C bus (see `i2c`), on A4 (SDA) and A5 (SCL).
+///   These are also the pins of the second JoyStick's y-axis and of the RNG, so the
+///   accelerometer replaces them.
+use arduino_uno::adc::Adc;
+
+use super::{I2c, I2cError, InputDevice, InputSignal, JoyStickSignal};
+
+// The acceleration of 1g, in the units of the readings.
+const ONE_G: i16 = 256;
+// The total acceleration of the three axes beyond which the board is being shaken (2.5g).
+const SHAKE: i16 = 5 * ONE_G / 2;
+
+
+/// The accelerometer models which are supported.
+#[derive(Copy, Clone, PartialEq)]
+pub enum AccelerometerModel {
+    /// The ADXL345, at I2C address 0x53.
+    Adxl345,
+    /// The MPU6050 (whose gyroscope is unused), at I2C address 0x68.
+    Mpu6050,
+}
+
+impl AccelerometerModel {
+
+    /// Returns the I2C address of the accelerometer.
+    fn address(self) -> u8 {
+        match self {
+            AccelerometerModel::Adxl345 => { 0x53 },
+            AccelerometerModel::Mpu6050 => { 0x68 },
+        }
+    }
+
+    /// Returns the (register, value) writes which start the accelerometer measuring.
+    fn setup(self) -> &'static [(u8, u8)] {
+        match self {
+            // DATA_FORMAT: +-2g, 10 bits (256 per g). POWER_CTL: measure.
+            AccelerometerModel::Adxl345 => { &[(0x31, 0x00), (0x2D, 0x08)] },
+            // PWR_MGMT_1: wake from sleep. ACCEL_CONFIG: +-2g (16384 per g).
+            AccelerometerModel::Mpu6050 => { &[(0x6B, 0x00), (0x1C, 0x00)] },
+        }
+    }
+
+    /// Returns the register of the first byte of the readings of the three axes.
+    fn data_register(self) -> u8 {
+        match self {
+            AccelerometerModel::Adxl345 => { 0x32 },
+            AccelerometerModel::Mpu6050 => { 0x3B },
+        }
+    }
+
+    /// Convert the bytes of the readings into the acceleration of each axis (x, y, z),
+    ///   in units of 1/256g.
+    fn to_axes(self, data: &[u8; 6]) -> [i16; 3] {
+        let mut axes = [0i16; 3];
+        for (axis, bytes) in axes.iter_mut().zip(data.chunks(2)) {
+            *axis = match self {
+                // Little endian, already 256 per g.
+                AccelerometerModel::Adxl345 => { i16::from_le_bytes([bytes[0], bytes[1]]) },
+                // Big endian, 16384 per g.
+                AccelerometerModel::Mpu6050 => { i16::from_be_bytes([bytes[0], bytes[1]]) / 64 },
+            };
+        }
+        axes
+    }
+}
+
+
+/// Object describing the tilt of the board.
+#[derive(Copy, Clone)]
+pub struct TiltSignal {
+    // Signed 8-bit integer where negative values indicate a tilt Left
+    //   and positive values indicate a tilt Right. Full magnitude is a tilt of 30 degrees.
+    pub horiz: i8,
+    // Signed 8-bit integer where negative values indicate a tilt Down (toward the player)
+    //   and positive values indicate a tilt Up (away from the player).
+    pub vert: i8,
+    // Boolean indicating if the board is being shaken (e.g. a gesture to restart a game).
+    pub shake: bool,
+}
+
+impl TiltSignal {
+
+    /// Convert the TiltSignal into the JoyStickSignal it stands for, tilting the JoyStick.
+    ///   Shaking isn't the button, so it doesn't pause or select anything.
+    pub fn to_joystick(self) -> JoyStickSignal {
+        JoyStickSignal { horiz: self.horiz, vert: self.vert, button: false }
+    }
+}
+
+
+/// Object that interfaces with an I2C accelerometer, reading the tilt of the board.
+pub struct Accelerometer {
+    // The I2C bus the accelerometer is on.
+    bus: I2c,
+    // The model of the accelerometer.
+    model: AccelerometerModel,
+}
+
+impl Accelerometer {
+
+    /// Creates a new Accelerometer object, starting the accelerometer measuring.
+    ///
+    /// # Arguments
+    /// * bus   - The I2C bus the accelerometer is on (see `I2c.share`).
+    /// * model - The model of the accelerometer.
+    ///
+    /// # Returns
+    /// The Accelerometer, or the error of the bus if the accelerometer doesn't respond.
+    pub fn new(mut bus: I2c, model: AccelerometerModel) -> Result<Self, I2cError> {
+        for &(register, value) in model.setup().iter() {
+            bus.write_register(model.address(), register, value)?;
+        }
+        Ok(Self { bus, model })
+    }
+
+    /// Returns the acceleration of each axis (x, y, z), in units of 1/256g.
+    pub fn read_axes(&mut self) -> Result<[i16; 3], I2cError> {
+        let mut data = [0u8; 6];
+        self.bus.read_registers(self.model.address(), self.model.data_register(), &mut data)?;
+        Ok(self.model.to_axes(&data))
+    }
+}
+
+
+impl InputDevice for Accelerometer {
+
+    /// Read the input data from the Accelerometer Peripheral.
+    ///
+    /// The board tilted by more than the JoyStick threshold (about 12 degrees) in any
+    ///   direction, or being shaken, is input. A failed read of the bus is no input.
+    /// 
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the accelerometer is read over I2C).
+    /// 
+    /// # Returns
+    /// Option<InputSignal::Tilt>
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let [x, y, z] = self.read_axes().ok()?;
+        let clamp = |axis: i16| axis.max(-(i8::MAX as i16)).min(i8::MAX as i16) as i8;
+        let signal = TiltSignal {
+            horiz: clamp(x),
+            vert: clamp(y),
+            shake: (x.abs() + y.abs() + z.abs()) > SHAKE,
+        };
+        let threshold = super::joystick_ps2::THRESHOLD;
+        let tilted = (signal.horiz.abs() > threshold) | (signal.vert.abs() > threshold);
+        if tilted | signal.shake { Some(InputSignal::Tilt(signal)) } else { None }
+    }
+}
@@ -0,0 +1,56 @@
+/// Functionality for reading a simple momentary push-button on a digital pin.
+use arduino_uno::prelude::*;
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{Pin, mode::{Input, PullUp}};
+
+use super::{clock, InputDevice, InputSignal};
+
+/// The time a reading of the button must stay the same before it is believed (debouncing).
+const DEBOUNCE_MS: u32 = 20;
+
+
+/// Object that interfaces with a momentary push-button.
+///
+/// The button connects its pin to ground when pressed, the pin being pulled up otherwise.
+///   The contacts of a button bounce as it is pressed and released, so a change of the
+///   reading only takes effect once the reading has been steady for `DEBOUNCE_MS`.
+pub struct Button {
+    // Digital pin that reads button presses.
+    pin: Pin<Input<PullUp>>,
+    // Whether the button is pressed, once debounced.
+    pressed: bool,
+    // The latest reading of the pin (whether the button is pressed).
+    reading: bool,
+    // The time, as returned by `clock::millis`, at which the reading last changed.
+    reading_ms: u32,
+}
+
+impl Button {
+
+    /// Creates a new Button object.
+    pub fn new(pin: Pin<Input<PullUp>>) -> Self {
+        Button { pin, pressed: false, reading: false, reading_ms: clock::millis() }
+    }
+}
+
+
+impl InputDevice for Button {
+
+    /// Read the input data from the Button Peripheral.
+    /// 
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the button is digital).
+    /// 
+    /// # Returns
+    /// Some(InputSignal::Button) while the button is pressed.
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let reading = self.pin.is_low().void_unwrap();
+        if reading != self.reading {
+            self.reading = reading;
+            self.reading_ms = clock::millis();
+        } else if clock::elapsed_since(self.reading_ms) >= DEBOUNCE_MS {
+            self.pressed = reading;
+        }
+        if self.pressed { Some(InputSignal::Button) } else { None }
+    }
+}
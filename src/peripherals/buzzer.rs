@@ -0,0 +1,203 @@
+/// Functionality for playing tones on a piezo buzzer, driven entirely by Timer1.
+///
+/// The buzzer is connected to the OC1A pin (D9). Timer1 runs in CTC mode,
+///   toggling OC1A in hardware on every compare match to produce the square wave
+///   of the note, so no code runs to generate the tone itself. The compare match
+///   interrupt only counts down the duration of the current note and, once it
+///   has elapsed, starts the next note from the queue. Playing a sound therefore
+///   costs the main loop nothing beyond queueing the notes. The timing of the notes
+///   is worked out by the `note_timing` crate.
+use core::cell::RefCell;
+use avr_device::interrupt::{self, Mutex};
+use arduino_uno::pac::{TC1, tc1::RegisterBlock};
+use arduino_uno::hal::port::{mode::Output, portb::PB1};
+use note_timing::{compare_value, duration_matches, match_hz};
+
+/// The number of notes which can be waiting in the queue.
+const QUEUE_CAPACITY: usize = 16;
+
+
+/// A note to be played by the Buzzer.
+#[derive(Copy, Clone)]
+pub struct Note {
+    /// The frequency of the note in Hertz. A frequency of 0 is a rest (silence).
+    pub frequency_hz: u16,
+    /// The duration of the note in milliseconds.
+    pub duration_ms: u16,
+}
+
+impl Note {
+
+    /// Create a new Note.
+    pub const fn new(frequency_hz: u16, duration_ms: u16) -> Self {
+        Self { frequency_hz, duration_ms }
+    }
+
+    /// Create a new rest, a period of silence.
+    pub const fn rest(duration_ms: u16) -> Self {
+        Self { frequency_hz: 0, duration_ms }
+    }
+}
+
+
+/// Fixed capacity ring buffer of the notes waiting to be played.
+struct NoteQueue {
+    notes: [Note; QUEUE_CAPACITY],
+    // The index of the next note to be played.
+    head: usize,
+    // The number of notes in the queue.
+    len: usize,
+}
+
+impl NoteQueue {
+    const fn new() -> Self {
+        Self { notes: [Note::rest(0); QUEUE_CAPACITY], head: 0, len: 0 }
+    }
+
+    /// Add a note to the back of the queue, returning whether there was room.
+    fn push(&mut self, note: Note) -> bool {
+        if self.len == QUEUE_CAPACITY { return false }
+        self.notes[(self.head + self.len) % QUEUE_CAPACITY] = note;
+        self.len += 1;
+        true
+    }
+
+    /// Remove the note at the front of the queue.
+    fn pop(&mut self) -> Option<Note> {
+        if self.len == 0 { return None }
+        let note = self.notes[self.head];
+        self.head = (self.head + 1) % QUEUE_CAPACITY;
+        self.len -= 1;
+        Some(note)
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+
+/// The playback state, shared between the Buzzer and the Timer1 interrupt.
+struct Playback {
+    queue: NoteQueue,
+    /// The number of compare matches remaining until the current note is over.
+    remaining_matches: u32,
+    /// Whether a note is currently being played.
+    playing: bool,
+}
+
+static PLAYBACK: Mutex<RefCell<Playback>> = Mutex::new(RefCell::new(
+    Playback { queue: NoteQueue::new(), remaining_matches: 0, playing: false }
+));
+
+
+/// Program Timer1 to play the note.
+fn start_note(tc1: &RegisterBlock, playback: &mut Playback, note: Note) {
+    let match_hz = match_hz(note.frequency_hz);
+    playback.remaining_matches = duration_matches(match_hz, note.duration_ms);
+    playback.playing = true;
+
+    tc1.ocr1a.write(|w| unsafe { w.bits(compare_value(match_hz)) });
+    tc1.tcnt1.write(|w| unsafe { w.bits(0) });
+    tc1.tccr1a.write(|w|
+        if note.frequency_hz == 0 { w.com1a().disconnected() } else { w.com1a().match_toggle() }
+    );
+    tc1.tccr1b.write(|w| unsafe { w.wgm1().bits(0b01) }.cs1().prescale_8());
+}
+
+/// Stop Timer1, silencing the buzzer.
+fn stop(tc1: &RegisterBlock, playback: &mut Playback) {
+    playback.remaining_matches = 0;
+    playback.playing = false;
+    tc1.tccr1a.write(|w| w.com1a().disconnected());
+    tc1.tccr1b.write(|w| unsafe { w.wgm1().bits(0b01) }.cs1().no_clock());
+}
+
+/// The Timer1 compare match interrupt, which sequences the notes of the queue.
+#[avr_device::interrupt(atmega328p)]
+fn TIMER1_COMPA() {
+    interrupt::free(|cs| {
+        let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+        if playback.remaining_matches > 0 {
+            playback.remaining_matches -= 1;
+            return
+        }
+        let tc1 = unsafe { &*TC1::ptr() };
+        match playback.queue.pop() {
+            Some(note) => { start_note(tc1, &mut playback, note) },
+            None => { stop(tc1, &mut playback) },
+        }
+    })
+}
+
+
+/// The object that interfaces with the piezo buzzer peripheral.
+pub struct Buzzer {
+    // Timer1, which generates the tones.
+    tc1: TC1,
+    // The OC1A pin the buzzer is connected to. This must be an output for the
+    //   tone to be produced.
+    _pin: PB1<Output>,
+}
+
+impl Buzzer {
+
+    /// Create a new Buzzer object.
+    ///
+    /// # Arguments
+    ///
+    /// * `tc1` - Timer1, which is dedicated to generating the tones.
+    /// * `pin` - The OC1A pin (D9), which the buzzer is connected to.
+    pub fn new(tc1: TC1, pin: PB1<Output>) -> Self {
+        interrupt::free(|cs| stop(&tc1, &mut PLAYBACK.borrow(cs).borrow_mut()));
+        tc1.timsk1.write(|w| w.ocie1a().set_bit());
+        Self { tc1, _pin: pin }
+    }
+
+    /// Queue a note to be played.
+    ///
+    /// If no note is currently being played, the note begins immediately.
+    ///
+    /// # Returns
+    /// Whether the note was queued. If the queue is full, the note is dropped.
+    pub fn play(&mut self, note: Note) -> bool {
+        let tc1 = &self.tc1;
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            if playback.playing {
+                playback.queue.push(note)
+            } else {
+                start_note(tc1, &mut playback, note);
+                true
+            }
+        })
+    }
+
+    /// Queue a sequence of notes to be played.
+    ///
+    /// # Returns
+    /// The number of notes which were queued.
+    pub fn play_all(&mut self, notes: &[Note]) -> usize {
+        notes.iter().take_while(|&&note| self.play(note)).count()
+    }
+
+    /// Stop the current note, and clear the queue.
+    pub fn stop(&mut self) {
+        let tc1 = &self.tc1;
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            playback.queue.clear();
+            stop(tc1, &mut playback);
+        })
+    }
+
+    /// Returns the number of notes waiting in the queue, after the current note.
+    pub fn queued(&self) -> usize {
+        interrupt::free(|cs| PLAYBACK.borrow(cs).borrow().queue.len)
+    }
+
+    /// Returns whether a note is currently being played.
+    pub fn is_playing(&self) -> bool {
+        interrupt::free(|cs| PLAYBACK.borrow(cs).borrow().playing)
+    }
+}
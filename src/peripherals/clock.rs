@@ -0,0 +1,139 @@
+/// Functionality for keeping time, counting milliseconds with Timer0.
+use avr_device::atmega328p::TC0;
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
+
+// Timer0 counts at 16MHz / 64 = 250kHz, so matches every 250 counts once a millisecond.
+const COUNTS_PER_MS: u8 = 250;
+// The number of microseconds of each count of Timer0.
+const US_PER_COUNT: u32 = 4;
+// The Output Compare Flag A bit of the TIFR0 register.
+const OCF0A: u8 = 1 << 1;
+
+/// The number of milliseconds elapsed since the clock started.
+static MILLIS: Mutex<Cell<u32>> = Mutex::new(Cell::new(0));
+
+
+/// The Timer0 compare match interrupt, which counts the milliseconds.
+#[avr_device::interrupt(atmega328p)]
+fn TIMER0_COMPA() {
+    interrupt::free(|cs| {
+        let millis = MILLIS.borrow(cs);
+        millis.set(millis.get().wrapping_add(1));
+    })
+}
+
+
+/// Start the clock, dedicating Timer0 to it.
+///
+/// Interrupts must be enabled globally for the clock to count.
+///
+/// # Arguments
+/// * tc0 - Timer0.
+pub fn start(tc0: TC0) {
+    tc0.tccr0a.write(|w| w.wgm0().ctc());
+    tc0.ocr0a.write(|w| unsafe { w.bits(COUNTS_PER_MS - 1) });
+    tc0.tccr0b.write(|w| w.cs0().prescale_64());
+    tc0.timsk0.write(|w| w.ocie0a().set_bit());
+}
+
+/// Returns the number of milliseconds elapsed since the clock started.
+///
+/// This wraps around after about 49 days, so compare times with `elapsed_since`.
+///   The clock does not count while the microcontroller is in its power-down sleep mode.
+pub fn millis() -> u32 {
+    interrupt::free(|cs| MILLIS.borrow(cs).get())
+}
+
+/// Returns the number of microseconds elapsed since the clock started, to a resolution
+///   of 4 microseconds (e.g. for timing the pulses of a signal).
+///
+/// This wraps around after about 71 minutes, so compare times with wrapping arithmetic.
+pub fn micros() -> u32 {
+    interrupt::free(|cs| {
+        let tc0 = unsafe { &*TC0::ptr() };
+        let mut millis = MILLIS.borrow(cs).get();
+        let count: u8 = tc0.tcnt0.read().bits();
+        // If the timer has matched but its interrupt is still pending (e.g. interrupts are
+        //   disabled, as in an interrupt handler), the millisecond is not yet counted.
+        let flags: u8 = tc0.tifr0.read().bits();
+        if (flags & OCF0A != 0) & (count < COUNTS_PER_MS / 2) { millis = millis.wrapping_add(1) }
+        millis.wrapping_mul(1000).wrapping_add(count as u32 * US_PER_COUNT)
+    })
+}
+
+/// Returns the number of milliseconds elapsed since a time returned by `millis`.
+pub fn elapsed_since(start_ms: u32) -> u32 {
+    millis().wrapping_sub(start_ms)
+}
+
+
+/// Times short intervals in microseconds (to a resolution of 4 microseconds) by following
+///   the count of Timer0 itself, so that, unlike `micros`, it keeps time while interrupts
+///   are disabled. It must be checked at least once a millisecond, as the count wraps.
+pub struct Stopwatch {
+    /// The count of Timer0 when last checked.
+    count: u8,
+    /// The number of microseconds elapsed since it started, as of the last check.
+    elapsed_us: u32,
+}
+
+impl Stopwatch {
+
+    /// Start a new Stopwatch.
+    pub fn start() -> Self {
+        Self { count: Self::read_count(), elapsed_us: 0 }
+    }
+
+    /// Returns the number of microseconds elapsed since the Stopwatch started.
+    pub fn elapsed_us(&mut self) -> u32 {
+        let count = Self::read_count();
+        let counts = (count as u16 + COUNTS_PER_MS as u16 - self.count as u16) % COUNTS_PER_MS as u16;
+        self.count = count;
+        self.elapsed_us += counts as u32 * US_PER_COUNT;
+        self.elapsed_us
+    }
+
+    /// Returns the count of Timer0.
+    fn read_count() -> u8 {
+        let tc0 = unsafe { &*TC0::ptr() };
+        tc0.tcnt0.read().bits()
+    }
+}
+
+
+/// Schedules game ticks of a fixed interval of real time.
+///
+/// The time a game spends between ticks (e.g. updating and rendering) counts toward
+///   the next tick, so a game runs at the same speed however long that takes.
+pub struct Ticker {
+    /// The time, as returned by `millis`, at which the current tick ends.
+    deadline_ms: u32,
+}
+
+impl Ticker {
+
+    /// Create a new Ticker, whose first tick begins when it is scheduled.
+    pub const fn new() -> Self {
+        Self { deadline_ms: 0 }
+    }
+
+    /// Schedule the next tick, returning the time remaining until it ends.
+    ///
+    /// The tick ends an interval after the previous tick ended. If that has already
+    ///   passed by a whole interval (e.g. the game was paused), the tick begins now.
+    ///
+    /// # Arguments
+    /// * interval_ms - The length of the tick in milliseconds.
+    pub fn schedule(&mut self, interval_ms: usize) -> usize {
+        let now = millis();
+        let interval_ms = interval_ms as u32;
+        // The previous tick is stale if it ended more than an interval ago (or has not ended yet).
+        self.deadline_ms = if now.wrapping_sub(self.deadline_ms) > interval_ms {
+            now.wrapping_add(interval_ms)
+        } else {
+            self.deadline_ms.wrapping_add(interval_ms)
+        };
+        self.deadline_ms.wrapping_sub(now) as usize
+    }
+}
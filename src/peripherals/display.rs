@@ -0,0 +1,355 @@
+/// The interface between the games and the display they are played on, so that the games
+///   run on any supported display (e.g. a MAX7219 LED matrix, an SSD1306 OLED, or a WS2812 RGB matrix).
+///
+/// For debugging, the screens shown on the game display may also be mirrored to the serial
+///   connection (see `GameDisplay.set_serial_mirror`), as an ASCII rendering of 8 lines of
+///   `#` (on) and `.` (off), top row first, followed by a blank line.
+///
+/// While the battery is low (see `GameDisplay.set_low_battery`), the top-right corner dot
+///   of every screen shown blinks, as an indicator.
+///
+/// The brightness of any display may be faded (see `Fade`), e.g. to fade a screen in or out
+///   rather than blinking it.
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
+
+use super::{clock, usart, Dot, DotDisplay, DotScreen, GreyScreen, Palette, Ssd1306, Tint, TintedScreen, Ws2812};
+
+/// Whether the screens are mirrored to the serial connection.
+static MIRROR_ENABLED: Mutex<Cell<bool>> = Mutex::new(Cell::new(false));
+/// The screen last mirrored to the serial connection, if any.
+static LAST_MIRRORED: Mutex<Cell<Option<DotScreen>>> = Mutex::new(Cell::new(None));
+/// Whether the low battery indicator is shown.
+static LOW_BATTERY: Mutex<Cell<bool>> = Mutex::new(Cell::new(false));
+
+/// The time the low battery indicator is toggled on (and then off), in milliseconds.
+const LOW_BATTERY_BLINK_MS: u32 = 500;
+/// The dot of the low battery indicator, the top-right corner.
+const LOW_BATTERY_DOT: Dot = Dot { x: DotScreen::WIDTH - 1, y: DotScreen::HEIGHT - 1 };
+/// The time each sub-frame of a GreyScreen is shown for, in milliseconds (see `Display.show_grey`).
+const GREY_SUB_FRAME_MS: u32 = 1;
+/// The time between the steps of the blocking fades (see `Display.fade_in`), in milliseconds.
+const FADE_STEP_MS: u16 = 20;
+
+
+/// Returns whether the low battery indicator is to be toggled on a screen shown now:
+///   while the battery is low, during the first half of each blink.
+fn low_battery_blink() -> bool {
+    interrupt::free(|cs| LOW_BATTERY.borrow(cs).get())
+        && (clock::millis() / LOW_BATTERY_BLINK_MS) % 2 == 0
+}
+
+
+/// Write the ASCII rendering of a screen to the serial connection, if mirroring is enabled
+///   and the screen differs from the one last mirrored.
+///
+/// The bytes are written directly to the USART of the serial connection (that of the
+///   `Components`), blocking until they are sent (about 80ms at 9600 baud).
+fn mirror(screen: &DotScreen) {
+    let is_new = interrupt::free(|cs| {
+        if !MIRROR_ENABLED.borrow(cs).get() { return false }
+        let last = LAST_MIRRORED.borrow(cs).replace(Some(*screen));
+        last.map_or(true, |last| last.columns != screen.columns)
+    });
+    if !is_new { return }
+
+    for y in (0..DotScreen::HEIGHT).rev() {
+        for x in 0..DotScreen::WIDTH {
+            usart::write_byte(if screen.is_dot_on(&Dot { x, y }) { b'#' } else { b'.' });
+        }
+        usart::write(b"\r\n");
+    }
+    usart::write(b"\r\n");
+}
+
+
+/// This trait signifies that the peripheral can display a DotScreen.
+pub trait Display {
+
+    /// Show a DotScreen on the display.
+    fn show(&mut self, screen: &DotScreen);
+
+    /// Turn off every dot of the display.
+    fn clear(&mut self) {
+        self.show(&DotScreen::new_empty());
+    }
+
+    /// Show a TintedScreen, coloring each layer from the Palette. Monochrome displays
+    ///   ignore the tints, showing the layers flattened.
+    fn show_tinted(&mut self, screen: &TintedScreen, _palette: &Palette) {
+        self.show(&screen.flatten());
+    }
+
+    /// Show a GreyScreen, with several brightness levels per dot.
+    ///
+    /// By default, this shows the sub-frame of the GreyScreen (see `GreyScreen.sub_frame`)
+    ///   of the current time, the sub-frames taking turns every `GREY_SUB_FRAME_MS`. This is
+    ///   to be called over and over (e.g. from `utils::animate_tick`) for the levels to show.
+    fn show_grey(&mut self, screen: &GreyScreen) {
+        let index = (clock::millis() / GREY_SUB_FRAME_MS) as usize % GreyScreen::SUB_FRAMES;
+        self.show(&screen.sub_frame(index));
+    }
+
+    /// Show the status of the game-play (the score, and any lives) alongside the screen,
+    ///   if the display has room for it. Most displays have no room, so ignore it.
+    fn show_status(&mut self, _score: usize, _lives: Option<usize>) {}
+
+    /// Set the (perceived) brightness of the display.
+    ///
+    /// # Arguments
+    /// * level - The brightness level, from 0 (lowest) to `DotDisplay::BRIGHTNESS_LEVELS - 1`
+    ///             (highest). Levels outside this range are clamped to the highest level.
+    fn set_brightness(&mut self, level: u8);
+
+    /// Returns the current brightness level of the display.
+    fn brightness(&self) -> u8;
+
+    /// Dim the display to a fraction of a brightness level, e.g. for a step of a fade.
+    ///
+    /// By default, this sets the nearest brightness level, which a fade restores as it finishes.
+    ///
+    /// # Arguments
+    /// * level    - The brightness level, see `set_brightness`.
+    /// * fraction - The fraction of the brightness level, from 0 (the dimmest) to 255 (the level itself).
+    fn set_faded(&mut self, level: u8, fraction: u8) {
+        self.set_brightness(((level as u16 * fraction as u16 + 127) / u8::MAX as u16) as u8);
+    }
+
+    /// Shutdown the display (or turn it back on), keeping what it shows.
+    fn shutdown(&mut self, off: bool);
+
+    /// Show a DotScreen, fading the brightness of the display in from its dimmest, blocking
+    ///   until it is done.
+    ///
+    /// # Arguments
+    /// * screen      - The screen to show.
+    /// * duration_ms - The time the fade takes.
+    fn fade_in(&mut self, screen: &DotScreen, duration_ms: u16) {
+        let fade = Fade::start(self, FadeKind::In, duration_ms);
+        self.show(screen);
+        fade.wait(self);
+    }
+
+    /// Fade the brightness of the display out to its dimmest, blocking until it is done.
+    ///   The display is then cleared, and its brightness restored.
+    ///
+    /// # Arguments
+    /// * duration_ms - The time the fade takes.
+    fn fade_out(&mut self, duration_ms: u16) {
+        Fade::start(self, FadeKind::Out, duration_ms).wait(self);
+    }
+
+    /// Pulse the brightness of the display (fading it out, and back in), blocking until it is done.
+    ///
+    /// # Arguments
+    /// * period_ms - The time each pulse takes.
+    /// * times     - The number of pulses.
+    fn pulse(&mut self, period_ms: u16, times: usize) {
+        for _ in 0..times {
+            Fade::start(self, FadeKind::Pulse, period_ms).wait(self);
+        }
+    }
+}
+
+
+/// The way the brightness changes over a Fade.
+#[derive(Copy, Clone, PartialEq)]
+pub enum FadeKind {
+    /// From the dimmest, up to the brightness level.
+    In,
+    /// From the brightness level, down to the dimmest, after which the display is cleared.
+    Out,
+    /// From the brightness level, down to the dimmest, and back up.
+    Pulse,
+}
+
+/// A fade of the brightness of a display, which is stepped over time (see `update`), so
+///   that it doesn't block, e.g. to poll for input meanwhile.
+///
+/// The brightness level of the display is restored as the fade finishes.
+pub struct Fade {
+    // The way the brightness changes.
+    kind: FadeKind,
+    // The brightness level of the display, as the fade started.
+    level: u8,
+    // The time, as returned by `clock::millis`, at which the fade started.
+    start_ms: u32,
+    // The time the fade takes.
+    duration_ms: u16,
+}
+
+impl Fade {
+
+    /// Start a fade of the brightness of a display.
+    ///
+    /// # Arguments
+    /// * display     - The display to fade.
+    /// * kind        - The way the brightness changes.
+    /// * duration_ms - The time the fade takes.
+    pub fn start<D: Display + ?Sized>(display: &mut D, kind: FadeKind, duration_ms: u16) -> Self {
+        let mut fade = Fade { kind, level: display.brightness(), start_ms: clock::millis(), duration_ms };
+        fade.update(display);
+        fade
+    }
+
+    /// Step the fade, dimming the display to the brightness of the time elapsed.
+    ///
+    /// # Returns
+    /// Whether the fade has finished (after which its steps change nothing).
+    pub fn update<D: Display + ?Sized>(&mut self, display: &mut D) -> bool {
+        let elapsed_ms = clock::elapsed_since(self.start_ms).min(self.duration_ms as u32);
+        if elapsed_ms == self.duration_ms as u32 {
+            self.finish(display);
+            return true
+        }
+        // The progress of the fade, from 0 up to (just below) 255.
+        let progress = (elapsed_ms * u8::MAX as u32 / (self.duration_ms as u32).max(1)) as u8;
+        let fraction = match self.kind {
+            FadeKind::In => { progress },
+            FadeKind::Out => { u8::MAX - progress },
+            FadeKind::Pulse => { (progress as i16 * 2 - u8::MAX as i16).abs() as u8 },
+        };
+        display.set_faded(self.level, fraction);
+        false
+    }
+
+    /// Finish the fade at once (e.g. if it is interrupted by input), restoring the brightness level.
+    pub fn finish<D: Display + ?Sized>(&mut self, display: &mut D) {
+        // Pretend the whole time has elapsed, so that any later steps change nothing.
+        self.start_ms = clock::millis().wrapping_sub(self.duration_ms as u32);
+        if self.kind == FadeKind::Out {
+            display.set_faded(self.level, 0);
+            display.clear();
+        }
+        display.set_brightness(self.level);
+    }
+
+    /// Step the fade until it has finished, blocking meanwhile.
+    fn wait<D: Display + ?Sized>(mut self, display: &mut D) {
+        while !self.update(display) {
+            arduino_uno::delay_ms(FADE_STEP_MS);
+        }
+    }
+}
+
+
+impl Display for DotDisplay {
+    fn show(&mut self, screen: &DotScreen) { DotDisplay::show(self, screen) }
+    fn clear(&mut self) { DotDisplay::clear(self) }
+    fn set_brightness(&mut self, level: u8) { DotDisplay::set_brightness(self, level) }
+    fn brightness(&self) -> u8 { DotDisplay::brightness(self) }
+    fn set_faded(&mut self, level: u8, fraction: u8) { DotDisplay::set_faded(self, level, fraction) }
+    fn shutdown(&mut self, off: bool) { DotDisplay::shutdown(self, off) }
+}
+
+
+/// The display the games are played on: any one of the supported displays,
+///   chosen when the components are constructed.
+pub enum GameDisplay {
+    /// A MAX7219 LED matrix (or several, chained).
+    Max7219(DotDisplay),
+    /// An SSD1306 OLED, on the I2C bus.
+    Ssd1306(Ssd1306),
+    /// An 8x8 matrix of WS2812 RGB LEDs.
+    Ws2812(Ws2812),
+}
+
+impl GameDisplay {
+
+    /// Show a DotScreen on the display, mirroring it to the serial connection if enabled.
+    ///   The low battery indicator, if shown, toggles its dot.
+    pub fn show(&mut self, screen: &DotScreen) {
+        if low_battery_blink() {
+            let mut indicated = *screen;
+            indicated.toggle(&LOW_BATTERY_DOT);
+            self.inner().show(&indicated);
+        } else {
+            self.inner().show(screen);
+        }
+        mirror(screen);
+    }
+
+    /// Show (or hide) the low battery indicator, which blinks the top-right corner dot
+    ///   of the screens shown.
+    pub fn set_low_battery(&mut self, low: bool) {
+        interrupt::free(|cs| LOW_BATTERY.borrow(cs).set(low));
+    }
+
+    /// Enable (or disable) mirroring the screens shown to the serial connection, for debugging.
+    pub fn set_serial_mirror(&mut self, enabled: bool) {
+        interrupt::free(|cs| {
+            MIRROR_ENABLED.borrow(cs).set(enabled);
+            LAST_MIRRORED.borrow(cs).set(None);
+        });
+    }
+
+    /// Turn off every dot of the display.
+    pub fn clear(&mut self) {
+        self.inner().clear()
+    }
+
+    /// Set the (perceived) brightness of the display, see `Display.set_brightness`.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.inner().set_brightness(level)
+    }
+
+    /// Returns the current brightness level of the display.
+    pub fn brightness(&self) -> u8 {
+        match self {
+            GameDisplay::Max7219(display) => { display.brightness() },
+            GameDisplay::Ssd1306(display) => { display.brightness() },
+            GameDisplay::Ws2812(display) => { display.brightness() },
+        }
+    }
+
+    /// Shutdown the display (or turn it back on), keeping what it shows.
+    pub fn shutdown(&mut self, off: bool) {
+        self.inner().shutdown(off)
+    }
+
+    /// Returns the display as a trait object.
+    fn inner(&mut self) -> &mut dyn Display {
+        match self {
+            GameDisplay::Max7219(display) => { display },
+            GameDisplay::Ssd1306(display) => { display },
+            GameDisplay::Ws2812(display) => { display },
+        }
+    }
+}
+
+impl Display for GameDisplay {
+    fn show(&mut self, screen: &DotScreen) { GameDisplay::show(self, screen) }
+    fn clear(&mut self) { GameDisplay::clear(self) }
+    fn show_tinted(&mut self, screen: &TintedScreen, palette: &Palette) {
+        if low_battery_blink() {
+            let mut indicated = *screen;
+            if screen.tint_at(&LOW_BATTERY_DOT).is_some() {
+                while let Some(tint) = indicated.tint_at(&LOW_BATTERY_DOT) {
+                    indicated.layer_mut(tint).remove(&LOW_BATTERY_DOT);
+                }
+            } else {
+                indicated.add(Tint::Hud, &LOW_BATTERY_DOT);
+            }
+            self.inner().show_tinted(&indicated, palette);
+        } else {
+            self.inner().show_tinted(screen, palette);
+        }
+        mirror(&screen.flatten());
+    }
+    fn show_grey(&mut self, screen: &GreyScreen) {
+        if low_battery_blink() {
+            let mut indicated = *screen;
+            let level = if screen.level(&LOW_BATTERY_DOT) > 0 { 0 } else { GreyScreen::MAX_LEVEL };
+            indicated.set(&LOW_BATTERY_DOT, level);
+            self.inner().show_grey(&indicated);
+        } else {
+            self.inner().show_grey(screen);
+        }
+        mirror(&screen.flatten());
+    }
+    fn show_status(&mut self, score: usize, lives: Option<usize>) { self.inner().show_status(score, lives) }
+    fn set_brightness(&mut self, level: u8) { GameDisplay::set_brightness(self, level) }
+    fn brightness(&self) -> u8 { GameDisplay::brightness(self) }
+    fn set_faded(&mut self, level: u8, fraction: u8) { self.inner().set_faded(level, fraction) }
+    fn shutdown(&mut self, off: bool) { GameDisplay::shutdown(self, off) }
+}
@@ -0,0 +1,149 @@
+/// Functionality for reading and writing the EEPROM, whose contents survive power loss.
+///
+/// Bytes and slices may be read, written, or updated (written only where they differ, to
+///   spare the EEPROM wear), and data may be stored as a checksummed record (see `read_record`).
+///   Addresses beyond the EEPROM are ignored: reading them returns the value of erased
+///   EEPROM (0xFF), and writing them does nothing.
+use avr_device::atmega328p::{eeprom::RegisterBlock, EEPROM};
+
+// The EEPROM Master Write Enable, Write Enable, and Read Enable bits of the EECR register.
+const EEMPE: u8 = 1 << 2;
+const EEPE: u8 = 1 << 1;
+const EERE: u8 = 1 << 0;
+
+/// The number of bytes of EEPROM on the ATmega328P.
+pub const SIZE: usize = 1024;
+/// The value of a byte of erased EEPROM.
+const ERASED: u8 = 0xFF;
+
+
+/// Read a byte from the EEPROM.
+///
+/// # Arguments
+/// * address - The address of the byte, less than `SIZE`.
+pub fn read(address: usize) -> u8 {
+    if address >= SIZE { return ERASED }
+    let eeprom = unsafe { &*EEPROM::ptr() };
+    wait_until_ready(eeprom);
+    eeprom.eear.write(|w| unsafe { w.bits(address as u16) });
+    eeprom.eecr.write(|w| unsafe { w.bits(EERE) });
+    eeprom.eedr.read().bits()
+}
+
+/// Write a byte to the EEPROM, whether or not it differs from the value already stored.
+///
+/// Each byte of the EEPROM only survives a limited number of writes (about 100,000),
+///   so `update` should be preferred. This blocks until any previous write is complete
+///   (each write takes about 3.4ms).
+///
+/// # Arguments
+/// * address - The address of the byte, less than `SIZE`.
+/// * value   - The value to write.
+pub fn write(address: usize, value: u8) {
+    if address >= SIZE { return }
+    let eeprom = unsafe { &*EEPROM::ptr() };
+    wait_until_ready(eeprom);
+    avr_device::interrupt::free(|_| {
+        eeprom.eear.write(|w| unsafe { w.bits(address as u16) });
+        eeprom.eedr.write(|w| unsafe { w.bits(value) });
+        // The write enable bit must be set within four clock cycles of the master write enable bit.
+        eeprom.eecr.write(|w| unsafe { w.bits(EEMPE) });
+        eeprom.eecr.write(|w| unsafe { w.bits(EEMPE | EEPE) });
+    });
+}
+
+/// Update a byte of the EEPROM, writing it only if it differs from the value already stored.
+///
+/// # Arguments
+/// * address - The address of the byte, less than `SIZE`.
+/// * value   - The value to write.
+pub fn update(address: usize, value: u8) {
+    if read(address) != value { write(address, value) }
+}
+
+/// Read consecutive bytes from the EEPROM.
+///
+/// # Arguments
+/// * address - The address of the first byte.
+/// * data    - The buffer into which the bytes are read.
+pub fn read_slice(address: usize, data: &mut [u8]) {
+    for (offset, byte) in data.iter_mut().enumerate() {
+        *byte = read(address + offset);
+    }
+}
+
+/// Write consecutive bytes to the EEPROM, see `write`.
+///
+/// # Arguments
+/// * address - The address of the first byte.
+/// * data    - The bytes to write.
+pub fn write_slice(address: usize, data: &[u8]) {
+    for (offset, &byte) in data.iter().enumerate() {
+        write(address + offset, byte);
+    }
+}
+
+/// Update consecutive bytes of the EEPROM, writing only those which differ, see `update`.
+///
+/// # Arguments
+/// * address - The address of the first byte.
+/// * data    - The bytes to write.
+pub fn update_slice(address: usize, data: &[u8]) {
+    for (offset, &byte) in data.iter().enumerate() {
+        update(address + offset, byte);
+    }
+}
+
+/// The number of bytes a record occupies in addition to its data (the magic byte and checksum).
+pub const RECORD_OVERHEAD: usize = 2;
+
+/// Read a record from the EEPROM.
+///
+/// A record is stored as a magic byte (identifying the record), its data, and a
+///   checksum of the two. If the EEPROM does not hold a valid record (e.g. it has
+///   never been written), the data must not be used.
+///
+/// # Arguments
+/// * address - The address of the record.
+/// * magic   - The magic byte identifying the record.
+/// * data    - The buffer into which the data of the record is read.
+///
+/// # Returns
+/// Whether a valid record was read (the magic byte and checksum match).
+pub fn read_record(address: usize, magic: u8, data: &mut [u8]) -> bool {
+    read_slice(address + 1, data);
+    (read(address) == magic) & (read(address + 1 + data.len()) == checksum(magic, data))
+}
+
+/// Write a record to the EEPROM, see `read_record`.
+///
+/// Only the bytes which differ from those already stored are written (see `update`).
+///
+/// # Arguments
+/// * address - The address of the record.
+/// * magic   - The magic byte identifying the record.
+/// * data    - The data of the record.
+pub fn write_record(address: usize, magic: u8, data: &[u8]) {
+    update(address, magic);
+    update_slice(address + 1, data);
+    update(address + 1 + data.len(), checksum(magic, data));
+}
+
+/// Returns the checksum of a record.
+///
+/// Each byte is rotated by its position, so that swapped bytes change the checksum.
+fn checksum(magic: u8, data: &[u8]) -> u8 {
+    data.iter()
+        .enumerate()
+        .fold(magic, |checksum, (offset, &byte)| checksum ^ byte.rotate_left((offset + 1) as u32))
+}
+
+/// Block until any write in progress is complete.
+///
+/// The EEPROM can't be read or written while a write is in progress.
+fn wait_until_ready(eeprom: &RegisterBlock) {
+    loop {
+        let control: u8 = eeprom.eecr.read().bits();
+        if control & EEPE == 0 { break }
+    }
+}
@@ -0,0 +1,111 @@
+/// Functionality for deriving input events (e.g. a press of the button) from the successive
+///   "JoyStickSignals" of a JoyStick, so that games need not interpret the raw signals.
+use arraydeque::{ArrayDeque, Wrapping};
+use crate::Direction;
+use super::{clock, JoyStickSignal, PollArray};
+
+/// The time the button must be held down before it is reported as held.
+const HOLD_MS: u32 = 800;
+
+
+/// An enumeration of the possible input events.
+#[derive(Copy, Clone, PartialEq)]
+pub enum InputEvent {
+    /// The button was pressed down.
+    Pressed,
+    /// The button was released.
+    Released,
+    /// The button has been held down for a while (e.g. a long press), reported once per press.
+    Held,
+    /// The JoyStick was pushed in a new direction, or returned to the center (None).
+    DirectionChanged(Option<Direction>),
+}
+
+
+/// The queue of the input events of the latest poll. The oldest events are dropped when full.
+pub type EventQueue = ArrayDeque<[InputEvent; 16], Wrapping>;
+
+/// This struct tracks the state of a JoyStick across polls, queueing an event for each change.
+///
+/// The events of each poll replace those of the previous poll, but the state carries over,
+///   so a button held down since before the poll is not reported as pressed.
+pub struct InputEvents {
+    queue: EventQueue,
+    /// Whether the button is down.
+    button: bool,
+    /// The time, as returned by `clock::millis`, at which the button was pressed down.
+    pressed_ms: u32,
+    /// Whether the button has been reported as held during the current press.
+    held: bool,
+    /// The direction the JoyStick is pushed in, if any.
+    direction: Option<Direction>,
+}
+
+impl InputEvents {
+
+    /// Construct a new InputEvents, with the button up and the JoyStick centered.
+    pub fn new() -> Self {
+        Self { queue: ArrayDeque::new(), button: false, pressed_ms: 0, held: false, direction: None }
+    }
+
+    /// Track the signals of a poll, replacing the queued events with those they produce.
+    ///
+    /// A JoyStick only produces a signal while it has input, so a poll without any signal
+    ///   is tracked as the button up and the JoyStick centered.
+    pub fn track_poll(&mut self, signals: &PollArray) {
+        self.queue.clear();
+        for signal in signals.iter() {
+            self.track(Some(signal.to_joystick()));
+        }
+        if signals.is_empty() { self.track(None) }
+    }
+
+    /// Track a single signal (or None for no input), queueing the events it produces.
+    pub fn track(&mut self, signal: Option<JoyStickSignal>) {
+        let button = signal.map_or(false, |signal| signal.button);
+        let direction = signal.and_then(JoyStickSignal::to_single_direction);
+        if button != self.button {
+            self.button = button;
+            self.held = false;
+            self.pressed_ms = clock::millis();
+            self.queue.push_back(if button { InputEvent::Pressed } else { InputEvent::Released });
+        } else if button & !self.held && (clock::elapsed_since(self.pressed_ms) >= HOLD_MS) {
+            self.held = true;
+            self.queue.push_back(InputEvent::Held);
+        }
+        if direction != self.direction {
+            self.direction = direction;
+            self.queue.push_back(InputEvent::DirectionChanged(direction));
+        }
+    }
+
+    /// Remove the queued events, keeping the state of the JoyStick.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+
+    /// Remove and return the oldest queued event, if any.
+    pub fn pop(&mut self) -> Option<InputEvent> {
+        self.queue.pop_front()
+    }
+
+    /// Returns an iterator over the queued events, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &InputEvent> {
+        self.queue.iter()
+    }
+
+    /// Returns whether the event is queued.
+    pub fn contains(&self, event: InputEvent) -> bool {
+        self.queue.iter().any(|&queued| queued == event)
+    }
+
+    /// Returns whether the button is down.
+    pub fn button(&self) -> bool {
+        self.button
+    }
+
+    /// Returns the direction the JoyStick is pushed in, if any.
+    pub fn direction(&self) -> Option<Direction> {
+        self.direction
+    }
+}
@@ -0,0 +1,97 @@
+/// Functionality for showing several brightness levels per dot (greyscale), e.g. for dimmed
+///   "ghost" pieces, or trails which fade away.
+///
+/// Games draw onto a GreyScreen, with a level of 0 (off) to 3 (full brightness) for each dot.
+///   Monochrome displays (such as the MAX7219 DotDisplay) can only turn a dot on or off, so
+///   they show the levels by time-multiplexing: each GreyScreen is shown as a cycle of
+///   sub-frames, a dot being on in as many of them as its level (see `Display.show_grey`).
+///   The sub-frames must be shown rapidly for the levels to blend, so a GreyScreen is shown
+///   over and over while the game waits out its tick (e.g. from `utils::animate_tick`).
+use super::{Dot, DotScreen};
+
+
+/// A screen of dots with a brightness level each.
+#[derive(Copy, Clone)]
+pub struct GreyScreen {
+    // The levels of the dots, as two bit planes: the low bit of each level, then the high bit.
+    planes: [DotScreen; 2],
+}
+
+impl GreyScreen {
+
+    /// The level of a dot at full brightness (the levels run from 0, which is off, up to this).
+    pub const MAX_LEVEL: u8 = 3;
+
+    /// The number of sub-frames a GreyScreen is shown as, see `sub_frame`.
+    pub const SUB_FRAMES: usize = Self::MAX_LEVEL as usize;
+
+    /// Creates a new GreyScreen object, with every dot off.
+    pub const fn new_empty() -> Self {
+        GreyScreen { planes: [DotScreen::new_empty(); 2] }
+    }
+
+    /// Creates a new GreyScreen object, with the dots which are on in a DotScreen at a level.
+    pub fn from_screen(screen: &DotScreen, level: u8) -> Self {
+        let level = level.min(Self::MAX_LEVEL);
+        let plane = |bit: u8| if level & bit != 0 { *screen } else { DotScreen::new_empty() };
+        GreyScreen { planes: [plane(1), plane(2)] }
+    }
+
+    /// Returns the level of a dot.
+    pub fn level(&self, dot: &Dot) -> u8 {
+        self.planes[0].is_dot_on(dot) as u8 | (self.planes[1].is_dot_on(dot) as u8) << 1
+    }
+
+    /// Set the level of a dot (levels above `MAX_LEVEL` are full brightness).
+    pub fn set(&mut self, dot: &Dot, level: u8) {
+        let level = level.min(Self::MAX_LEVEL);
+        for (bit, plane) in self.planes.iter_mut().enumerate() {
+            if level & (1 << bit) != 0 { plane.add(dot) } else { plane.remove(dot) }
+        }
+    }
+
+    /// Raise the level of a dot to a level, leaving a brighter dot as it is.
+    pub fn brighten(&mut self, dot: &Dot, level: u8) {
+        if level > self.level(dot) { self.set(dot, level) }
+    }
+
+    /// Turn every dot off.
+    pub fn clear(&mut self) {
+        self.planes.iter_mut().for_each(|plane| plane.clear());
+    }
+
+    /// Dim every dot by a single level, e.g. so that a trail fades away.
+    pub fn fade(&mut self) {
+        for x in 0..DotScreen::WIDTH {
+            let (low, high) = (self.planes[0].columns[x], self.planes[1].columns[x]);
+            // A level of 3 becomes 2, 2 becomes 1, and 1 becomes 0.
+            self.planes[0].columns[x] = high & !low;
+            self.planes[1].columns[x] = high & low;
+        }
+    }
+
+    /// Returns a sub-frame of the GreyScreen: the dots whose level is above its index.
+    ///
+    /// Showing every sub-frame for an equal time shows each dot for a fraction of the
+    ///   time of its level (out of `MAX_LEVEL`).
+    ///
+    /// # Arguments
+    /// * index - The index of the sub-frame, from 0 up to `SUB_FRAMES - 1`.
+    pub fn sub_frame(&self, index: usize) -> DotScreen {
+        let mut screen = DotScreen::new_empty();
+        for (x, column) in screen.columns.iter_mut().enumerate() {
+            let (low, high) = (self.planes[0].columns[x], self.planes[1].columns[x]);
+            *column = match index {
+                0 => { low | high },
+                1 => { high },
+                _ => { low & high },
+            };
+        }
+        screen
+    }
+
+    /// Flatten the GreyScreen into a single monochrome DotScreen, of the dots which aren't off.
+    pub fn flatten(&self) -> DotScreen {
+        self.sub_frame(0)
+    }
+}
@@ -0,0 +1,159 @@
+/// Functionality for haptic feedback: pulses and patterns of the vibration (rumble) motor,
+///   timed entirely by Timer2.
+///
+/// Like the buzzer (see `Buzzer`), a pulse or pattern runs by itself once started: Timer2
+///   interrupts once a millisecond, counting down the current step of the pattern, and
+///   switches the motor at the end of each step. The game loop never waits on the motor.
+use core::cell::RefCell;
+use avr_device::interrupt::{self, Mutex};
+use arduino_uno::pac::{TC2, tc2::RegisterBlock};
+
+use super::Rumble;
+
+// Timer2 counts at 16MHz / 64 = 250kHz, so matches every 250 counts once a millisecond.
+const COUNTS_PER_MS: u8 = 250;
+
+
+/// A haptic pattern: the durations of its steps in milliseconds, alternating between
+///   the motor running and resting (starting with running).
+pub type HapticPattern = [u16];
+
+/// A short tap, e.g. for eating an egg or scoring a point.
+pub static TAP: [u16; 1] = [40];
+/// A heavy double thud, for a collision ending the game.
+pub static GAME_OVER: [u16; 3] = [200, 100, 400];
+/// A rapid flutter, for winning the game.
+pub static VICTORY: [u16; 7] = [60, 60, 60, 60, 60, 60, 300];
+
+
+/// The playback state, shared between the Haptics and the Timer2 interrupt.
+struct Playback {
+    /// The motor, once the Haptics have been constructed.
+    rumble: Option<Rumble>,
+    /// The pattern being played. A single pulse has no pattern beyond its first step.
+    pattern: &'static HapticPattern,
+    /// The index of the current step of the pattern.
+    step: usize,
+    /// The milliseconds remaining until the current step is over.
+    remaining_ms: u16,
+}
+
+static PLAYBACK: Mutex<RefCell<Playback>> = Mutex::new(RefCell::new(
+    Playback { rumble: None, pattern: &[], step: 0, remaining_ms: 0 }
+));
+
+
+/// Start a step of the pattern, running or resting the motor for its duration.
+fn start_step(tc2: &RegisterBlock, playback: &mut Playback, running: bool, duration_ms: u16) {
+    playback.remaining_ms = duration_ms;
+    if let Some(rumble) = playback.rumble.as_mut() {
+        if running { rumble.start() } else { rumble.stop() }
+    }
+    tc2.tcnt2.write(|w| unsafe { w.bits(0) });
+    tc2.tccr2b.write(|w| w.cs2().prescale_64());
+}
+
+/// Stop Timer2 and the motor, ending the pattern.
+fn stop(tc2: &RegisterBlock, playback: &mut Playback) {
+    playback.pattern = &[];
+    playback.remaining_ms = 0;
+    if let Some(rumble) = playback.rumble.as_mut() { rumble.stop() }
+    tc2.tccr2b.write(|w| w.cs2().no_clock());
+}
+
+/// The Timer2 compare match interrupt, which sequences the steps of the pattern.
+#[avr_device::interrupt(atmega328p)]
+fn TIMER2_COMPA() {
+    interrupt::free(|cs| {
+        let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+        if playback.remaining_ms > 1 {
+            playback.remaining_ms -= 1;
+            return
+        }
+        let tc2 = unsafe { &*TC2::ptr() };
+        playback.step += 1;
+        match playback.pattern.get(playback.step).copied() {
+            Some(duration_ms) => {
+                let running = playback.step % 2 == 0;
+                start_step(tc2, &mut playback, running, duration_ms)
+            },
+            None => { stop(tc2, &mut playback) },
+        }
+    })
+}
+
+
+/// The object that plays haptic feedback on the vibration motor, dedicating Timer2 to it.
+pub struct Haptics {
+    // Timer2, which times the pulses.
+    tc2: TC2,
+}
+
+impl Haptics {
+
+    /// Create a new Haptics object, with the motor switched off.
+    ///
+    /// # Arguments
+    ///
+    /// * `tc2`    - Timer2, which is dedicated to timing the pulses.
+    /// * `rumble` - The vibration motor.
+    pub fn new(tc2: TC2, rumble: Rumble) -> Self {
+        tc2.tccr2a.write(|w| w.wgm2().ctc());
+        tc2.ocr2a.write(|w| unsafe { w.bits(COUNTS_PER_MS - 1) });
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            playback.rumble = Some(rumble);
+            stop(&tc2, &mut playback);
+        });
+        tc2.timsk2.write(|w| w.ocie2a().set_bit());
+        Self { tc2 }
+    }
+
+    /// Run the motor for a number of milliseconds, replacing any pattern being played.
+    pub fn pulse(&mut self, duration_ms: u16) {
+        let tc2 = &self.tc2;
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            playback.pattern = &[];
+            playback.step = 0;
+            start_step(tc2, &mut playback, true, duration_ms);
+        })
+    }
+
+    /// Play a haptic pattern, replacing any pattern being played.
+    pub fn play(&mut self, pattern: &'static HapticPattern) {
+        let tc2 = &self.tc2;
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            match pattern.first().copied() {
+                Some(duration_ms) => {
+                    playback.pattern = pattern;
+                    playback.step = 0;
+                    start_step(tc2, &mut playback, true, duration_ms);
+                },
+                None => { stop(tc2, &mut playback) },
+            }
+        })
+    }
+
+    /// Switch the motor on until `stop` is called, replacing any pattern being played.
+    pub fn start(&mut self) {
+        let tc2 = &self.tc2;
+        interrupt::free(|cs| {
+            let mut playback = PLAYBACK.borrow(cs).borrow_mut();
+            stop(tc2, &mut playback);
+            if let Some(rumble) = playback.rumble.as_mut() { rumble.start() }
+        })
+    }
+
+    /// Switch the motor off, ending any pattern being played.
+    pub fn stop(&mut self) {
+        let tc2 = &self.tc2;
+        interrupt::free(|cs| stop(tc2, &mut PLAYBACK.borrow(cs).borrow_mut()))
+    }
+
+    /// Returns whether a pulse or pattern is being played.
+    pub fn is_playing(&self) -> bool {
+        interrupt::free(|cs| PLAYBACK.borrow(cs).borrow().remaining_ms > 0)
+    }
+}
@@ -0,0 +1,194 @@
+/// Functionality for communicating with devices on the I2C bus (e.g. an accelerometer,
+///   a real-time clock, or an OLED display), driven by the TWI peripheral.
+///
+/// The bus is on A4 (SDA) and A5 (SCL), which need pull-up resistors (usually fitted to
+///   the breakout boards of the devices). Every transaction is blocking, completing (or
+///   failing) before it returns, so the devices sharing the bus never interleave.
+use arduino_uno::hal::port::{mode::{Floating, Input}, portc::{PC4, PC5}};
+use arduino_uno::pac::{TWI, twi::RegisterBlock};
+
+// The bits of the TWCR register: the interrupt flag (set once the bus operation is
+//   complete), acknowledge, start condition, stop condition, and enable bits.
+const TWINT: u8 = 1 << 7;
+const TWEA: u8 = 1 << 6;
+const TWSTA: u8 = 1 << 5;
+const TWSTO: u8 = 1 << 4;
+const TWEN: u8 = 1 << 2;
+// The bit rate of the bus (100kHz SCL): 16MHz / (16 + 2 * TWBR).
+const BIT_RATE: u8 = 72;
+// The status codes of the TWSR register (with the prescaler bits masked out).
+const STATUS_MASK: u8 = 0xF8;
+const START: u8 = 0x08;
+const REPEATED_START: u8 = 0x10;
+const ADDRESS_WRITE_ACK: u8 = 0x18;
+const ADDRESS_WRITE_NACK: u8 = 0x20;
+const DATA_WRITE_ACK: u8 = 0x28;
+const DATA_WRITE_NACK: u8 = 0x30;
+const ARBITRATION_LOST: u8 = 0x38;
+const ADDRESS_READ_ACK: u8 = 0x40;
+const ADDRESS_READ_NACK: u8 = 0x48;
+// The number of times the interrupt flag is checked before the bus is given up on.
+const TIMEOUT_CHECKS: u16 = 2000;
+
+
+/// An enumeration of the ways a transaction on the I2C bus may fail.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum I2cError {
+    /// The bus didn't complete an operation in time (e.g. SCL is held low).
+    Timeout,
+    /// No device acknowledged the address (e.g. it isn't connected).
+    AddressNack,
+    /// The device didn't acknowledge a byte written to it.
+    DataNack,
+    /// Another controller took over the bus.
+    ArbitrationLost,
+    /// The bus reported any other (unexpected) status.
+    Bus(u8),
+}
+
+
+/// The object that interfaces with the I2C bus.
+///
+/// The bus is shared between devices by handing each their own I2c (see `share`).
+pub struct I2c {
+    // The TWI peripheral, and its SDA (A4) and SCL (A5) pins, are owned by the first I2c,
+    //   and configured once, so that nothing else can take over the pins of the bus.
+    _twi: Option<(TWI, PC4<Input<Floating>>, PC5<Input<Floating>>)>,
+}
+
+impl I2c {
+
+    /// Creates a new I2c object, configuring the bus.
+    ///
+    /// # Arguments
+    /// * twi - The TWI peripheral.
+    /// * sda - The data pin of the bus (A4).
+    /// * scl - The clock pin of the bus (A5).
+    pub fn new(twi: TWI, sda: PC4<Input<Floating>>, scl: PC5<Input<Floating>>) -> Self {
+        twi.twsr.write(|w| unsafe { w.bits(0) });
+        twi.twbr.write(|w| unsafe { w.bits(BIT_RATE) });
+        Self { _twi: Some((twi, sda, scl)) }
+    }
+
+    /// Creates another I2c object on the same bus, e.g. for another device.
+    pub fn share(&self) -> Self {
+        Self { _twi: None }
+    }
+
+    /// Write bytes to a device.
+    ///
+    /// # Arguments
+    /// * address - The (7-bit) address of the device.
+    /// * bytes   - The bytes to write.
+    pub fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), I2cError> {
+        let result = self.begin_write(address, bytes);
+        stop();
+        result
+    }
+
+    /// Read bytes from a device.
+    ///
+    /// # Arguments
+    /// * address - The (7-bit) address of the device.
+    /// * buffer  - The buffer to fill with the bytes read.
+    pub fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
+        let result = start(START).and_then(|_| receive(address, buffer));
+        stop();
+        result
+    }
+
+    /// Write bytes to a device, then read bytes from it, without releasing the bus between
+    ///   (e.g. writing the register to read).
+    ///
+    /// # Arguments
+    /// * address - The (7-bit) address of the device.
+    /// * bytes   - The bytes to write.
+    /// * buffer  - The buffer to fill with the bytes read.
+    pub fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), I2cError> {
+        let result = self.begin_write(address, bytes)
+            .and_then(|_| start(REPEATED_START))
+            .and_then(|_| receive(address, buffer));
+        stop();
+        result
+    }
+
+    /// Write a register of a device.
+    pub fn write_register(&mut self, address: u8, register: u8, value: u8) -> Result<(), I2cError> {
+        self.write(address, &[register, value])
+    }
+
+    /// Read consecutive registers of a device, from the first register.
+    pub fn read_registers(&mut self, address: u8, register: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
+        self.write_read(address, &[register], buffer)
+    }
+
+    /// Start a transaction and write bytes to a device, without releasing the bus.
+    fn begin_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), I2cError> {
+        start(START)?;
+        send(address << 1, ADDRESS_WRITE_ACK)?;
+        for &byte in bytes.iter() {
+            send(byte, DATA_WRITE_ACK)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// Returns the registers of the TWI peripheral, which is configured by the first I2c.
+fn twi() -> &'static RegisterBlock {
+    unsafe { &*TWI::ptr() }
+}
+
+/// Send a start condition, checking the bus reports the expected status.
+fn start(expected: u8) -> Result<(), I2cError> {
+    twi().twcr.write(|w| unsafe { w.bits(TWINT | TWSTA | TWEN) });
+    wait(expected)
+}
+
+/// Send a byte (or an address), checking the bus reports the expected status.
+fn send(byte: u8, expected: u8) -> Result<(), I2cError> {
+    twi().twdr.write(|w| unsafe { w.bits(byte) });
+    twi().twcr.write(|w| unsafe { w.bits(TWINT | TWEN) });
+    wait(expected)
+}
+
+/// Address a device for reading, then receive bytes from it.
+fn receive(address: u8, buffer: &mut [u8]) -> Result<(), I2cError> {
+    send((address << 1) | 1, ADDRESS_READ_ACK)?;
+    let last = buffer.len().saturating_sub(1);
+    for (index, byte) in buffer.iter_mut().enumerate() {
+        // Every byte but the last is acknowledged, asking for the next.
+        let ack = if index < last { TWEA } else { 0 };
+        twi().twcr.write(|w| unsafe { w.bits(TWINT | TWEN | ack) });
+        wait_complete()?;
+        *byte = twi().twdr.read().bits();
+    }
+    Ok(())
+}
+
+/// Send a stop condition, releasing the bus.
+fn stop() {
+    twi().twcr.write(|w| unsafe { w.bits(TWINT | TWSTO | TWEN) });
+}
+
+/// Wait until the bus operation is complete, then check the bus reports the expected status.
+fn wait(expected: u8) -> Result<(), I2cError> {
+    wait_complete()?;
+    let status: u8 = twi().twsr.read().bits();
+    match status & STATUS_MASK {
+        status if status == expected => { Ok(()) },
+        ADDRESS_WRITE_NACK | ADDRESS_READ_NACK => { Err(I2cError::AddressNack) },
+        DATA_WRITE_NACK => { Err(I2cError::DataNack) },
+        ARBITRATION_LOST => { Err(I2cError::ArbitrationLost) },
+        status => { Err(I2cError::Bus(status)) },
+    }
+}
+
+/// Wait until the bus operation is complete, failing if it takes too long.
+fn wait_complete() -> Result<(), I2cError> {
+    let complete = (0..TIMEOUT_CHECKS).any(|_| {
+        let control: u8 = twi().twcr.read().bits();
+        control & TWINT != 0
+    });
+    if complete { Ok(()) } else { Err(I2cError::Timeout) }
+}
@@ -0,0 +1,190 @@
+/// Functionality for receiving the commands of an infrared remote control (e.g. a TV
+///   remote), sent with the NEC protocol.
+///
+/// The IR receiver module (e.g. a VS1838B) is connected to pin D7, which it pulls low
+///   while it receives a burst of the carrier (a "mark"). The pin change interrupt of
+///   the pin times each mark from the start of the previous one, which is enough to
+///   decode the NEC protocol:
+///   * A frame begins with a leader (a 9ms mark and a 4.5ms space, 13.5ms in all),
+///     followed by 32 bits, least significant first: the address, the inverted address
+///     (or a second address byte), the command, and the inverted command.
+///   * A bit 0 is a 562.5us mark and space (1.125ms in all), a bit 1 has a space three
+///     times as long (2.25ms in all).
+///   * While a key is held, a repeat code (a 9ms mark and a 2.25ms space, 11.25ms in
+///     all) is sent every 108ms instead of the frame.
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{mode::{Input, Floating}, portd::PD7};
+use avr_device::atmega328p::{EXINT, PORTD};
+use avr_device::interrupt::{self, Mutex};
+use core::cell::RefCell;
+
+use super::{clock, InputDevice, InputSignal, JoyStickSignal, Key};
+
+// The Pin Change Interrupt Enable bit of the PCICR register, for the pins of Port D (PCINT16-23).
+const PCIE2: u8 = 1 << 2;
+// The pin change mask bit of the PCMSK2 register, and the bit of the PIND register, for pin D7 (PCINT23).
+const PCINT23: u8 = 1 << 7;
+const PIN_D7: u8 = 1 << 7;
+// The ranges of the time from the start of a mark to the start of the next, in microseconds.
+const LEADER_US: (u32, u32) = (12_000, 15_000);
+const REPEAT_US: (u32, u32) = (10_000, 12_500);
+const BIT_0_US: (u32, u32) = (900, 1_400);
+const BIT_1_US: (u32, u32) = (1_900, 2_600);
+// The number of bits of a frame.
+const FRAME_BITS: u8 = 32;
+// The time after the latest frame or repeat code until the key is released. Repeat codes
+//   are sent every 108ms, so this allows for one to be missed.
+const HOLD_MS: u32 = 250;
+
+
+/// The state of the decoder, shared between the IrRemote and the pin change interrupt.
+struct Decoder {
+    /// The time, as returned by `clock::micros`, at which the latest mark began.
+    mark_us: u32,
+    /// The bits of the frame being received, and the number received, if a frame is being received.
+    frame: Option<(u32, u8)>,
+    /// The command of the latest frame received, if any.
+    command: Option<u8>,
+    /// The time, as returned by `clock::millis`, of the latest frame or repeat code.
+    received_ms: u32,
+}
+
+static DECODER: Mutex<RefCell<Decoder>> = Mutex::new(RefCell::new(
+    Decoder { mark_us: 0, frame: None, command: None, received_ms: 0 }
+));
+
+/// Returns whether a time is within a range.
+fn within(time_us: u32, (low, high): (u32, u32)) -> bool {
+    (low <= time_us) & (time_us < high)
+}
+
+
+/// The pin change interrupt of Port D, which decodes the signal of the IR receiver.
+#[avr_device::interrupt(atmega328p)]
+fn PCINT2() {
+    let portd = unsafe { &*PORTD::ptr() };
+    let pind: u8 = portd.pind.read().bits();
+    // Only the start of each mark (the pin falling low) is timed.
+    if pind & PIN_D7 != 0 { return }
+    let now_us = clock::micros();
+    interrupt::free(|cs| {
+        let mut decoder = DECODER.borrow(cs).borrow_mut();
+        let elapsed_us = now_us.wrapping_sub(decoder.mark_us);
+        decoder.mark_us = now_us;
+
+        if within(elapsed_us, LEADER_US) {
+            decoder.frame = Some((0, 0));
+        } else if within(elapsed_us, REPEAT_US) {
+            decoder.frame = None;
+            if decoder.command.is_some() { decoder.received_ms = clock::millis() }
+        } else if let Some((bits, count)) = decoder.frame {
+            let bit = if within(elapsed_us, BIT_0_US) {
+                0
+            } else if within(elapsed_us, BIT_1_US) {
+                1
+            } else {
+                // Not a bit, so the frame is corrupt.
+                decoder.frame = None;
+                return
+            };
+            let (bits, count) = (bits | (bit << count), count + 1);
+            decoder.frame = if count < FRAME_BITS { Some((bits, count)) } else { None };
+            if count == FRAME_BITS {
+                // The command is followed by its inverse, so a corrupt frame is ignored.
+                let [_, _, command, inverse] = bits.to_le_bytes();
+                if command == !inverse {
+                    decoder.command = Some(command);
+                    decoder.received_ms = clock::millis();
+                }
+            }
+        }
+    })
+}
+
+
+/// Object describing the input received from a remote control.
+#[derive(Copy, Clone)]
+pub struct RemoteSignal {
+    /// The command of the key held on the remote control.
+    pub command: u8,
+    /// The key of the keymap the command stands for, if any.
+    pub key: Option<Key>,
+}
+
+impl RemoteSignal {
+
+    /// Convert the RemoteSignal into the JoyStickSignal it stands for (that of its key,
+    ///   or a centered JoyStick if the command has no key).
+    pub fn to_joystick(self) -> JoyStickSignal {
+        self.key.map_or(JoyStickSignal { horiz: 0, vert: 0, button: false }, Key::to_joystick)
+    }
+}
+
+
+/// The commands of a remote control which stand for each key, as (command, key) pairs.
+pub type RemoteKeymap = [(u8, Key); 5];
+
+/// The keymap of the common 21-key NEC remote control (as sold with Arduino kits),
+///   whose 2, 8, 4, and 6 keys are the directions, and 5 key is Select.
+pub const DEFAULT_REMOTE_KEYMAP: RemoteKeymap = [
+    (0x18, Key::Up),
+    (0x52, Key::Down),
+    (0x08, Key::Left),
+    (0x5A, Key::Right),
+    (0x1C, Key::Select),
+];
+
+
+/// Object that interfaces with an infrared receiver, receiving from a remote control.
+pub struct IrRemote {
+    // Digital pin the IR receiver is connected to. It is read by the pin change interrupt.
+    _pin: PD7<Input<Floating>>,
+    // The keys of the commands of the remote control.
+    keymap: RemoteKeymap,
+}
+
+impl IrRemote {
+
+    /// Creates a new IrRemote object, enabling the pin change interrupt of its pin.
+    ///
+    /// Interrupts must be enabled globally for the remote control to be received.
+    ///
+    /// # Arguments
+    /// * pin    - The pin D7, which the IR receiver is connected to.
+    /// * keymap - The keys of the commands of the remote control, e.g. `DEFAULT_REMOTE_KEYMAP`.
+    pub fn new(pin: PD7<Input<Floating>>, keymap: RemoteKeymap) -> Self {
+        let exint = unsafe { &*EXINT::ptr() };
+        interrupt::free(|_| {
+            let pcmsk2: u8 = exint.pcmsk2.read().bits();
+            exint.pcmsk2.write(|w| unsafe { w.bits(pcmsk2 | PCINT23) });
+            let pcicr: u8 = exint.pcicr.read().bits();
+            exint.pcicr.write(|w| unsafe { w.bits(pcicr | PCIE2) });
+        });
+        IrRemote { _pin: pin, keymap }
+    }
+}
+
+
+impl InputDevice for IrRemote {
+
+    /// Read the input data from the IrRemote Peripheral.
+    ///
+    /// A key of the remote control is held from its frame until shortly after its
+    ///   last repeat code.
+    /// 
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the signal is decoded by an interrupt).
+    /// 
+    /// # Returns
+    /// Option<InputSignal::Remote>
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let (command, received_ms) = interrupt::free(|cs| {
+            let decoder = DECODER.borrow(cs).borrow();
+            (decoder.command, decoder.received_ms)
+        });
+        if clock::elapsed_since(received_ms) >= HOLD_MS { return None }
+        let command = command?;
+        let key = self.keymap.iter().find(|&&(code, _)| code == command).map(|&(_, key)| key);
+        Some(InputSignal::Remote(RemoteSignal { command, key }))
+    }
+}
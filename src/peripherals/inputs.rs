@@ -1,11 +1,52 @@
 /// Functionality having to do with receiving "InputSignals" from peripherals.
 use arduino_uno::adc::Adc;
-use super::JoyStickSignal;
+use super::{
+    clock, watchdog, ButtonsSignal, DistanceSignal, JoyStickSignal, Key, KeyboardSignal, PaddleSignal, RemoteSignal,
+    TiltSignal,
+};
 
 
 /// An enumeration of the possible "InputSignals".
 pub enum InputSignal {
-    JoyStick(JoyStickSignal)
+    JoyStick(JoyStickSignal),
+    /// A push-button is pressed (a button only signals while it is pressed).
+    Button,
+    /// A key of a keypad is pressed.
+    Keypad(Key),
+    /// A key of a remote control is held.
+    Remote(RemoteSignal),
+    /// The board is tilted (or shaken).
+    Tilt(TiltSignal),
+    /// A potentiometer paddle is turned to a new position.
+    Paddle(PaddleSignal),
+    /// Buttons read through shift registers are pressed.
+    Buttons(ButtonsSignal),
+    /// A key is typed on a terminal, over the serial connection.
+    Serial(Key),
+    /// An ultrasonic sensor measures a new distance (e.g. to a hand held over it).
+    Distance(DistanceSignal),
+    /// Keys are held on a PS/2 keyboard.
+    Keyboard(KeyboardSignal),
+}
+
+impl InputSignal {
+
+    /// Convert the InputSignal into the JoyStickSignal it stands for, so that any device
+    ///   can take the place of a JoyStick (e.g. a button is a JoyStick button, centered).
+    pub fn to_joystick(&self) -> JoyStickSignal {
+        match self {
+            InputSignal::JoyStick(signal) => { *signal },
+            InputSignal::Button => { JoyStickSignal { horiz: 0, vert: 0, button: true } },
+            InputSignal::Keypad(key) => { key.to_joystick() },
+            InputSignal::Remote(signal) => { signal.to_joystick() },
+            InputSignal::Tilt(signal) => { signal.to_joystick() },
+            InputSignal::Paddle(signal) => { signal.to_joystick() },
+            InputSignal::Buttons(signal) => { signal.to_joystick() },
+            InputSignal::Serial(key) => { key.to_joystick() },
+            InputSignal::Distance(signal) => { signal.to_joystick() },
+            InputSignal::Keyboard(signal) => { signal.to_joystick() },
+        }
+    }
 }
 
 
@@ -14,20 +55,94 @@ pub trait InputDevice {
 
     /// Read input data.
     /// 
+    /// The ADC is shared with the background JoyStick sampler (see `sampler`), so this
+    ///   must be called with the ADC claimed from it, as `read_claimed` does.
+    /// 
     /// # Arguments
     /// * adc - The Analog-Digital convertor required to read analog data.
     fn read(&mut self, adc: &mut Adc) -> Option<InputSignal>;
 }
 
 
+/// Two InputDevices read as one, e.g. a remote control alongside the JoyStick.
+///
+/// The second device, if there is one, is read whenever the first has no input.
+pub struct Paired<A, B> {
+    first: A,
+    second: Option<B>,
+}
+
+impl<A, B> Paired<A, B> {
+
+    /// Construct a new Paired InputDevice.
+    pub fn new(first: A, second: Option<B>) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A, B> InputDevice for Paired<A, B>
+  where A: InputDevice, B: InputDevice
+{
+    fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
+        let signal = self.first.read(adc);
+        if signal.is_some() { return signal }
+        self.second.as_mut().and_then(|second| second.read(adc))
+    }
+}
+
+
+/// An InputDevice which may be absent (e.g. not fitted), in which case it has no input.
+impl<D> InputDevice for Option<D>
+  where D: InputDevice
+{
+    fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
+        self.as_mut().and_then(|device| device.read(adc))
+    }
+}
+
+
+/// One of two InputDevices, chosen when constructed, e.g. a paddle in place of a JoyStick.
+pub enum Either<A, B> {
+    First(A),
+    Second(B),
+}
+
+impl<A, B> InputDevice for Either<A, B>
+  where A: InputDevice, B: InputDevice
+{
+    fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
+        match self {
+            Either::First(first) => { first.read(adc) },
+            Either::Second(second) => { second.read(adc) },
+        }
+    }
+}
+
+
+/// Read input data from an InputDevice with the ADC claimed, so that the background
+///   JoyStick sampler cannot use the ADC partway through the read (while interrupts,
+///   e.g. of the keyboard, are still taken).
+fn read_claimed<D: InputDevice>(device: &mut D, adc: &mut Adc) -> Option<InputSignal> {
+    super::sampler::claim(|| device.read(adc))
+}
+
+
 /// This PollArray is used to record a sequence of InputSignals over a period of time.
-pub type PollArray = arraydeque::ArrayDeque<[InputSignal; 100], arraydeque::Wrapping>;
+///
+/// Only the latest signals of a poll are kept (about the last 32 milliseconds' worth), since
+///   each JoyStick holds one of these in SRAM. This is also why the InputSignals are kept
+///   small (at most 3 bytes of data each).
+pub type PollArray = arraydeque::ArrayDeque<[InputSignal; 32], arraydeque::Wrapping>;
 
 /// This struct wraps an InputDevice, providing functionality for reading streams
 ///   of "InputSignals".
+/// 
+/// Polling feeds the watchdog, since every game loop waits on its input.
 pub struct InputPeripheral<D: InputDevice> {
     device: D,
     deque: PollArray,
+    /// A signal injected (e.g. from the serial console) to be received by the next poll.
+    injected: Option<InputSignal>,
 }
 
 impl<D> InputPeripheral<D>
@@ -37,7 +152,13 @@ impl<D> InputPeripheral<D>
 
     /// Construct a new InputPeripheral.
     pub fn new(device: D) -> Self {
-        Self { device, deque: arraydeque::ArrayDeque::new() }
+        Self { device, deque: arraydeque::ArrayDeque::new(), injected: None }
+    }
+
+    /// Inject an InputSignal, as though it were read from the InputDevice by the next poll.
+    ///   Any signal injected before, and not yet polled, is replaced.
+    pub fn inject(&mut self, signal: InputSignal) {
+        self.injected = Some(signal);
     }
 
     /// Poll the InputDevice, collecting data a period of time.
@@ -51,16 +172,53 @@ impl<D> InputPeripheral<D>
     /// Reference to the PollArray object that recorded all "InputSignals"
     ///   from the InputDevice.
     pub fn poll(&mut self, adc: &mut Adc, duration_ms: usize) -> &PollArray {
+        watchdog::feed();
         self.deque.clear();
-        (0..duration_ms).for_each(|_| {
-            if let Some(signal) = self.device.read(adc) {
+        if let Some(signal) = self.injected.take() { self.deque.push_back(signal); }
+        let start_ms = clock::millis();
+        while clock::elapsed_since(start_ms) < duration_ms as u32 {
+            if let Some(signal) = read_claimed(&mut self.device, adc) {
                 self.deque.push_back(signal);
             };
             arduino_uno::delay_us(Self::POLL_DELAY_US);
-        });
+        }
         &self.deque
     }
 
+    /// Poll this InputDevice alongside another, collecting data from both over
+    ///   the same period of time.
+    /// 
+    /// # Arguments
+    /// * other       - The other InputPeripheral to poll.
+    /// * adc         - The Analog-Digital convertor required to read analog data.
+    /// * duration_ms - The duration of time, in milliseconds, over which
+    ///                   to poll the InputDevices.
+    /// 
+    /// # Returns
+    /// References to the PollArray objects that recorded all "InputSignals"
+    ///   from this InputDevice and the other InputDevice, respectively.
+    pub fn poll_with<'a, E: InputDevice>(
+        &'a mut self, other: &'a mut InputPeripheral<E>, adc: &mut Adc, duration_ms: usize
+    ) -> (&'a PollArray, &'a PollArray) {
+        watchdog::feed();
+        self.deque.clear();
+        other.deque.clear();
+        if let Some(signal) = self.injected.take() { self.deque.push_back(signal); }
+        if let Some(signal) = other.injected.take() { other.deque.push_back(signal); }
+        let start_ms = clock::millis();
+        while clock::elapsed_since(start_ms) < duration_ms as u32 {
+            if let Some(signal) = read_claimed(&mut self.device, adc) {
+                self.deque.push_back(signal);
+            };
+            if let Some(signal) = read_claimed(&mut other.device, adc) {
+                other.deque.push_back(signal);
+            };
+            // Each read takes some time, so wait less to keep a similar polling rate.
+            arduino_uno::delay_us(Self::POLL_DELAY_US / 2);
+        }
+        (&self.deque, &other.deque)
+    }
+
     /// Poll the InputDevice continuously until any "InputSignal" is received.
     ///
     /// # Arguments
@@ -69,8 +227,10 @@ impl<D> InputPeripheral<D>
     /// # Returns
     /// The first "InputSignal" received from the device.
     pub fn poll_until_any(&mut self, adc: &mut Adc) -> InputSignal {
+        if let Some(signal) = self.injected.take() { return signal }
         loop {
-            if let Some(signal) = self.device.read(adc) {
+            watchdog::feed();
+            if let Some(signal) = read_claimed(&mut self.device, adc) {
                 return signal
             }
             arduino_uno::delay_us(Self::POLL_DELAY_US);
@@ -3,8 +3,9 @@ use arduino_uno::adc::Adc;
 use arduino_uno::hal::port::{
     Pin,
     mode::{Analog, Input, Floating},
-    portc::{PC0, PC1},
+    portc::{PC0, PC1, PC3, PC4},
 };
+use embedded_hal::adc::{Channel, OneShot};
 
 use crate::Direction;
 use super::{InputDevice, InputSignal};
@@ -26,20 +27,41 @@ pub struct JoyStickSignal {
 
 impl JoyStickSignal {
 
+    /// Create a JoyStickSignal from the readings of the JoyStick, if there is any input.
+    ///
+    /// # Arguments
+    /// * x      - The ADC reading of the x-axis.
+    /// * y      - The ADC reading of the y-axis.
+    /// * button - Whether the button is pressed.
+    ///
+    /// # Returns
+    /// None if the button is not pressed and neither axis exceeds the threshold value.
+    pub fn from_readings(x: u16, y: u16, button: bool) -> Option<Self> {
+        let signal = JoyStickSignal {
+            horiz: (((x as i16) - CENTER) / 4) as i8,
+            vert: (((y as i16) - CENTER) / 4) as i8,
+            button,
+        };
+        if (signal.button) | (signal.horiz.abs() > THRESHOLD) | (signal.vert.abs() > THRESHOLD) {
+            return Some(signal)
+        }
+        None
+    }
+
     /// Convert the JoyStickSignal object into a single direction, if possible.
     /// 
     /// If no direction exceeds the threshold value, None value is returned.
     pub fn to_single_direction(self) -> Option<Direction> {
         if self.horiz.abs() > self.vert.abs() {
-            if self.horiz < -JoyStick::THRESHOLD {
+            if self.horiz < -THRESHOLD {
                 return Some(Direction::Left)
-            } else if self.horiz > JoyStick::THRESHOLD {
+            } else if self.horiz > THRESHOLD {
                 return Some(Direction::Right)
             }
         }
-        if self.vert < -JoyStick::THRESHOLD {
+        if self.vert < -THRESHOLD {
             return Some(Direction::Down)
-        } else if self.vert > JoyStick::THRESHOLD {
+        } else if self.vert > THRESHOLD {
             return Some(Direction::Up)
         }
         None
@@ -47,32 +69,41 @@ impl JoyStickSignal {
 }
 
 
-/// Object that interfaces with the JoyStick peripheral.
-pub struct JoyStick {
+/// The reading of a centered JoyStick axis.
+const CENTER: i16 = 512;
+/// The magnitude a JoyStick axis must exceed to register as a direction.
+pub const THRESHOLD: i8 = 50;
+
+
+/// Object that interfaces with a JoyStick peripheral.
+/// 
+/// The type parameters are the analog pins which read the x-axis and y-axis values.
+pub struct JoyStick<X, Y> {
     // Analog pin that reads x-axis values.
-    x_axis: PC0<Analog>,
+    x_axis: X,
     // Analog pin that reads y-axis values.
-    y_axis: PC1<Analog>,
+    y_axis: Y,
     // Digital pin that reads button presses.
     z_axis: Pin<Input<Floating>>,
 }
 
-impl JoyStick {
-    const CENTER: i16 = 512;
-    pub const THRESHOLD: i8 = 50;
+/// The JoyStick of the first player, with axes on pins A0 and A1.
+pub type JoyStick1 = JoyStick<PC0<Analog>, PC1<Analog>>;
+/// The JoyStick of the second player, with axes on pins A3 and A4.
+pub type JoyStick2 = JoyStick<PC3<Analog>, PC4<Analog>>;
+
+impl<X, Y> JoyStick<X, Y> {
 
     /// Creates a new JoyStick object.
-    pub fn new(
-        x_axis: PC0<Analog>,
-        y_axis: PC1<Analog>,
-        z_axis: Pin<Input<Floating>>,
-    ) -> Self {
+    pub fn new(x_axis: X, y_axis: Y, z_axis: Pin<Input<Floating>>) -> Self {
         JoyStick { x_axis, y_axis, z_axis }
     }
 }
 
 
-impl InputDevice for JoyStick {
+impl<X, Y> InputDevice for JoyStick<X, Y>
+  where X: Channel<Adc>, Y: Channel<Adc>, Adc: OneShot<Adc, u16, X> + OneShot<Adc, u16, Y>
+{
 
     /// Read the input data from the JoyStick Peripheral.
     /// 
@@ -82,17 +113,10 @@ impl InputDevice for JoyStick {
     /// # Returns
     /// Option<InputSignal::JoyStick>
     fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
-        let x: u16 = nb::block!(adc.read(&mut self.x_axis)).void_unwrap();
-        let y: u16 = nb::block!(adc.read(&mut self.y_axis)).void_unwrap();
+        // A failed reading is treated as a centered axis.
+        let x: u16 = nb::block!(adc.read(&mut self.x_axis)).unwrap_or(CENTER as u16);
+        let y: u16 = nb::block!(adc.read(&mut self.y_axis)).unwrap_or(CENTER as u16);
         let z: bool = self.z_axis.is_low().void_unwrap();
-        let signal = JoyStickSignal {
-            horiz: (((x as i16) - Self::CENTER) / 4) as i8,
-            vert: (((y as i16) - Self::CENTER) / 4) as i8,
-            button: z,
-        };
-        if (signal.button) | (signal.horiz.abs() > Self::THRESHOLD) | (signal.vert.abs() > Self::THRESHOLD) {
-            return Some(InputSignal::JoyStick(signal))
-        }
-        None
+        JoyStickSignal::from_readings(x, y, z).map(InputSignal::JoyStick)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,120 @@
+/// Functionality for reading an analog keypad: several buttons on a single ADC pin.
+///
+/// The buttons of the keypad are wired as a resistor ladder, each one pulling the pin to
+///   a different voltage, so the reading of the pin tells which button is pressed (the
+///   pin is pulled up to the supply voltage while none are).
+use arduino_uno::adc::Adc;
+use embedded_hal::adc::{Channel, OneShot};
+
+use crate::Direction;
+use super::{InputDevice, InputSignal, JoyStickSignal};
+
+
+/// An enumeration of the keys of a keypad.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+}
+
+impl Key {
+
+    /// Convert the key into the JoyStickSignal it stands for: a direction pushed all the
+    ///   way, or the button (for Select).
+    pub fn to_joystick(self) -> JoyStickSignal {
+        let (horiz, vert) = match self.to_direction() {
+            Some(Direction::Up) => { (0, i8::MAX) },
+            Some(Direction::Down) => { (0, -i8::MAX) },
+            Some(Direction::Left) => { (-i8::MAX, 0) },
+            Some(Direction::Right) => { (i8::MAX, 0) },
+            None => { (0, 0) },
+        };
+        JoyStickSignal { horiz, vert, button: self == Key::Select }
+    }
+
+    /// Returns the direction of the key, if it has one.
+    pub fn to_direction(self) -> Option<Direction> {
+        match self {
+            Key::Up => { Some(Direction::Up) },
+            Key::Down => { Some(Direction::Down) },
+            Key::Left => { Some(Direction::Left) },
+            Key::Right => { Some(Direction::Right) },
+            Key::Select => { None },
+        }
+    }
+}
+
+
+/// The calibration of a keypad: the key of each range of ADC readings, as the reading
+///   below which each key is pressed, in ascending order. Readings above every threshold
+///   are no key pressed.
+pub type LadderThresholds = [(u16, Key); 5];
+
+/// The calibration of the common keypad (e.g. of the LCD keypad shield), whose keys read
+///   about 0 (Right), 140 (Up), 330 (Down), 500 (Left), and 740 (Select), with thresholds
+///   halfway between them.
+pub const DEFAULT_LADDER_THRESHOLDS: LadderThresholds = [
+    (70, Key::Right),
+    (235, Key::Up),
+    (415, Key::Down),
+    (620, Key::Left),
+    (880, Key::Select),
+];
+
+
+/// Object that interfaces with a 5-button analog (resistor ladder) keypad.
+/// 
+/// The type parameter is the analog pin which reads the keypad.
+pub struct LadderKeypad<P> {
+    // Analog pin that reads the keypad.
+    pin: P,
+    // The calibration of the keypad.
+    thresholds: LadderThresholds,
+    // The key of the previous reading, if any.
+    previous: Option<Key>,
+}
+
+impl<P> LadderKeypad<P> {
+
+    /// Creates a new LadderKeypad object.
+    ///
+    /// # Arguments
+    /// * pin        - The analog pin which reads the keypad.
+    /// * thresholds - The calibration of the keypad, e.g. `DEFAULT_LADDER_THRESHOLDS`.
+    pub fn new(pin: P, thresholds: LadderThresholds) -> Self {
+        LadderKeypad { pin, thresholds, previous: None }
+    }
+
+    /// Returns the key of an ADC reading, if any.
+    fn key_of(&self, reading: u16) -> Option<Key> {
+        self.thresholds.iter().find(|&&(threshold, _)| reading < threshold).map(|&(_, key)| key)
+    }
+}
+
+
+impl<P> InputDevice for LadderKeypad<P>
+  where P: Channel<Adc>, Adc: OneShot<Adc, u16, P>
+{
+
+    /// Read the input data from the LadderKeypad Peripheral.
+    /// 
+    /// While a key is pressed or released, the reading passes through the readings of
+    ///   other keys, so a key is only signalled once two successive readings agree.
+    /// 
+    /// # Arguments
+    /// * adc - The Analog-Digital convertor required to read analog data.
+    /// 
+    /// # Returns
+    /// Option<InputSignal::Keypad>
+    fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
+        // A failed reading is treated as no key pressed.
+        let reading: u16 = nb::block!(adc.read(&mut self.pin)).unwrap_or(u16::MAX);
+        let key = self.key_of(reading);
+        let steady = key == self.previous;
+        self.previous = key;
+        if steady { key.map(InputSignal::Keypad) } else { None }
+    }
+}
@@ -0,0 +1,72 @@
+/// Functionality for sensing the ambient light with an LDR (light dependent resistor),
+///   so that the brightness of the display can follow it (see `Settings::AUTO_BRIGHTNESS`).
+///
+/// The LDR is wired between 5V and an analog pin, with a fixed resistor (e.g. 10k) between
+///   the pin and GND, so the brighter the light, the higher the reading. The pin is read
+///   by its ADC channel, as the background JoyStick sampler reads its pins (see `sampler`).
+use super::{clock, sampler, DotDisplay};
+
+// The ambient light is read at most once every this many milliseconds.
+const READ_INTERVAL_MS: u32 = 500;
+// The range of the readings (out of 1024) of each brightness level.
+const LEVEL_RANGE: u16 = 1024 / DotDisplay::BRIGHTNESS_LEVELS as u16;
+// How far (out of 1024) the reading must move beyond the range of the brightness level
+//   before the level changes, so that it doesn't flicker between two levels.
+const HYSTERESIS: u16 = 16;
+
+
+/// Object that interfaces with an LDR ambient light sensor.
+pub struct LightSensor {
+    // The ADC channel of the analog pin which reads the LDR (e.g. 4 for A4).
+    channel: u8,
+    // The smoothed reading of the ambient light.
+    reading: u16,
+    // The brightness level of the display suited to the ambient light.
+    level: u8,
+    // The time, as returned by `clock::millis`, at which the ambient light was last read.
+    read_ms: u32,
+}
+
+impl LightSensor {
+
+    /// Creates a new LightSensor object, reading the ambient light.
+    ///
+    /// # Arguments
+    /// * channel - The ADC channel of the analog pin which reads the LDR (e.g. 4 for A4).
+    pub fn new(channel: u8) -> Self {
+        let mut sensor = Self { channel, reading: 0, level: 0, read_ms: clock::millis() };
+        sensor.reading = sensor.read();
+        sensor.level = (sensor.reading / LEVEL_RANGE) as u8;
+        sensor
+    }
+
+    /// Returns the brightness level of the display suited to the ambient light,
+    ///   see `DotDisplay::set_brightness`.
+    pub fn brightness(&self) -> u8 {
+        self.level
+    }
+
+    /// Read the ambient light, if it is time to, updating the suited brightness level.
+    ///
+    /// The readings are smoothed, so a passing shadow hardly changes the level.
+    ///
+    /// # Returns
+    /// Whether the suited brightness level changed.
+    pub fn update(&mut self) -> bool {
+        if clock::elapsed_since(self.read_ms) < READ_INTERVAL_MS { return false }
+        self.read_ms = clock::millis();
+        self.reading = (self.reading * 3 + self.read()) / 4;
+
+        let low = self.level as u16 * LEVEL_RANGE;
+        let beyond = (self.reading + HYSTERESIS < low) | (self.reading >= low + LEVEL_RANGE + HYSTERESIS);
+        if !beyond { return false }
+        self.level = (self.reading / LEVEL_RANGE).min(DotDisplay::BRIGHTNESS_LEVELS as u16 - 1) as u8;
+        true
+    }
+
+    /// Read the LDR.
+    fn read(&self) -> u16 {
+        // The ADC is shared with the background JoyStick sampler, see `sampler`.
+        sampler::read_channel(self.channel, 1)
+    }
+}
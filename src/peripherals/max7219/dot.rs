@@ -1,3 +1,4 @@
+use crate::Direction;
 use super::DotScreen;
 
 /// LED on the DotDisplay.
@@ -69,6 +70,20 @@ impl Dot {
         Self { x: self.x, y }
     }
 
+    /// Returns the Dot neighbouring the current dot in the provided direction.
+    /// 
+    /// If the current dot is at the corresponding edge of the screen,
+    ///   this returns the current dot. This simulates "hitting"
+    ///   the wall.
+    pub fn step(&self, direction: Direction) -> Self {
+        match direction {
+            Direction::Left => { self.left() },
+            Direction::Right => { self.right() },
+            Direction::Up => { self.up() },
+            Direction::Down => { self.down() },
+        }
+    }
+
     /// Moves the current Dot to the Left.
     /// 
     /// If the current dot is at the Left edge of the screen,
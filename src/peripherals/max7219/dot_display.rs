@@ -1,5 +1,6 @@
 use arduino_uno::prelude::*;
-use arduino_uno::hal::port::{Pin, mode::Output};
+use arduino_uno::hal::port::{Pin, mode::Output, portb::{PB3, PB5}};
+use arduino_uno::pac::{SPI, spi::RegisterBlock};
 
 use super::DotScreen;
 
@@ -7,6 +8,7 @@ use super::DotScreen;
 #[derive(Clone, Copy)]
 #[repr(u8)]
 enum RegisterAddress {
+    NoOp = 0x0,
     Column1 = 0x1,
     Column2 = 0x2,
     Column3 = 0x3,
@@ -22,14 +24,109 @@ enum RegisterAddress {
     Test = 0xF,
 }
 
+/// The serial bus the data is sent to the display over.
+enum Bus {
+    /// Every bit is clocked out by toggling a pair of pins.
+    BitBanged {
+        // The clock pin.
+        clk: Pin<Output>,
+        // The data input-output pin.
+        dio: Pin<Output>,
+    },
+    /// The bytes are shifted out by the SPI peripheral of the microcontroller.
+    Hardware {
+        spi: SPI,
+        // The SCK (clock) pin, D13. This must be an output for the SPI to drive it.
+        _sck: PB5<Output>,
+        // The MOSI (data) pin, D11. This must be an output for the SPI to drive it.
+        _mosi: PB3<Output>,
+    },
+    /// The bytes are shifted out by the SPI peripheral, owned by another DotDisplay.
+    SharedHardware,
+}
+
+/// A turn of the screens shown on a DotDisplay, clockwise.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Rotation {
+    Rotate0,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+/// The orientation of a DotDisplay: the transform applied to every screen it shows, to undo
+///   the way its module is mounted (e.g. Rotate270 for a module soldered a quarter turn
+///   clockwise), so that the games needn't know.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Orientation {
+    /// The turn of the screens.
+    pub rotation: Rotation,
+    /// Whether the screens are mirrored left to right (before they are turned).
+    pub mirrored: bool,
+}
+
+impl Orientation {
+
+    /// The orientation of a module mounted the right way up, which shows the screens as they are.
+    pub const UPRIGHT: Self = Self::new(Rotation::Rotate0, false);
+
+    /// Creates a new Orientation object.
+    pub const fn new(rotation: Rotation, mirrored: bool) -> Self {
+        Orientation { rotation, mirrored }
+    }
+
+    /// Returns a screen transformed by the orientation.
+    pub fn apply(self, screen: &DotScreen) -> DotScreen {
+        let screen = if self.mirrored { screen.flip_horizontal() } else { *screen };
+        match self.rotation {
+            Rotation::Rotate0 => { screen },
+            Rotation::Rotate90 => { screen.rotate90() },
+            Rotation::Rotate180 => { screen.rotate180() },
+            Rotation::Rotate270 => { screen.rotate270() },
+        }
+    }
+
+    /// Returns a single column of a screen transformed by the orientation (see `apply`),
+    ///   without transforming the rest of the screen.
+    fn column(self, screen: &DotScreen, x: usize) -> u8 {
+        if self == Self::UPRIGHT { return screen.columns[x] }
+        let last = DotScreen::WIDTH - 1;
+        (0..DotScreen::HEIGHT).fold(0, |column, y| {
+            // The dot of the screen which is turned (and mirrored) onto the dot (x, y).
+            let (source_x, source_y) = match self.rotation {
+                Rotation::Rotate0 => { (x, y) },
+                Rotation::Rotate90 => { (last - y, x) },
+                Rotation::Rotate180 => { (last - x, last - y) },
+                Rotation::Rotate270 => { (y, last - x) },
+            };
+            let source_x = if self.mirrored { last - source_x } else { source_x };
+            if screen.is_on(source_x, source_y) { column | (1 << (7 - y)) } else { column }
+        })
+    }
+}
+
+/// The number of panels of a chain whose columns are remembered, see `DotDisplay::show_panel`.
+///   The columns of any further panels are always sent.
+const REMEMBERED_PANELS: usize = 4;
+
 /// The object the interfaces with the MAX7219 8x8 LED Dot Display peripheral.
 pub struct DotDisplay {
     // The chip select pin.
     cs: Pin<Output>,
-    // The clock pin.
-    clk: Pin<Output>,
-    // The data input-output pin.
-    dio: Pin<Output>,
+    // The bus the data is sent over.
+    bus: Bus,
+    // The number of panels (MAX7219 modules) daisy-chained together.
+    panels: usize,
+    // The current (perceived) brightness level of the LED lights.
+    brightness: u8,
+    // The orientation of the panels, applied to the screens shown.
+    orientation: Orientation,
+    // The columns last sent to each of the first `REMEMBERED_PANELS` panels, so that only
+    //   the columns which change are sent again.
+    shown: [[u8; 8]; REMEMBERED_PANELS],
+    // The panels whose columns may differ from those remembered (one bit per panel),
+    //   which are sent in full.
+    stale: u8,
 }
 
 impl DotDisplay {
@@ -38,6 +135,20 @@ impl DotDisplay {
         RegisterAddress::Column5, RegisterAddress::Column6, RegisterAddress::Column7, RegisterAddress::Column8,
     ];
 
+    /// The number of perceived brightness levels, see `set_brightness`.
+    pub const BRIGHTNESS_LEVELS: u8 = 8;
+
+    /// The brightness level the display is initialized with.
+    pub const DEFAULT_BRIGHTNESS: u8 = 6;
+
+    /// Gamma-corrected mapping from perceived brightness level to intensity level.
+    /// 
+    /// The MAX7219 intensity levels are linear steps of PWM duty cycle ((2n + 1) / 32),
+    ///   but the eye perceives brightness on a (roughly gamma 2.2) curve, so the low
+    ///   intensity levels appear to jump while the high levels appear nearly identical.
+    ///   These intensity levels are chosen to be evenly spaced in perceived brightness.
+    const GAMMA_TABLE: [u8; Self::BRIGHTNESS_LEVELS as usize] = [0, 1, 2, 4, 6, 8, 11, 15];
+
     /// Create a new DotDisplay object.
     /// 
     /// # Arguments
@@ -46,15 +157,115 @@ impl DotDisplay {
     /// * `clock_pin`       - The pin used as the clock for the SPI data transfers.
     /// * `data_io_pin`     - The pin used to transmit data. 
     pub fn new(
+        chip_select_pin: Pin<Output>,
+        clock_pin: Pin<Output>,
+        data_io_pin: Pin<Output>,
+    ) -> Self {
+        Self::new_chain(chip_select_pin, clock_pin, data_io_pin, 1)
+    }
+
+    /// Create a new DotDisplay object of several MAX7219 modules daisy-chained together
+    ///   (each one's DOUT wired to the next one's DIN), e.g. four for an 8x32 display.
+    ///
+    /// Every panel (module) of the chain shares the chip select and clock pins. The panels
+    ///   are numbered along the chain, the panel wired to the microcontroller being panel 0.
+    ///
+    /// # Arguments
+    /// 
+    /// * `chip_select_pin` - The pin used to select this DotDisplay.
+    /// * `clock_pin`       - The pin used as the clock for the SPI data transfers.
+    /// * `data_io_pin`     - The pin used to transmit data. 
+    /// * `panels`          - The number of panels of the chain (at least 1).
+    pub fn new_chain(
         mut chip_select_pin: Pin<Output>,
         mut clock_pin: Pin<Output>,
         mut data_io_pin: Pin<Output>,
+        panels: usize,
     ) -> Self {
         // Initialize the pin digital outputs.
         chip_select_pin.set_high().void_unwrap();
         clock_pin.set_low().void_unwrap();
         data_io_pin.set_low().void_unwrap();
-        Self { cs: chip_select_pin, clk: clock_pin, dio: data_io_pin }.init()
+        let bus = Bus::BitBanged { clk: clock_pin, dio: data_io_pin };
+        Self::from_bus(chip_select_pin, bus, panels)
+    }
+
+    /// Create a new DotDisplay object, sending the data with the hardware SPI peripheral
+    ///   rather than toggling the pins in software, which writes frames much faster.
+    ///
+    /// The SPI runs as the bus master, in mode 0 at 8 MHz (the MAX7219 accepts up to 10 MHz).
+    ///   The SS pin (D10) must be an output for the SPI to remain the master, so it should
+    ///   be the chip select pin.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi`             - The SPI peripheral, which is dedicated to the display.
+    /// * `chip_select_pin` - The pin used to select this DotDisplay.
+    /// * `clock_pin`       - The SCK pin (D13), the clock for the SPI data transfers.
+    /// * `data_out_pin`    - The MOSI pin (D11), used to transmit data.
+    pub fn new_hardware_spi(
+        spi: SPI,
+        chip_select_pin: Pin<Output>,
+        clock_pin: PB5<Output>,
+        data_out_pin: PB3<Output>,
+    ) -> Self {
+        Self::new_hardware_spi_chain(spi, chip_select_pin, clock_pin, data_out_pin, 1)
+    }
+
+    /// Create a new DotDisplay object of daisy-chained panels (see `new_chain`), sending
+    ///   the data with the hardware SPI peripheral (see `new_hardware_spi`).
+    ///
+    /// # Arguments
+    ///
+    /// * `spi`             - The SPI peripheral, which is dedicated to the display.
+    /// * `chip_select_pin` - The pin used to select this DotDisplay.
+    /// * `clock_pin`       - The SCK pin (D13), the clock for the SPI data transfers.
+    /// * `data_out_pin`    - The MOSI pin (D11), used to transmit data.
+    /// * `panels`          - The number of panels of the chain (at least 1).
+    pub fn new_hardware_spi_chain(
+        spi: SPI,
+        mut chip_select_pin: Pin<Output>,
+        clock_pin: PB5<Output>,
+        data_out_pin: PB3<Output>,
+        panels: usize,
+    ) -> Self {
+        chip_select_pin.set_high().void_unwrap();
+        // Enable the SPI as the master, sending the most significant bit first (mode 0, f_osc/2).
+        spi.spcr.write(|w| w.spe().set_bit().mstr().set_bit());
+        spi.spsr.write(|w| w.spi2x().set_bit());
+        let bus = Bus::Hardware { spi, _sck: clock_pin, _mosi: data_out_pin };
+        Self::from_bus(chip_select_pin, bus, panels)
+    }
+
+    /// Create another DotDisplay object on the same SPI bus as this one, selected by its own
+    ///   chip select pin (e.g. a second display, as a scoreboard).
+    ///
+    /// Only a display driven by the hardware SPI peripheral can share its bus, since the
+    ///   clock and data pins of a bit-banged display are owned by it.
+    ///
+    /// # Arguments
+    ///
+    /// * `chip_select_pin` - The pin used to select the new DotDisplay.
+    ///
+    /// # Returns
+    /// The new DotDisplay, or None if this display isn't driven by the hardware SPI.
+    pub fn share_spi(&self, mut chip_select_pin: Pin<Output>) -> Option<Self> {
+        if let Bus::BitBanged { .. } = self.bus { return None }
+        chip_select_pin.set_high().void_unwrap();
+        Some(Self::from_bus(chip_select_pin, Bus::SharedHardware, 1))
+    }
+
+    /// Create a new DotDisplay object on a bus, and initialize it (see `init`).
+    fn from_bus(chip_select_pin: Pin<Output>, bus: Bus, panels: usize) -> Self {
+        Self {
+            cs: chip_select_pin,
+            bus,
+            panels: panels.max(1),
+            brightness: 0,
+            orientation: Orientation::UPRIGHT,
+            shown: [[0; 8]; REMEMBERED_PANELS],
+            stale: u8::MAX,
+        }.init()
     }
 
     /// Initialize the dot display by initializing data within its registers.
@@ -78,8 +289,8 @@ impl DotDisplay {
         // Enable all columns.
         self.send_raw_data(RegisterAddress::ScanLimit, 7);
 
-        // Set the intensity of the LEDs to bright, but not full intensity.
-        self.set_intensity(12);
+        // Set the brightness of the LEDs to bright, but not full intensity.
+        self.set_brightness(Self::DEFAULT_BRIGHTNESS);
 
         // Clear display.
         self.clear();
@@ -87,7 +298,28 @@ impl DotDisplay {
         return self
     }
 
-    /// Send raw data to the dot display over the SPI protocol.
+    /// Set the orientation of the display (of each of its panels), e.g. for a module mounted
+    ///   on its side or upside down. The display is created upright.
+    ///
+    /// # Arguments
+    ///
+    /// * `orientation` - The orientation, applied to every screen shown from then on.
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Returns the orientation of the display, see `with_orientation`.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the number of panels of the display (1, unless daisy-chained, see `new_chain`).
+    pub fn panels(&self) -> usize {
+        self.panels
+    }
+
+    /// Send raw data to every panel of the dot display over the SPI protocol.
     /// 
     /// The serial data format uses 16 bits:
     ///  | D15 | D14 | D13 | D12 | D11 | D10 | D09 | D08 | D07 | D06 | D05 | D04 | D03 | D02 | D01 | D00 |
@@ -98,7 +330,9 @@ impl DotDisplay {
     /// The data is expected in MSB order.
     /// Due to the nature of how data is written to the device, 
     ///   only 12 bits of data needs to be written for each serial message, 
-    ///   where D15-D12 are skipped over.
+    ///   where D15-D12 are skipped over. The hardware SPI sends whole bytes,
+    ///   so it sends all 16 bits instead, as does a chain of panels (each one
+    ///   passing the full 16 bits on to the next).
     /// 
     /// # Arguments
     /// 
@@ -106,26 +340,117 @@ impl DotDisplay {
     ///                 address on the device to write the command. 
     /// * `data`     - The data of the command.
     fn send_raw_data(&mut self, register: RegisterAddress, data: u8) {
-        let message = ((register as u16) << 8) | data as u16;
+        self.send_messages(|_| Some((register, data)));
+    }
+
+    /// Send a message to each panel of the chain, within a single chip select.
+    ///
+    /// The message sent first is shifted the furthest along the chain, so the messages
+    ///   are sent from the last panel to the first. A panel without a message is sent
+    ///   a NO-OP, leaving its registers as they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Returns the (register, data) message of a panel, if any.
+    fn send_messages<F: Fn(usize) -> Option<(RegisterAddress, u8)>>(&mut self, message: F) {
+        let first_bit = if self.panels == 1 { 4 } else { 0 };
         self.cs.set_low().void_unwrap();
-        (4..16).for_each(|shift| {
-            if (message & (1 << 15 - shift)) != 0 { 
-                self.dio.set_high().void_unwrap() 
-            } else { 
-                self.dio.set_low().void_unwrap() 
+        for panel in (0..self.panels).rev() {
+            let (register, data) = message(panel).unwrap_or((RegisterAddress::NoOp, 0));
+            match &mut self.bus {
+                Bus::BitBanged { clk, dio } => {
+                    let message = ((register as u16) << 8) | data as u16;
+                    (first_bit..16).for_each(|shift| {
+                        if (message & (1 << 15 - shift)) != 0 { 
+                            dio.set_high().void_unwrap() 
+                        } else { 
+                            dio.set_low().void_unwrap() 
+                        }
+                        clk.set_high().void_unwrap();
+                        clk.set_low().void_unwrap();
+                    });
+                    dio.set_low().void_unwrap();
+                },
+                Bus::Hardware { spi, .. } => {
+                    transfer(spi, register as u8);
+                    transfer(spi, data);
+                },
+                Bus::SharedHardware => {
+                    // The SPI is configured by the display which owns it.
+                    let spi = unsafe { &*SPI::ptr() };
+                    transfer(spi, register as u8);
+                    transfer(spi, data);
+                },
             }
-            self.clk.set_high().void_unwrap();
-            self.clk.set_low().void_unwrap();
-        });
+        }
         self.cs.set_high().void_unwrap();
-        self.dio.set_low().void_unwrap();
     }
     
-    /// Print a DotScreen to the display
+    /// Print a DotScreen to the display, on its first panel.
     pub fn show(&mut self, screen: &DotScreen) {
-        for (&col, &data) in Self::COLUMNS.iter().zip(screen.columns.iter()) {
-            self.send_raw_data(col, data);
+        self.show_panel(0, screen);
+    }
+
+    /// Print a DotScreen to a single panel of a chained display, leaving the others as they are.
+    ///
+    /// Only the columns which differ from those last sent to the panel are sent, which saves
+    ///   most of the time of a frame which changes by a few dots (see `force_refresh`).
+    ///
+    /// # Arguments
+    ///
+    /// * `panel`  - The number of the panel, see `new_chain`. Panels beyond the chain are ignored.
+    /// * `screen` - The screen to show on the panel.
+    pub fn show_panel(&mut self, panel: usize, screen: &DotScreen) {
+        let orientation = self.orientation;
+        for (x, &col) in Self::COLUMNS.iter().enumerate() {
+            let data = orientation.column(screen, x);
+            if !self.is_changed(panel, x, data) { continue }
+            self.send_messages(|index| if index == panel { Some((col, data)) } else { None });
+            self.remember(panel, x, data);
         }
+        self.stale &= !Self::panel_bit(panel);
+    }
+
+    /// Print a row of DotScreens across the panels of a chained display, e.g. a wide game
+    ///   field or a line of text, with a column of every panel written at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `screens` - The screen of each panel, in the order of the chain. Panels without a
+    ///                 screen are left as they are.
+    pub fn show_wide(&mut self, screens: &[DotScreen]) {
+        let orientation = self.orientation;
+        let panels = self.panels.min(screens.len());
+        for (x, &col) in Self::COLUMNS.iter().enumerate() {
+            let data = |panel: usize| orientation.column(&screens[panel], x);
+            // A column is only sent if it changed on any of the panels.
+            if !(0..panels).any(|panel| self.is_changed(panel, x, data(panel))) { continue }
+            self.send_messages(|panel| if panel < panels { Some((col, data(panel))) } else { None });
+            (0..panels).for_each(|panel| self.remember(panel, x, data(panel)));
+        }
+        (0..panels).for_each(|panel| self.stale &= !Self::panel_bit(panel));
+    }
+
+    /// Send every column of the next screens shown, rather than only those which changed,
+    ///   e.g. in case a panel lost what it showed (a glitch of its supply, or it was reconnected).
+    pub fn force_refresh(&mut self) {
+        self.stale = u8::MAX;
+    }
+
+    /// Returns the bit of a panel in the stale panels, see `force_refresh`.
+    fn panel_bit(panel: usize) -> u8 {
+        if panel < REMEMBERED_PANELS { 1 << panel } else { 0 }
+    }
+
+    /// Returns whether a column of a panel differs from the column last sent to it (or may do).
+    fn is_changed(&self, panel: usize, x: usize, data: u8) -> bool {
+        if panel >= REMEMBERED_PANELS { return true }
+        (self.stale & Self::panel_bit(panel) != 0) | (self.shown[panel][x] != data)
+    }
+
+    /// Remember a column sent to a panel.
+    fn remember(&mut self, panel: usize, x: usize, data: u8) {
+        if panel < REMEMBERED_PANELS { self.shown[panel][x] = data }
     }
 
     /// Turn off all the LED lights of the display.
@@ -133,6 +458,8 @@ impl DotDisplay {
         Self::COLUMNS.iter().for_each(|&col| {
             self.send_raw_data( col, 0b00000000);
         });
+        self.shown = [[0; 8]; REMEMBERED_PANELS];
+        self.stale = 0;
     }
 
     /// Set the intensity of the LED lights. 
@@ -146,6 +473,41 @@ impl DotDisplay {
         self.send_raw_data(RegisterAddress::Intensity, level);
     }
 
+    /// Set the perceived brightness of the LED lights.
+    /// 
+    /// Unlike `set_intensity`, the brightness levels are gamma-corrected, such that
+    ///   each level appears evenly spaced to the eye. This should be preferred
+    ///   over `set_intensity` wherever the brightness is adjusted gradually.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `level` - The perceived brightness level of the LED lights.
+    ///             This varies from 0 (lowest) to BRIGHTNESS_LEVELS - 1 (highest).
+    ///             Levels outside this range are clamped to the highest level.
+    pub fn set_brightness(&mut self, level: u8) {
+        let level = level.min(Self::BRIGHTNESS_LEVELS - 1);
+        self.brightness = level;
+        self.set_intensity(Self::GAMMA_TABLE[level as usize]);
+    }
+
+    /// Returns the current perceived brightness level of the LED lights.
+    pub fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    /// Dim the LED lights to a fraction of the intensity of a brightness level, without changing
+    ///   the brightness level, e.g. for a step of a fade (see `Fade`). The intensity levels are
+    ///   finer than the brightness levels, so the fade is smoother.
+    ///
+    /// # Arguments
+    ///
+    /// * `level`    - The perceived brightness level, see `set_brightness`.
+    /// * `fraction` - The fraction of its intensity, from 0 (the lowest intensity) to 255 (all of it).
+    pub fn set_faded(&mut self, level: u8, fraction: u8) {
+        let intensity = Self::GAMMA_TABLE[level.min(Self::BRIGHTNESS_LEVELS - 1) as usize] as u16;
+        self.set_intensity(((intensity * fraction as u16 + 127) / u8::MAX as u16) as u8);
+    }
+
     /// Shutdown the display.
     /// 
     /// This turns the LED lights off but does not overwrite the data for each LED.
@@ -163,3 +525,8 @@ impl DotDisplay {
 }
 
 
+/// Shift a byte out over the hardware SPI, waiting until the transfer is complete.
+fn transfer(spi: &RegisterBlock, byte: u8) {
+    spi.spdr.write(|w| unsafe { w.bits(byte) });
+    while spi.spsr.read().spif().bit_is_clear() {}
+}
@@ -63,6 +63,188 @@ impl DotScreen {
         }
     }
 
+    /// Returns the row of the DotScreen at a y-index (0 is the bottom row and `HEIGHT - 1`
+    ///   the top, as for `Dot::up`), as the bits of its dots: the MSB is the left end of
+    ///   the row, so that the row reads left to right.
+    pub fn row(&self, y: usize) -> u8 {
+        // The dot at a y-index is bit `7 - y` of its column, see `add`.
+        let bit = 1 << (7 - y);
+        self.columns.iter().fold(0, |row, &column| (row << 1) | (column & bit != 0) as u8)
+    }
+
+    /// Set a row of the DotScreen, from the bits of its dots (see `row`).
+    pub fn set_row(&mut self, y: usize, bits: u8) {
+        let bit = 1 << (7 - y);
+        for (x, column) in self.columns.iter_mut().enumerate() {
+            if bits & (0b10000000 >> x) != 0 { *column |= bit } else { *column &= !bit }
+        }
+    }
+
+    /// Create an iterator over the rows of the DotScreen (see `row`), from the bottom row
+    ///   (a y-index of 0) up to the top row.
+    pub fn iter_rows<'d>(&'d self) -> impl Iterator<Item = u8> + 'd {
+        (0..Self::HEIGHT).map(move |y| self.row(y))
+    }
+
+    /// Create an iterator over the columns of the DotScreen, from the left column to the right.
+    pub fn iter_columns<'d>(&'d self) -> impl Iterator<Item = u8> + 'd {
+        self.columns.iter().copied()
+    }
+
+    /// Add a horizontal line of dots, running right from a dot.
+    ///
+    /// Dots off the screen are ignored, as are the dots of the lines below.
+    pub fn draw_h_line(&mut self, left: &Dot, width: usize) {
+        if left.y >= Self::HEIGHT { return }
+        for x in left.x..(left.x + width).min(Self::WIDTH) {
+            self.add(&Dot { x, y: left.y });
+        }
+    }
+
+    /// Add a vertical line of dots, running up from a dot.
+    pub fn draw_v_line(&mut self, bottom: &Dot, height: usize) {
+        if bottom.x >= Self::WIDTH { return }
+        for y in bottom.y..(bottom.y + height).min(Self::HEIGHT) {
+            self.add(&Dot { x: bottom.x, y });
+        }
+    }
+
+    /// Add a rectangle of dots, running right and up from its bottom-left corner.
+    ///
+    /// # Arguments
+    /// * corner - The bottom-left corner of the rectangle.
+    /// * width  - The width of the rectangle, in dots.
+    /// * height - The height of the rectangle, in dots.
+    /// * filled - Whether the inside of the rectangle is filled, rather than only its outline.
+    pub fn draw_rect(&mut self, corner: &Dot, width: usize, height: usize, filled: bool) {
+        if (width == 0) | (height == 0) { return }
+        if filled {
+            for y in corner.y..(corner.y + height) {
+                self.draw_h_line(&Dot { x: corner.x, y }, width);
+            }
+            return
+        }
+        self.draw_h_line(corner, width);
+        self.draw_h_line(&Dot { x: corner.x, y: corner.y + height - 1 }, width);
+        self.draw_v_line(corner, height);
+        self.draw_v_line(&Dot { x: corner.x + width - 1, y: corner.y }, height);
+    }
+
+    /// Add a straight line of dots between two dots (both included), by Bresenham's algorithm.
+    pub fn draw_line(&mut self, a: &Dot, b: &Dot) {
+        let (mut x, mut y) = (a.x as isize, a.y as isize);
+        let (end_x, end_y) = (b.x as isize, b.y as isize);
+        let (dx, dy) = ((end_x - x).abs(), -(end_y - y).abs());
+        let (step_x, step_y) = (if x < end_x { 1 } else { -1 }, if y < end_y { 1 } else { -1 });
+        // The error of the next dot from the true line, scaled to remain an integer.
+        let mut error = dx + dy;
+        loop {
+            if (x < Self::WIDTH as isize) & (y < Self::HEIGHT as isize) {
+                self.add(&Dot { x: x as usize, y: y as usize });
+            }
+            if (x == end_x) & (y == end_y) { break }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Shift every dot of the DotScreen left, e.g. to scroll a background past.
+    ///
+    /// # Arguments
+    /// * n    - The number of dots to shift by.
+    /// * wrap - Whether the dots shifted off the left edge wrap around onto the right edge,
+    ///            rather than the columns at the right edge being left empty (to draw anew).
+    pub fn shift_left(&mut self, n: usize, wrap: bool) {
+        if wrap {
+            self.columns.rotate_left(n % Self::WIDTH);
+        } else {
+            let n = n.min(Self::WIDTH);
+            self.columns.rotate_left(n);
+            self.columns[(Self::WIDTH - n)..].iter_mut().for_each(|column| *column = 0);
+        }
+    }
+
+    /// Shift every dot of the DotScreen right (see `shift_left`).
+    pub fn shift_right(&mut self, n: usize, wrap: bool) {
+        if wrap {
+            self.columns.rotate_right(n % Self::WIDTH);
+        } else {
+            let n = n.min(Self::WIDTH);
+            self.columns.rotate_right(n);
+            self.columns[..n].iter_mut().for_each(|column| *column = 0);
+        }
+    }
+
+    /// Shift every dot of the DotScreen up (see `shift_left`).
+    pub fn shift_up(&mut self, n: usize, wrap: bool) {
+        // A dot moved up (to a higher y-index) moves to a lower bit of its column, see `add`.
+        for column in self.columns.iter_mut() {
+            *column = if wrap {
+                column.rotate_right((n % Self::HEIGHT) as u32)
+            } else {
+                column.checked_shr(n as u32).unwrap_or(0)
+            };
+        }
+    }
+
+    /// Shift every dot of the DotScreen down (see `shift_left`).
+    pub fn shift_down(&mut self, n: usize, wrap: bool) {
+        for column in self.columns.iter_mut() {
+            *column = if wrap {
+                column.rotate_left((n % Self::HEIGHT) as u32)
+            } else {
+                column.checked_shl(n as u32).unwrap_or(0)
+            };
+        }
+    }
+
+    /// Returns the DotScreen mirrored left to right.
+    pub const fn flip_horizontal(&self) -> Self {
+        let c = &self.columns;
+        DotScreen { columns: [c[7], c[6], c[5], c[4], c[3], c[2], c[1], c[0]] }
+    }
+
+    /// Returns the DotScreen mirrored top to bottom.
+    pub const fn flip_vertical(&self) -> Self {
+        let c = &self.columns;
+        DotScreen {
+            columns: [
+                c[0].reverse_bits(), c[1].reverse_bits(), c[2].reverse_bits(), c[3].reverse_bits(),
+                c[4].reverse_bits(), c[5].reverse_bits(), c[6].reverse_bits(), c[7].reverse_bits(),
+            ]
+        }
+    }
+
+    /// Returns the DotScreen turned a quarter turn clockwise, e.g. for a display mounted on its side.
+    pub fn rotate90(&self) -> Self {
+        let mut rotated = Self::new_empty();
+        for dot in self.iter_on() {
+            rotated.add(&Dot { x: dot.y, y: Self::WIDTH - 1 - dot.x });
+        }
+        rotated
+    }
+
+    /// Returns the DotScreen turned a half turn, e.g. for a display mounted upside down.
+    pub const fn rotate180(&self) -> Self {
+        self.flip_horizontal().flip_vertical()
+    }
+
+    /// Returns the DotScreen turned a quarter turn anticlockwise (three quarters clockwise).
+    pub fn rotate270(&self) -> Self {
+        let mut rotated = Self::new_empty();
+        for dot in self.iter_on() {
+            rotated.add(&Dot { x: Self::HEIGHT - 1 - dot.y, y: dot.x });
+        }
+        rotated
+    }
+
     /// Helper function used to determine if a particular dot LED is turned on.
     #[inline(always)]
     pub fn is_dot_on(&self, dot: &Dot) -> bool {
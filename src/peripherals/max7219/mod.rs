@@ -3,5 +3,5 @@ mod dot_display;
 mod dot_screen;
 
 pub use dot::Dot;
-pub use dot_display::DotDisplay;
+pub use dot_display::{DotDisplay, Orientation, Rotation};
 pub use dot_screen::DotScreen;
@@ -1,9 +1,65 @@
+mod accelerometer;
+mod button;
+mod buzzer;
+pub mod clock;
+mod display;
+pub mod eeprom;
+mod events;
+mod grey_screen;
+pub mod haptics;
+mod i2c;
+mod infrared;
 mod inputs;
 mod joystick_ps2;
+mod keypad;
+mod light_sensor;
 mod max7219;
+mod palette;
+mod potentiometer;
+pub mod power;
+pub mod ps2_keyboard;
+pub mod progmem;
 mod random;
+mod rtc;
+mod rumble;
+mod serial_joystick;
+pub mod sampler;
+mod shift_register;
+pub mod sleep;
+mod ssd1306;
+mod tm1637;
+mod touch;
+mod ultrasonic;
+pub mod usart;
+mod ws2812;
+pub mod watchdog;
 
-pub use inputs::{InputDevice, InputPeripheral, InputSignal, PollArray};
-pub use joystick_ps2::{JoyStick, JoyStickSignal};
-pub use max7219::{DotDisplay, DotScreen, Dot};
+pub use accelerometer::{Accelerometer, AccelerometerModel, TiltSignal};
+pub use button::Button;
+pub use buzzer::{Buzzer, Note};
+pub use display::{Display, Fade, FadeKind, GameDisplay};
+pub use events::{EventQueue, InputEvent, InputEvents};
+pub use grey_screen::GreyScreen;
+pub use haptics::{Haptics, HapticPattern};
+pub use i2c::{I2c, I2cError};
+pub use infrared::{IrRemote, RemoteKeymap, RemoteSignal, DEFAULT_REMOTE_KEYMAP};
+pub use inputs::{Either, InputDevice, InputPeripheral, InputSignal, Paired, PollArray};
+pub use joystick_ps2::{JoyStick, JoyStick1, JoyStick2, JoyStickSignal};
+pub use keypad::{Key, LadderKeypad, LadderThresholds, DEFAULT_LADDER_THRESHOLDS};
+pub use light_sensor::LightSensor;
+pub use max7219::{DotDisplay, DotScreen, Dot, Orientation, Rotation};
+pub use palette::{Color, Palette, Tint, TintedScreen};
+pub use potentiometer::{PaddleSignal, Potentiometer};
+pub use ps2_keyboard::{KeyboardSignal, Ps2Keyboard};
 pub use random::XOrShiftPrng;
+pub use rtc::{Date, DateTime, Rtc};
+pub use rumble::Rumble;
+pub use serial_joystick::SerialJoystick;
+pub use shift_register::{
+    ButtonsSignal, GamepadKeymap, ShiftRegisters, DEFAULT_GAMEPAD_KEYMAP, MAX_SHIFT_REGISTERS
+};
+pub use ssd1306::Ssd1306;
+pub use tm1637::Tm1637;
+pub use touch::TouchButton;
+pub use ultrasonic::{DistanceSignal, Ultrasonic};
+pub use ws2812::Ws2812;
@@ -0,0 +1,132 @@
+/// Functionality for declaring the semantic colors of a game's screen.
+///
+/// Games draw onto a TintedScreen, with a layer for each semantic Tint, and describe
+///   the colors of those tints with a Palette. Monochrome displays (such as the MAX7219
+///   DotDisplay) ignore the tints, while RGB displays color each layer from the Palette
+///   (see `Display.show_tinted`).
+use super::{Dot, DotScreen};
+
+
+/// The semantic role of a layer of a screen.
+///
+/// Layers are drawn in the order of the variants, so that later layers cover earlier ones.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Tint {
+    /// The scenery, such as walls and terrain.
+    Background,
+    /// Things the player collects, such as eggs and gems.
+    Pickup,
+    /// Things that harm the player, such as enemies and projectiles.
+    Hazard,
+    /// The characters controlled by the players.
+    Actor,
+    /// Indicators overlaid on the game, such as gauges and cursors.
+    Hud,
+}
+
+impl Tint {
+    /// The number of tints.
+    pub const COUNT: usize = 5;
+}
+
+
+/// A color, described by its red, green, and blue intensities.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+
+    /// Create a new Color from its red, green, and blue intensities.
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+
+/// The colors in which each Tint is displayed.
+#[derive(Copy, Clone)]
+pub struct Palette {
+    /// The colors, indexed by Tint.
+    colors: [Color; Tint::COUNT],
+}
+
+impl Palette {
+
+    /// The Palette used by games which do not declare their own.
+    pub const DEFAULT: Palette = Palette::new(
+        Color::new(0, 0, 64),
+        Color::new(255, 160, 0),
+        Color::new(255, 0, 0),
+        Color::new(0, 255, 0),
+        Color::new(255, 255, 255),
+    );
+
+    /// Create a new Palette from the color of each Tint.
+    pub const fn new(background: Color, pickup: Color, hazard: Color, actor: Color, hud: Color) -> Self {
+        Self { colors: [background, pickup, hazard, actor, hud] }
+    }
+
+    /// Returns the color of the Tint.
+    pub fn color(&self, tint: Tint) -> Color {
+        self.colors[tint as usize]
+    }
+}
+
+
+/// A screen composed of a layer for each Tint.
+#[derive(Copy, Clone)]
+pub struct TintedScreen {
+    /// The layers, indexed by Tint.
+    layers: [DotScreen; Tint::COUNT],
+}
+
+impl TintedScreen {
+
+    /// Create a new TintedScreen with every layer empty.
+    pub const fn new_empty() -> Self {
+        Self { layers: [DotScreen::new_empty(); Tint::COUNT] }
+    }
+
+    /// Add a dot to the layer of the Tint.
+    pub fn add(&mut self, tint: Tint, dot: &Dot) {
+        self.layers[tint as usize].add(dot);
+    }
+
+    /// Clear every layer.
+    pub fn clear(&mut self) {
+        self.layers.iter_mut().for_each(|layer| layer.clear());
+    }
+
+    /// Returns the layer of the Tint, to draw onto it directly (e.g. a whole DotScreen).
+    pub fn layer_mut(&mut self, tint: Tint) -> &mut DotScreen {
+        &mut self.layers[tint as usize]
+    }
+
+    /// Returns the layer of the Tint.
+    pub fn layer(&self, tint: Tint) -> &DotScreen {
+        &self.layers[tint as usize]
+    }
+
+    /// Returns the topmost Tint of the dot, if any layer has the dot on.
+    pub fn tint_at(&self, dot: &Dot) -> Option<Tint> {
+        [Tint::Hud, Tint::Actor, Tint::Hazard, Tint::Pickup, Tint::Background].iter()
+            .find(|&&tint| self.layer(tint).is_dot_on(dot))
+            .copied()
+    }
+
+    /// Flatten the layers into a single monochrome DotScreen.
+    pub fn flatten(&self) -> DotScreen {
+        let mut screen = DotScreen::new_empty();
+        for layer in self.layers.iter() {
+            for (column, layer_column) in screen.columns.iter_mut().zip(layer.columns.iter()) {
+                *column |= layer_column;
+            }
+        }
+        screen
+    }
+}
+
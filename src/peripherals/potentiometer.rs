@@ -0,0 +1,78 @@
+/// Functionality for reading a potentiometer paddle: a knob whose absolute position
+///   (rather than a direction) is the input, e.g. for paddle games.
+use arduino_uno::adc::Adc;
+use embedded_hal::adc::{Channel, OneShot};
+
+use super::{InputDevice, InputSignal, JoyStickSignal};
+
+/// The change of position (out of 255) below which the position is considered unchanged,
+///   so that the noise of the readings isn't input.
+const DEADBAND: u8 = 2;
+
+
+/// Object describing the position of a potentiometer paddle.
+#[derive(Copy, Clone)]
+pub struct PaddleSignal {
+    // The absolute position of the paddle, from 0 (fully counter-clockwise) to 255.
+    pub position: u8,
+}
+
+impl PaddleSignal {
+
+    /// Convert the PaddleSignal into the JoyStickSignal it stands for: the JoyStick pushed
+    ///   Left or Right as far as the paddle is turned from the middle.
+    pub fn to_joystick(self) -> JoyStickSignal {
+        JoyStickSignal { horiz: (self.position as i16 - 128).max(-(i8::MAX as i16)) as i8, vert: 0, button: false }
+    }
+}
+
+
+/// Object that interfaces with a potentiometer paddle.
+///
+/// The type parameter is the analog pin which reads the potentiometer (its wiper).
+pub struct Potentiometer<P> {
+    // Analog pin that reads the potentiometer.
+    pin: P,
+    // The position of the previous signal, if any.
+    position: Option<u8>,
+}
+
+impl<P> Potentiometer<P> {
+
+    /// Creates a new Potentiometer object.
+    ///
+    /// # Arguments
+    /// * pin - The analog pin which reads the potentiometer.
+    pub fn new(pin: P) -> Self {
+        Potentiometer { pin, position: None }
+    }
+}
+
+
+impl<P> InputDevice for Potentiometer<P>
+  where P: Channel<Adc>, Adc: OneShot<Adc, u16, P>
+{
+
+    /// Read the input data from the Potentiometer Peripheral.
+    ///
+    /// The position is only signalled when the paddle is turned (i.e. the first reading,
+    ///   and any which moves the position beyond the deadband), so a resting paddle is
+    ///   no input. The latest signal is the position of the paddle.
+    /// 
+    /// # Arguments
+    /// * adc - The Analog-Digital convertor required to read analog data.
+    /// 
+    /// # Returns
+    /// Option<InputSignal::Paddle>
+    fn read(&mut self, adc: &mut Adc) -> Option<InputSignal> {
+        // A failed reading is treated as no input.
+        let reading: u16 = nb::block!(adc.read(&mut self.pin)).ok()?;
+        let position = (reading >> 2).min(u8::MAX as u16) as u8;
+        let turned = self.position.map_or(true, |previous| {
+            (position as i16 - previous as i16).abs() > DEADBAND as i16
+        });
+        if !turned { return None }
+        self.position = Some(position);
+        Some(InputSignal::Paddle(PaddleSignal { position }))
+    }
+}
@@ -0,0 +1,85 @@
+/// Functionality for monitoring the supply voltage (Vcc), e.g. of a battery-powered
+///   handheld build, so that a low battery can be noticed before the board browns out.
+///
+/// Vcc is measured without any wiring, by reading the internal 1.1V bandgap reference
+///   with the ADC, against AVcc (which is Vcc): the lower Vcc, the higher the reading.
+///   The bandgap reference is only accurate to about 10%, so the threshold of a low
+///   battery may need calibrating against a multimeter. The ADC registers are read
+///   directly, having claimed the ADC from the background JoyStick sampler (see `sampler`).
+use super::{clock, sampler};
+
+// The ADC channel (the multiplexer selection of the ADMUX register) of the bandgap reference.
+const BANDGAP_CHANNEL: u8 = 0b1110;
+// The voltage of the bandgap reference, in millivolts.
+const BANDGAP_MV: u32 = 1100;
+// The voltage is measured at most once every this many milliseconds.
+const READ_INTERVAL_MS: u32 = 5000;
+// How far (in millivolts) the voltage must rise back above the threshold before the
+//   battery is no longer low, so that the indicator doesn't flicker around the threshold.
+const HYSTERESIS_MV: u16 = 100;
+
+
+/// Measure the supply voltage (Vcc).
+///
+/// # Returns
+/// The supply voltage, in millivolts.
+pub fn vcc_millivolts() -> u16 {
+    // The first conversion after switching to the bandgap reference reads low,
+    //   while the reference settles, so it is discarded.
+    let reading = sampler::read_channel(BANDGAP_CHANNEL, 2);
+    (BANDGAP_MV * 1024 / (reading.max(1) as u32)) as u16
+}
+
+
+/// Object that monitors the supply voltage, for a low battery.
+pub struct BatteryMonitor {
+    // The supply voltage, in millivolts, below which the battery is low.
+    threshold_mv: u16,
+    // The supply voltage last measured, in millivolts.
+    millivolts: u16,
+    // Whether the battery is low.
+    low: bool,
+    // The time, as returned by `clock::millis`, at which the voltage was last measured.
+    read_ms: u32,
+}
+
+impl BatteryMonitor {
+
+    /// Creates a new BatteryMonitor object, measuring the supply voltage.
+    ///
+    /// # Arguments
+    /// * threshold_mv - The supply voltage, in millivolts, below which the battery is low
+    ///                    (e.g. 3400 for a single lithium cell).
+    pub fn new(threshold_mv: u16) -> Self {
+        let millivolts = vcc_millivolts();
+        Self { threshold_mv, millivolts, low: millivolts < threshold_mv, read_ms: clock::millis() }
+    }
+
+    /// Returns the supply voltage last measured, in millivolts.
+    pub fn millivolts(&self) -> u16 {
+        self.millivolts
+    }
+
+    /// Returns whether the battery is low.
+    pub fn is_low(&self) -> bool {
+        self.low
+    }
+
+    /// Measure the supply voltage, if it is time to, updating whether the battery is low.
+    ///
+    /// # Returns
+    /// Whether the battery became low (or no longer is).
+    pub fn update(&mut self) -> bool {
+        if clock::elapsed_since(self.read_ms) < READ_INTERVAL_MS { return false }
+        self.read_ms = clock::millis();
+        self.millivolts = vcc_millivolts();
+        let low = if self.low {
+            self.millivolts < self.threshold_mv + HYSTERESIS_MV
+        } else {
+            self.millivolts < self.threshold_mv
+        };
+        let changed = low != self.low;
+        self.low = low;
+        changed
+    }
+}
@@ -0,0 +1,39 @@
+/// Read a byte from program memory (flash) rather than RAM.
+///
+/// The ATmega328P has only 2KB of RAM, but 32KB of flash. Every static is copied into
+///   RAM at start up, unless it is placed in the `.progmem.data` section:
+///
+///   #[link_section = ".progmem.data"]
+///   static TABLE: [u8; 4] = [1, 2, 3, 4];
+///
+/// Such a static stays in flash, which is a separate address space, so it must not be
+///   read directly. Instead, each byte is read with the `lpm` instruction.
+///
+/// # Safety
+/// The address must be within a static placed in the `.progmem.data` section.
+pub unsafe fn read_byte(address: *const u8) -> u8 {
+    #[cfg(target_arch = "avr")]
+    {
+        let value: u8;
+        llvm_asm!("lpm $0, Z" : "=r"(value) : "{Z}"(address));
+        value
+    }
+    // Elsewhere (e.g. when building documentation on the host) there is a single address space.
+    #[cfg(not(target_arch = "avr"))]
+    {
+        *address
+    }
+}
+
+/// Read a value (e.g. a DotScreen, or a table of references) from program memory, byte by byte.
+///
+/// # Safety
+/// The value must be within a static placed in the `.progmem.data` section.
+pub unsafe fn read_value<T: Copy>(address: *const T) -> T {
+    let mut value = core::mem::MaybeUninit::<T>::uninit();
+    let bytes = value.as_mut_ptr() as *mut u8;
+    for offset in 0..core::mem::size_of::<T>() {
+        *bytes.add(offset) = read_byte((address as *const u8).add(offset));
+    }
+    value.assume_init()
+}
@@ -0,0 +1,253 @@
+/// Functionality for receiving the keystrokes of a PS/2 keyboard, both as the keys held
+///   (the arrows or WASD, and space or enter, standing in for the JoyStick) and as the
+///   characters typed (e.g. to enter the initials of a high score, see `take_char`).
+///
+/// The keyboard drives its clock line (on D2), and the data line (on A4) is read on each
+///   falling edge of the clock, by the external interrupt INT0. Each byte is a frame of 11
+///   bits: a start bit (0), the 8 bits of the byte (least significant first), an odd parity
+///   bit, and a stop bit (1). The bytes are the scan codes (of set 2) of the keys: a key
+///   sends its code as it is pressed (repeatedly, while held), and the code preceded by
+///   0xF0 as it is released. The codes of the extended keys (e.g. the arrows) are
+///   preceded by 0xE0. The scan codes are decoded by the interrupt, as they arrive.
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{mode::{Input, PullUp}, portc::PC4, portd::PD2};
+use avr_device::atmega328p::{EXINT, PORTC};
+use avr_device::interrupt::{self, Mutex};
+use core::cell::RefCell;
+
+use super::{clock, InputDevice, InputSignal, JoyStickSignal, Key};
+
+// The Interrupt Sense Control bits of the EICRA register for INT0, selecting the falling edge.
+const ISC01: u8 = 1 << 1;
+const ISC00: u8 = 1 << 0;
+// The External Interrupt Request 0 Enable bit of the EIMSK register.
+const INT0_ENABLE: u8 = 1 << 0;
+// The bit of the data pin (A4) in the PINC register.
+const DATA_PIN: u8 = 1 << 4;
+// The number of bits of a frame.
+const FRAME_BITS: u8 = 11;
+// The most time between the bits of a frame, in microseconds. A longer gap begins a new
+//   frame, so that a bit missed (or a glitch of the clock) only corrupts a single frame.
+const BIT_TIMEOUT_US: u32 = 1000;
+// The scan codes preceding the code of an extended key, and of a released key.
+const EXTENDED: u8 = 0xE0;
+const RELEASE: u8 = 0xF0;
+// The number of characters typed which may be waiting to be taken.
+const TYPED_CAPACITY: usize = 8;
+// The keys which may be held, in the order of their bits of a KeyboardSignal.
+const KEYS: [Key; 5] = [Key::Up, Key::Down, Key::Left, Key::Right, Key::Select];
+
+/// The character typed by the Enter key.
+pub const ENTER: u8 = b'\r';
+/// The character typed by the Backspace key.
+pub const BACKSPACE: u8 = 0x08;
+/// The character typed by the Escape key.
+pub const ESCAPE: u8 = 0x1B;
+
+
+/// Returns the key held by a key of the keyboard, if any: the arrows and WASD are the
+///   directions, and space and enter are Select.
+fn key_of(code: u8, extended: bool) -> Option<Key> {
+    match (extended, code) {
+        (true, 0x75) | (false, 0x1D) => { Some(Key::Up) },
+        (true, 0x72) | (false, 0x1B) => { Some(Key::Down) },
+        (true, 0x6B) | (false, 0x1C) => { Some(Key::Left) },
+        (true, 0x74) | (false, 0x23) => { Some(Key::Right) },
+        (false, 0x29) | (false, 0x5A) | (true, 0x5A) => { Some(Key::Select) },
+        _ => { None },
+    }
+}
+
+/// Returns the character typed by a (not extended) key of the keyboard, if any: the
+///   letters (in upper case), the digits, space, and the `ENTER`, `BACKSPACE`, and `ESCAPE` keys.
+fn char_of(code: u8) -> Option<u8> {
+    // The scan codes of the letters, from A to Z, and of the digits, from 0 to 9.
+    const LETTERS: [u8; 26] = [
+        0x1C, 0x32, 0x21, 0x23, 0x24, 0x2B, 0x34, 0x33, 0x43, 0x3B, 0x42, 0x4B, 0x3A,
+        0x31, 0x44, 0x4D, 0x15, 0x2D, 0x1B, 0x2C, 0x3C, 0x2A, 0x1D, 0x22, 0x35, 0x1A,
+    ];
+    const DIGITS: [u8; 10] = [0x45, 0x16, 0x1E, 0x26, 0x25, 0x2E, 0x36, 0x3D, 0x3E, 0x46];
+    if let Some(index) = LETTERS.iter().position(|&letter| letter == code) { return Some(b'A' + index as u8) }
+    if let Some(index) = DIGITS.iter().position(|&digit| digit == code) { return Some(b'0' + index as u8) }
+    match code {
+        0x29 => { Some(b' ') },
+        0x5A => { Some(ENTER) },
+        0x66 => { Some(BACKSPACE) },
+        0x76 => { Some(ESCAPE) },
+        _ => { None },
+    }
+}
+
+
+/// The state of the receiver, shared between the Ps2Keyboard and the external interrupt.
+struct Receiver {
+    /// The bits of the frame being received, and the number received.
+    bits: u16,
+    count: u8,
+    /// The time, as returned by `clock::micros`, at which the latest bit was received.
+    bit_us: u32,
+    /// Whether the scan code being received is of an extended key, and of a released key.
+    extended: bool,
+    releasing: bool,
+    /// The keys held, one bit per key of `KEYS`.
+    held: u8,
+    /// The characters typed, waiting to be taken, as a ring buffer.
+    typed: [u8; TYPED_CAPACITY],
+    head: usize,
+    len: usize,
+}
+
+impl Receiver {
+
+    /// Decode a scan code received from the keyboard.
+    fn decode(&mut self, code: u8) {
+        match code {
+            EXTENDED => { self.extended = true; return },
+            RELEASE => { self.releasing = true; return },
+            _ => {}
+        }
+        if let Some(key) = key_of(code, self.extended) {
+            let bit = 1 << KEYS.iter().position(|&held| held == key).unwrap_or(0);
+            if self.releasing { self.held &= !bit } else { self.held |= bit }
+        }
+        let typed = if self.extended | self.releasing { None } else { char_of(code) };
+        if let Some(character) = typed {
+            // The oldest character is dropped if no room is left.
+            if self.len == TYPED_CAPACITY {
+                self.head = (self.head + 1) % TYPED_CAPACITY;
+                self.len -= 1;
+            }
+            self.typed[(self.head + self.len) % TYPED_CAPACITY] = character;
+            self.len += 1;
+        }
+        self.extended = false;
+        self.releasing = false;
+    }
+}
+
+static RECEIVER: Mutex<RefCell<Receiver>> = Mutex::new(RefCell::new(Receiver {
+    bits: 0, count: 0, bit_us: 0, extended: false, releasing: false, held: 0,
+    typed: [0; TYPED_CAPACITY], head: 0, len: 0,
+}));
+
+
+/// The external interrupt INT0, which receives a bit from the keyboard on each falling
+///   edge of its clock.
+#[avr_device::interrupt(atmega328p)]
+fn INT0() {
+    let portc = unsafe { &*PORTC::ptr() };
+    let pinc: u8 = portc.pinc.read().bits();
+    let now_us = clock::micros();
+    interrupt::free(|cs| {
+        let mut receiver = RECEIVER.borrow(cs).borrow_mut();
+        if now_us.wrapping_sub(receiver.bit_us) > BIT_TIMEOUT_US { receiver.count = 0 }
+        receiver.bit_us = now_us;
+        let bit = (pinc & DATA_PIN != 0) as u16;
+        let count = receiver.count;
+        receiver.bits = if count == 0 { bit } else { receiver.bits | (bit << count) };
+        receiver.count += 1;
+        if receiver.count < FRAME_BITS { return }
+
+        receiver.count = 0;
+        let bits = receiver.bits;
+        let code = (bits >> 1) as u8;
+        // The start bit is 0, the stop bit is 1, and the byte and parity bit have an odd number of ones.
+        let parity_ok = ((bits >> 1) & 0x1FF).count_ones() % 2 == 1;
+        if (bits & 1 == 0) & (bits & (1 << 10) != 0) & parity_ok { receiver.decode(code) }
+    })
+}
+
+
+/// Take the oldest character typed on the keyboard which is yet to be taken, if any.
+///
+/// The characters are the letters (in upper case), the digits, space, and the
+///   `ENTER`, `BACKSPACE`, and `ESCAPE` keys.
+pub fn take_char() -> Option<u8> {
+    interrupt::free(|cs| {
+        let mut receiver = RECEIVER.borrow(cs).borrow_mut();
+        if receiver.len == 0 { return None }
+        let character = receiver.typed[receiver.head];
+        receiver.head = (receiver.head + 1) % TYPED_CAPACITY;
+        receiver.len -= 1;
+        Some(character)
+    })
+}
+
+/// Discard the characters typed on the keyboard which are yet to be taken.
+pub fn clear_typed() {
+    interrupt::free(|cs| RECEIVER.borrow(cs).borrow_mut().len = 0);
+}
+
+
+/// Object describing the keys held on a keyboard.
+#[derive(Copy, Clone)]
+pub struct KeyboardSignal {
+    // The keys held, one bit per key (Up, Down, Left, Right, then Select, from the lowest bit).
+    pub held: u8,
+}
+
+impl KeyboardSignal {
+
+    /// Returns whether a key is held.
+    pub fn is_held(&self, key: Key) -> bool {
+        KEYS.iter().position(|&held| held == key).map_or(false, |index| self.held & (1 << index) != 0)
+    }
+
+    /// Convert the KeyboardSignal into the JoyStickSignal it stands for: the directions
+    ///   of the keys held combined, and the button if Select is held.
+    pub fn to_joystick(self) -> JoyStickSignal {
+        let mut signal = JoyStickSignal { horiz: 0, vert: 0, button: false };
+        for &key in KEYS.iter().filter(|&&key| self.is_held(key)) {
+            let pushed = key.to_joystick();
+            signal.horiz = signal.horiz.saturating_add(pushed.horiz);
+            signal.vert = signal.vert.saturating_add(pushed.vert);
+            signal.button |= pushed.button;
+        }
+        signal
+    }
+}
+
+
+/// Object that interfaces with a PS/2 keyboard, on pins D2 (clock) and A4 (data).
+pub struct Ps2Keyboard {
+    // The clock and data pins, read by the external interrupt.
+    _clock: PD2<Input<PullUp>>,
+    _data: PC4<Input<PullUp>>,
+}
+
+impl Ps2Keyboard {
+
+    /// Creates a new Ps2Keyboard object, enabling the external interrupt of its clock.
+    ///
+    /// Interrupts must be enabled globally for the keyboard to be received.
+    ///
+    /// # Arguments
+    /// * clock - The pin D2 (INT0), connected to the clock of the keyboard.
+    /// * data  - The pin A4, connected to the data of the keyboard.
+    pub fn new(clock: PD2<Input<PullUp>>, data: PC4<Input<PullUp>>) -> Self {
+        let exint = unsafe { &*EXINT::ptr() };
+        interrupt::free(|_| {
+            let eicra: u8 = exint.eicra.read().bits();
+            exint.eicra.write(|w| unsafe { w.bits((eicra & !ISC00) | ISC01) });
+            let eimsk: u8 = exint.eimsk.read().bits();
+            exint.eimsk.write(|w| unsafe { w.bits(eimsk | INT0_ENABLE) });
+        });
+        Ps2Keyboard { _clock: clock, _data: data }
+    }
+}
+
+
+impl InputDevice for Ps2Keyboard {
+
+    /// Read the input data from the Ps2Keyboard Peripheral.
+    ///
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the keyboard is received by an interrupt).
+    ///
+    /// # Returns
+    /// Option<InputSignal::Keyboard>, while any of its keys is held.
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let held = interrupt::free(|cs| RECEIVER.borrow(cs).borrow().held);
+        if held == 0 { None } else { Some(InputSignal::Keyboard(KeyboardSignal { held })) }
+    }
+}
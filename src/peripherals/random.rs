@@ -1,20 +1,20 @@
-use arduino_uno::prelude::*;
-use arduino_uno::adc::Adc;
-use arduino_uno::hal::port::{
-    mode::Analog,
-    portc::PC5,
-};
+use super::sampler;
 
 
 pub type RngType = usize;
 
 /// Implementation of a sufficiently-random Pseudo Random Number Generator 
 ///   that utilizes an ADC.
+///
+/// The generator may instead be seeded (see `seed`), after which it generates the same
+///   sequence for the same seed, without the ADC (e.g. to replay a recorded game).
 pub struct XOrShiftPrng {
     /// The current random number.
     bits: RngType,
-    /// The analog pin from which to read. This pin is expected to be floating.
-    pin: PC5<Analog>,
+    /// The ADC channel of the analog pin from which to read. This pin is expected to be floating.
+    channel: u8,
+    /// Whether the generator is seeded, generating a deterministic sequence.
+    seeded: bool,
 }
 
 impl XOrShiftPrng {
@@ -22,9 +22,12 @@ impl XOrShiftPrng {
     const BIT_COUNT: usize = RngType::MIN.count_zeros() as usize;
 
     /// Create and initialize a new XOrShiftPrng object.
-    pub fn new(pin: PC5<Analog>, adc: &mut Adc) -> Self {
-        let mut rng = Self { bits: 0, pin };
-        rng.shuffle(adc);
+    ///
+    /// # Arguments
+    /// * channel - The ADC channel of the floating analog pin from which to read (e.g. 5 for A5).
+    pub fn new(channel: u8) -> Self {
+        let mut rng = Self { bits: 0, channel, seeded: false };
+        rng.shuffle();
         rng
     }
 
@@ -34,19 +37,33 @@ impl XOrShiftPrng {
     ///   from a designated analog input and XOR-ing it with the current random
     ///   number, while with each read rotating the random number's bits to the
     ///   left. This is done for each bit of the RngType type.
-    fn shuffle(&mut self, adc: &mut Adc) {
+    fn shuffle(&mut self) {
         for _ in 0..Self::BIT_COUNT {
-            let sample: u16 = nb::block!(adc.read(&mut self.pin)).void_unwrap();
+            // The pin is read by its channel, sharing the ADC with the background JoyStick sampler.
+            let sample: u16 = sampler::read_channel(self.channel, 1);
             self.bits = self.bits.rotate_left(1) ^ ((sample & 255) as RngType);
         }
     }
 
+    /// Seed the generator, so that it generates a deterministic sequence (a 16-bit xorshift),
+    ///   or with None return to shuffling the bits from the analog input.
+    pub fn seed(&mut self, seed: Option<u16>) {
+        self.seeded = seed.is_some();
+        // The xorshift never leaves 0, so a seed of 0 is replaced.
+        if let Some(seed) = seed { self.bits = if seed == 0 { 1 } else { seed as RngType } }
+    }
+
     /// Generate a random (ish) RngType number.
-    /// 
-    /// # Arguments
-    /// * adc - The Analog-Digital convertor required to read analog data.
-    pub fn generate(&mut self, adc: &mut Adc) -> RngType {
-        self.shuffle(adc);
+    pub fn generate(&mut self) -> RngType {
+        if self.seeded {
+            let mut bits = self.bits as u16;
+            bits ^= bits << 7;
+            bits ^= bits >> 9;
+            bits ^= bits << 8;
+            self.bits = bits as RngType;
+        } else {
+            self.shuffle();
+        }
         self.bits.clone()
     }
 }
@@ -0,0 +1,147 @@
+/// Functionality for keeping the date and time with an I2C real-time clock (a DS1307 or
+///   a DS3231), which keeps counting on its backup battery while the board is off.
+///
+/// The real-time clock is connected to the I2C bus (see `i2c`), on A4 (SDA) and A5 (SCL).
+///   Both models keep the time in the same registers, as binary-coded decimal, so a
+///   single driver serves both. They share their I2C address with the MPU6050, so can't
+///   be on the bus alongside one.
+use super::{I2c, I2cError};
+
+// The I2C address of the real-time clock.
+const ADDRESS: u8 = 0x68;
+// The register of the seconds, the first of the seven registers of the date and time.
+const SECONDS_REGISTER: u8 = 0x00;
+// The Clock Halt bit of the seconds register of the DS1307, set while the clock is stopped
+//   (e.g. the first time it is powered). This bit is always clear on the DS3231.
+const CLOCK_HALT: u8 = 1 << 7;
+// The 12-hour mode bit of the hours register.
+const HOUR_12: u8 = 1 << 6;
+// The PM bit of the hours register, in the 12-hour mode.
+const HOUR_PM: u8 = 1 << 5;
+
+
+/// Convert a binary-coded decimal byte into its value.
+fn from_bcd(bcd: u8) -> u8 {
+    (bcd >> 4) * 10 + (bcd & 0x0F)
+}
+
+/// Convert a value (less than 100) into a binary-coded decimal byte.
+fn to_bcd(value: u8) -> u8 {
+    ((value / 10) << 4) | (value % 10)
+}
+
+
+/// A calendar date, between the years 2000 and 2099.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Date {
+    /// The year, counted from 2000.
+    pub year: u8,
+    /// The month, from 1 (January) to 12.
+    pub month: u8,
+    /// The day of the month, from 1.
+    pub day: u8,
+}
+
+impl Date {
+
+    /// Returns the number of days of a month, from 1 (January) to 12, of a year counted from 2000.
+    pub fn days_in_month(year: u8, month: u8) -> u8 {
+        match month {
+            2 => { if year % 4 == 0 { 29 } else { 28 } },
+            4 | 6 | 9 | 11 => { 30 },
+            _ => { 31 },
+        }
+    }
+
+    /// Pack the date into two bytes (7 bits of the year, 4 of the month, and 5 of the day).
+    ///   A packed date is never 0, which may therefore stand for no date.
+    pub fn to_packed(&self) -> u16 {
+        ((self.year as u16) << 9) | ((self.month as u16) << 5) | (self.day as u16)
+    }
+
+    /// Unpack a date packed by `to_packed`, or None for 0 (or any other invalid date).
+    pub fn from_packed(packed: u16) -> Option<Self> {
+        let date = Self { year: (packed >> 9) as u8, month: ((packed >> 5) & 0x0F) as u8, day: (packed & 0x1F) as u8 };
+        let valid = (1..=12).contains(&date.month) && (1..=Self::days_in_month(date.year, date.month)).contains(&date.day);
+        if valid { Some(date) } else { None }
+    }
+}
+
+
+/// A date and time of day, as kept by the real-time clock.
+#[derive(Copy, Clone, PartialEq)]
+pub struct DateTime {
+    /// The date.
+    pub date: Date,
+    /// The hours, from 0 to 23.
+    pub hours: u8,
+    /// The minutes, from 0 to 59.
+    pub minutes: u8,
+    /// The seconds, from 0 to 59.
+    pub seconds: u8,
+}
+
+
+/// The object that interfaces with the real-time clock.
+pub struct Rtc {
+    // The I2C bus the real-time clock is on.
+    bus: I2c,
+}
+
+impl Rtc {
+
+    /// Creates a new Rtc object, starting the clock if it is halted.
+    ///
+    /// # Arguments
+    /// * `bus` - The I2C bus the real-time clock is on.
+    ///
+    /// # Returns
+    /// The Rtc, or the error of the bus if the real-time clock doesn't respond.
+    pub fn new(mut bus: I2c) -> Result<Self, I2cError> {
+        let mut seconds = [0u8];
+        bus.read_registers(ADDRESS, SECONDS_REGISTER, &mut seconds)?;
+        if seconds[0] & CLOCK_HALT != 0 {
+            bus.write_register(ADDRESS, SECONDS_REGISTER, seconds[0] & !CLOCK_HALT)?;
+        }
+        Ok(Self { bus })
+    }
+
+    /// Read the current date and time.
+    pub fn now(&mut self) -> Result<DateTime, I2cError> {
+        // Seconds, minutes, hours, day of the week, day, month, and year.
+        let mut registers = [0u8; 7];
+        self.bus.read_registers(ADDRESS, SECONDS_REGISTER, &mut registers)?;
+        let hours = if registers[2] & HOUR_12 != 0 {
+            let hour = from_bcd(registers[2] & 0x1F) % 12;
+            if registers[2] & HOUR_PM != 0 { hour + 12 } else { hour }
+        } else {
+            from_bcd(registers[2] & 0x3F)
+        };
+        Ok(DateTime {
+            date: Date {
+                year: from_bcd(registers[6]),
+                month: from_bcd(registers[5] & 0x1F),
+                day: from_bcd(registers[4] & 0x3F),
+            },
+            hours,
+            minutes: from_bcd(registers[1] & 0x7F),
+            seconds: from_bcd(registers[0] & !CLOCK_HALT),
+        })
+    }
+
+    /// Set the date and time, in the 24-hour mode, starting the clock if it is halted.
+    pub fn set(&mut self, time: &DateTime) -> Result<(), I2cError> {
+        // The day of the week is unused, so is left at 1.
+        let bytes = [
+            SECONDS_REGISTER,
+            to_bcd(time.seconds),
+            to_bcd(time.minutes),
+            to_bcd(time.hours),
+            1,
+            to_bcd(time.date.day),
+            to_bcd(time.date.month),
+            to_bcd(time.date.year),
+        ];
+        self.bus.write(ADDRESS, &bytes)
+    }
+}
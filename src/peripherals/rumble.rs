@@ -0,0 +1,34 @@
+/// Functionality for driving a vibration (rumble) motor.
+use arduino_uno::prelude::*;
+use arduino_uno::hal::port::{Pin, mode::Output};
+
+
+/// The object that interfaces with a vibration motor, switched on and off
+///   (through a transistor) by a digital output pin.
+pub struct Rumble {
+    // The pin switching the motor.
+    pin: Pin<Output>,
+}
+
+impl Rumble {
+
+    /// Create a new Rumble object, with the motor switched off.
+    ///
+    /// # Arguments
+    ///
+    /// * `pin` - The digital output pin switching the motor.
+    pub fn new(mut pin: Pin<Output>) -> Self {
+        pin.set_low().void_unwrap();
+        Self { pin }
+    }
+
+    /// Switch the motor on.
+    pub fn start(&mut self) {
+        self.pin.set_high().void_unwrap();
+    }
+
+    /// Switch the motor off.
+    pub fn stop(&mut self) {
+        self.pin.set_low().void_unwrap();
+    }
+}
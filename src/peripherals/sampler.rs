@@ -0,0 +1,227 @@
+/// Functionality for sampling the JoyStick of the first player in the background,
+///   on a timer interrupt, so that games need not block while polling it.
+///
+/// The samples are taken by the Timer0 compare match B interrupt, which fires once a
+///   millisecond alongside the clock (see `clock::start`). Each sample reads the ADC
+///   registers directly, over three interrupts: the first starts converting the x-axis,
+///   the second reads it and starts converting the y-axis, and the third reads that. So the
+///   interrupt never waits on a conversion, and never delays the others (e.g. the keyboard's,
+///   which must catch each bit as it arrives). Every other ADC read must claim the ADC first
+///   (see `claim`), as the InputPeripheral and XOrShiftPrng do, which sets the sampler aside
+///   meanwhile, with interrupts enabled.
+use avr_device::atmega328p::{ADC, PORTC, TC0};
+use avr_device::interrupt::{self, Mutex};
+use core::cell::RefCell;
+use super::JoyStickSignal;
+
+// The JoyStick is sampled every this many milliseconds.
+const SAMPLE_INTERVAL_MS: u8 = 5;
+// The number of samples kept; older samples are overwritten.
+const RING_SIZE: usize = 8;
+// The ADC channels of the x-axis and y-axis (pins A0 and A1), and the pin of the button (A2).
+const X_CHANNEL: u8 = 0;
+const Y_CHANNEL: u8 = 1;
+const BUTTON_PIN: u8 = 1 << 2;
+// The reference selection bits of the ADMUX register, selecting AVcc.
+const REFS_AVCC: u8 = 0b01 << 6;
+// The ADC Start Conversion bit of the ADCSRA register.
+const ADSC: u8 = 1 << 6;
+// The Timer0 Output Compare Match B Interrupt Enable bit of the TIMSK0 register.
+const OCIE0B: u8 = 1 << 2;
+
+
+/// The stage of the sample being taken.
+#[derive(Copy, Clone, PartialEq)]
+enum Stage {
+    /// Waiting for the next sample.
+    Idle,
+    /// Converting the x-axis.
+    XAxis,
+    /// Converting the y-axis, with the reading of the x-axis.
+    YAxis(u16),
+}
+
+
+/// The samples with input, and whether the button has been pressed since they were last taken.
+struct Ring {
+    signals: [JoyStickSignal; RING_SIZE],
+    /// The index at which the next sample is stored.
+    next: usize,
+    /// The number of samples stored.
+    len: usize,
+    /// Whether the button was pressed in any sample, even one since overwritten.
+    button: bool,
+    /// The number of milliseconds until the next sample.
+    countdown: u8,
+    /// The stage of the sample being taken.
+    stage: Stage,
+    /// Whether the ADC is claimed by another read, setting the sampler aside, see `claim`.
+    claimed: bool,
+}
+
+static RING: Mutex<RefCell<Ring>> = Mutex::new(RefCell::new(Ring {
+    signals: [JoyStickSignal { horiz: 0, vert: 0, button: false }; RING_SIZE],
+    next: 0,
+    len: 0,
+    button: false,
+    countdown: 0,
+    stage: Stage::Idle,
+    claimed: false,
+}));
+
+
+/// The Timer0 compare match B interrupt, which samples the JoyStick.
+#[avr_device::interrupt(atmega328p)]
+fn TIMER0_COMPB() {
+    interrupt::free(|cs| {
+        let mut ring = RING.borrow(cs).borrow_mut();
+        // A claimed ADC abandons the sample being taken, which starts again once it is released.
+        if ring.claimed {
+            ring.stage = Stage::Idle;
+            return
+        }
+
+        let (adc, portc) = unsafe { (&*ADC::ptr(), &*PORTC::ptr()) };
+        match ring.stage {
+            Stage::Idle => {
+                if ring.countdown > 0 {
+                    ring.countdown -= 1;
+                    return
+                }
+                ring.countdown = SAMPLE_INTERVAL_MS - 1;
+                start_conversion(adc, X_CHANNEL);
+                ring.stage = Stage::XAxis;
+            },
+            // A conversion takes about a tenth of a millisecond, so it is almost always complete.
+            _ if is_converting(adc) => {},
+            Stage::XAxis => {
+                let x: u16 = adc.adc.read().bits();
+                start_conversion(adc, Y_CHANNEL);
+                ring.stage = Stage::YAxis(x);
+            },
+            Stage::YAxis(x) => {
+                let y: u16 = adc.adc.read().bits();
+                ring.stage = Stage::Idle;
+                let pins: u8 = portc.pinc.read().bits();
+                if let Some(signal) = JoyStickSignal::from_readings(x, y, pins & BUTTON_PIN == 0) {
+                    let next = ring.next;
+                    ring.signals[next] = signal;
+                    ring.next = (next + 1) % RING_SIZE;
+                    ring.len = (ring.len + 1).min(RING_SIZE);
+                    ring.button |= signal.button;
+                }
+            },
+        }
+    })
+}
+
+/// Start converting an ADC channel.
+fn start_conversion(adc: &avr_device::atmega328p::adc::RegisterBlock, channel: u8) {
+    adc.admux.write(|w| unsafe { w.bits(REFS_AVCC | channel) });
+    let adcsra: u8 = adc.adcsra.read().bits();
+    adc.adcsra.write(|w| unsafe { w.bits(adcsra | ADSC) });
+}
+
+/// Returns whether the ADC is partway through a conversion.
+fn is_converting(adc: &avr_device::atmega328p::adc::RegisterBlock) -> bool {
+    let adcsra: u8 = adc.adcsra.read().bits();
+    adcsra & ADSC != 0
+}
+
+/// Read an ADC channel, waiting for the conversion to complete.
+///
+/// The ADC must be claimed, see `claim`.
+fn convert(adc: &avr_device::atmega328p::adc::RegisterBlock, channel: u8) -> u16 {
+    start_conversion(adc, channel);
+    while is_converting(adc) {}
+    adc.adc.read().bits()
+}
+
+
+/// Claim the ADC from the background sampler, for the duration of a read of it.
+///
+/// Interrupts stay enabled throughout. The sampler sets itself aside (abandoning any sample
+///   it was taking), and any conversion it started is waited on, before the read begins.
+///
+/// # Arguments
+/// * read - The read of the ADC (e.g. of an InputDevice).
+pub(super) fn claim<R, F: FnOnce() -> R>(read: F) -> R {
+    interrupt::free(|cs| RING.borrow(cs).borrow_mut().claimed = true);
+    let adc = unsafe { &*ADC::ptr() };
+    while is_converting(adc) {}
+    let result = read();
+    interrupt::free(|cs| RING.borrow(cs).borrow_mut().claimed = false);
+    result
+}
+
+/// Read an ADC channel by claiming the ADC (see `claim`), e.g. for a pin without an analog input.
+///
+/// # Arguments
+/// * channel - The ADC channel (the multiplexer selection of the ADMUX register, e.g. 4 for A4).
+/// * times   - The number of conversions, of which only the last is returned, e.g. to let
+///               the reading settle after switching to an internal channel.
+pub(super) fn read_channel(channel: u8, times: u8) -> u16 {
+    claim(|| {
+        let adc = unsafe { &*ADC::ptr() };
+        (0..times.max(1)).fold(0, |_, _| convert(adc, channel))
+    })
+}
+
+
+/// Start sampling the JoyStick in the background, discarding any previous samples.
+///
+/// The clock must have been started, since the samples are timed by Timer0.
+pub fn start() {
+    interrupt::free(|cs| {
+        let mut ring = RING.borrow(cs).borrow_mut();
+        ring.next = 0;
+        ring.len = 0;
+        ring.button = false;
+        ring.countdown = 0;
+        ring.stage = Stage::Idle;
+        let tc0 = unsafe { &*TC0::ptr() };
+        tc0.timsk0.modify(|r, w| {
+            let bits: u8 = r.bits();
+            unsafe { w.bits(bits | OCIE0B) }
+        });
+    })
+}
+
+/// Stop sampling the JoyStick in the background.
+pub fn stop() {
+    let tc0 = unsafe { &*TC0::ptr() };
+    interrupt::free(|_| {
+        tc0.timsk0.modify(|r, w| {
+            let bits: u8 = r.bits();
+            unsafe { w.bits(bits & !OCIE0B) }
+        });
+    })
+}
+
+/// Returns the latest sample with any input, if any has been taken since they were last taken.
+pub fn latest() -> Option<JoyStickSignal> {
+    interrupt::free(|cs| {
+        let ring = RING.borrow(cs).borrow();
+        if ring.len == 0 { None } else { Some(ring.signals[(ring.next + RING_SIZE - 1) % RING_SIZE]) }
+    })
+}
+
+/// Take the samples with input, oldest first, removing them.
+///
+/// # Arguments
+/// * f - Called with each sample, in turn.
+///
+/// # Returns
+/// Whether the button was pressed in any sample since they were last taken, including those
+///   overwritten by newer samples (so that a quick press is never missed).
+pub fn take<F: FnMut(JoyStickSignal)>(mut f: F) -> bool {
+    let (signals, first, len, button) = interrupt::free(|cs| {
+        let mut ring = RING.borrow(cs).borrow_mut();
+        let taken = (ring.signals, (ring.next + RING_SIZE - ring.len) % RING_SIZE, ring.len, ring.button);
+        ring.len = 0;
+        ring.button = false;
+        taken
+    });
+    (0..len).for_each(|index| f(signals[(first + index) % RING_SIZE]));
+    button
+}
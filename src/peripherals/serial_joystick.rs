@@ -0,0 +1,74 @@
+/// Functionality for a virtual JoyStick, driven by keystrokes sent over the serial
+///   connection (e.g. from a PC terminal), so that the games can be played (and developed,
+///   or demonstrated) without the physical JoyStick wired up.
+///
+/// The protocol is a single ASCII byte per keystroke: `w`, `a`, `s`, and `d` are the
+///   directions (Up, Left, Down, and Right), and space is the button. Letters may be upper
+///   case, and any other byte is ignored. A terminal sends nothing while a key is held,
+///   besides its key repeat, so each keystroke holds its key for a short while.
+///
+/// The bytes are read directly from the USART, alongside the serial connection of the
+///   `Components`. They are therefore not seen by the leaderboard (see `Leaderboard`),
+///   whose handshake is lost while the virtual JoyStick is in use.
+use arduino_uno::adc::Adc;
+
+use super::{clock, usart, InputDevice, InputSignal, Key};
+
+// The time a key is held after its keystroke (or its latest repeat), in milliseconds.
+//   This outlasts the interval between the repeats of a held key.
+const HOLD_MS: u32 = 120;
+
+
+/// Returns the key of a keystroke, if the byte stands for one.
+fn key_of(byte: u8) -> Option<Key> {
+    match byte.to_ascii_lowercase() {
+        b'w' => { Some(Key::Up) },
+        b'a' => { Some(Key::Left) },
+        b's' => { Some(Key::Down) },
+        b'd' => { Some(Key::Right) },
+        b' ' => { Some(Key::Select) },
+        _ => { None },
+    }
+}
+
+
+/// Object that receives keystrokes over the serial connection, as a virtual JoyStick.
+pub struct SerialJoystick {
+    // The key of the latest keystroke, if any.
+    key: Option<Key>,
+    // The time the latest keystroke was received (see `clock::millis`).
+    received_ms: u32,
+}
+
+impl SerialJoystick {
+
+    /// Creates a new SerialJoystick object, with no key held.
+    ///
+    /// The serial connection must be set up (see `arduino_uno::Serial`) for keystrokes
+    ///   to be received.
+    pub fn new() -> Self {
+        Self { key: None, received_ms: 0 }
+    }
+}
+
+
+impl InputDevice for SerialJoystick {
+
+    /// Read the input data from the serial connection.
+    ///
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the keystrokes are received by the USART).
+    ///
+    /// # Returns
+    /// Option<InputSignal::Serial>
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        while let Some(byte) = usart::read_byte() {
+            if let Some(key) = key_of(byte) {
+                self.key = Some(key);
+                self.received_ms = clock::millis();
+            }
+        }
+        if clock::elapsed_since(self.received_ms) >= HOLD_MS { return None }
+        self.key.map(InputSignal::Serial)
+    }
+}
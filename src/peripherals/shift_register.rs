@@ -0,0 +1,161 @@
+/// Functionality for reading many buttons over three pins, through daisy-chained 74HC165
+///   (parallel-in, serial-out) shift registers, e.g. the buttons of two gamepads.
+///
+/// Each 74HC165 reads 8 buttons, each connecting an input to ground when pressed (the
+///   inputs being pulled up by resistors otherwise). The registers share the latch (PL)
+///   and clock (CP) pins, and each one's serial output (Q7) feeds the serial input (DS)
+///   of the next, the output of the register nearest the board being read.
+use arduino_uno::prelude::*;
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{Pin, mode::{Floating, Input, Output}};
+
+use super::{InputDevice, InputSignal, JoyStickSignal, Key};
+
+/// The most shift registers which may be chained (the bits of a ButtonsSignal).
+pub const MAX_SHIFT_REGISTERS: usize = 2;
+
+
+/// The keys of the 8 inputs (A to H) of the shift register of a gamepad: the directions,
+///   then the buttons (of which the first is the JoyStick button), unused inputs being None.
+pub type GamepadKeymap = [Option<Key>; 8];
+
+/// The gamepad wiring: Up, Down, Left, Right, then the A button (the JoyStick button),
+///   the other inputs being free for other buttons.
+pub const DEFAULT_GAMEPAD_KEYMAP: GamepadKeymap = [
+    Some(Key::Up), Some(Key::Down), Some(Key::Left), Some(Key::Right), Some(Key::Select), None, None, None
+];
+
+// The keys of the buttons of a ButtonsSignal, in the order of their bits (the other
+//   buttons following them).
+const SIGNAL_KEYS: [Key; 5] = [Key::Up, Key::Down, Key::Left, Key::Right, Key::Select];
+
+
+/// Object describing the buttons pressed on the gamepads of the shift registers.
+///
+/// The buttons are ordered by their keys rather than by their inputs (the ShiftRegisters
+///   apply the keymap as they are read), so that the signal needn't carry the keymap.
+#[derive(Copy, Clone)]
+pub struct ButtonsSignal {
+    // The pressed buttons: bit 8 * n + i is button i of the gamepad of the nth register from
+    //   the board, buttons 0 to 4 being Up, Down, Left, Right, and Select, and 5 to 7 the
+    //   inputs without a key (in the order of the inputs).
+    pub pressed: u16,
+}
+
+impl ButtonsSignal {
+
+    /// Returns whether button i (see `pressed`) of the gamepad of the nth register from the board is pressed.
+    pub fn is_pressed(&self, register: usize, button: usize) -> bool {
+        self.pressed & (1 << (8 * register + button)) != 0
+    }
+
+    /// Convert the buttons of the gamepad of a register into the JoyStickSignal they stand for.
+    ///
+    /// # Arguments
+    /// * register - The register of the gamepad, e.g. 0 for the first player and 1 for the second.
+    pub fn gamepad(&self, register: usize) -> JoyStickSignal {
+        let mut signal = JoyStickSignal { horiz: 0, vert: 0, button: false };
+        for (button, key) in SIGNAL_KEYS.iter().enumerate() {
+            if !self.is_pressed(register, button) { continue }
+            let pushed = key.to_joystick();
+            signal.horiz = signal.horiz.saturating_add(pushed.horiz);
+            signal.vert = signal.vert.saturating_add(pushed.vert);
+            signal.button |= pushed.button;
+        }
+        signal
+    }
+
+    /// Convert the ButtonsSignal into the JoyStickSignal it stands for: the gamepad of the
+    ///   register nearest the board.
+    pub fn to_joystick(self) -> JoyStickSignal {
+        self.gamepad(0)
+    }
+}
+
+
+/// Object that interfaces with daisy-chained 74HC165 shift registers.
+pub struct ShiftRegisters {
+    // The latch (PL) pin, which loads the inputs into the registers while low.
+    latch: Pin<Output>,
+    // The clock (CP) pin, which shifts the bits of the registers on its rising edge.
+    clock: Pin<Output>,
+    // The data (Q7) pin, the serial output of the register nearest the board.
+    data: Pin<Input<Floating>>,
+    // The number of registers chained.
+    registers: usize,
+    // The keys of the inputs of the register of each gamepad.
+    keymap: &'static GamepadKeymap,
+}
+
+impl ShiftRegisters {
+
+    /// Creates a new ShiftRegisters object.
+    ///
+    /// # Arguments
+    /// * latch     - The pin connected to the latch (PL) of the registers.
+    /// * clock     - The pin connected to the clock (CP) of the registers.
+    /// * data      - The pin connected to the serial output (Q7) of the register nearest the board.
+    /// * registers - The number of registers chained, up to `MAX_SHIFT_REGISTERS`.
+    /// * keymap    - The keys of the inputs of the register of each gamepad, e.g. `DEFAULT_GAMEPAD_KEYMAP`.
+    pub fn new(
+        mut latch: Pin<Output>,
+        mut clock: Pin<Output>,
+        data: Pin<Input<Floating>>,
+        registers: usize,
+        keymap: &'static GamepadKeymap,
+    ) -> Self {
+        latch.set_high().void_unwrap();
+        clock.set_low().void_unwrap();
+        Self { latch, clock, data, registers: registers.min(MAX_SHIFT_REGISTERS), keymap }
+    }
+
+    /// Read the inputs of every register, returning the pressed buttons (see `ButtonsSignal.pressed`).
+    pub fn read_pressed(&mut self) -> u16 {
+        // Load the inputs, then shift them out, input H of the nearest register first.
+        self.latch.set_low().void_unwrap();
+        self.latch.set_high().void_unwrap();
+        let mut pressed = 0u16;
+        for register in 0..self.registers {
+            let mut inputs = 0u8;
+            for input in (0..8).rev() {
+                if self.data.is_low().void_unwrap() { inputs |= 1 << input }
+                self.clock.set_high().void_unwrap();
+                self.clock.set_low().void_unwrap();
+            }
+            pressed |= (self.to_buttons(inputs) as u16) << (8 * register);
+        }
+        pressed
+    }
+
+    /// Reorder the pressed inputs of a register (input A being bit 0) into its pressed
+    ///   buttons (see `ButtonsSignal.pressed`), by the keymap.
+    fn to_buttons(&self, inputs: u8) -> u8 {
+        let mut buttons = 0u8;
+        let mut other = SIGNAL_KEYS.len();
+        for (input, key) in self.keymap.iter().enumerate() {
+            let button = match key.and_then(|key| SIGNAL_KEYS.iter().position(|&signal_key| signal_key == key)) {
+                Some(button) => { button },
+                None => { other += 1; other - 1 },
+            };
+            if (inputs & (1 << input) != 0) & (button < 8) { buttons |= 1 << button }
+        }
+        buttons
+    }
+}
+
+
+impl InputDevice for ShiftRegisters {
+
+    /// Read the input data from the ShiftRegisters Peripheral.
+    /// 
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the registers are digital).
+    /// 
+    /// # Returns
+    /// Option<InputSignal::Buttons>, while any button is pressed.
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let pressed = self.read_pressed();
+        if pressed == 0 { return None }
+        Some(InputSignal::Buttons(ButtonsSignal { pressed }))
+    }
+}
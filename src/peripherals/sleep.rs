@@ -0,0 +1,50 @@
+/// Functionality having to do with the sleep modes of the microcontroller, to save power.
+use avr_device::atmega328p::{ADC, CPU, EXINT};
+
+// The Sleep Mode Select bits of the SMCR register, selecting the power-down mode,
+//   and the Sleep Enable bit.
+const SM_POWER_DOWN: u8 = 0b010 << 1;
+const SE: u8 = 1 << 0;
+// The Pin Change Interrupt Enable bit of the PCICR register, and the Flag bit of
+//   the PCIFR register, for the pins of Port C (PCINT8-14).
+const PCIE1: u8 = 1 << 1;
+const PCIF1: u8 = 1 << 1;
+// The pin change mask bit of the PCMSK1 register for pin A2 (PCINT10),
+//   the button of the JoyStick of the first player.
+const PCINT10: u8 = 1 << 2;
+// The ADC Enable bit of the ADCSRA register.
+const ADEN: u8 = 1 << 7;
+
+
+/// The pin change interrupt of Port C, which only wakes the microcontroller.
+#[avr_device::interrupt(atmega328p)]
+fn PCINT1() {}
+
+
+/// Put the microcontroller into its power-down sleep mode, until the button of the
+///   JoyStick of the first player is pressed.
+///
+/// Every clock stops while asleep, including that of Timer1, so the buzzer should be
+///   stopped beforehand. The ADC is disabled while asleep, and enabled again on waking.
+///   Interrupts must be enabled globally, otherwise the button cannot wake the microcontroller.
+pub fn power_down_until_button() {
+    let (adc, cpu, exint) = unsafe { (&*ADC::ptr(), &*CPU::ptr(), &*EXINT::ptr()) };
+
+    let adcsra: u8 = adc.adcsra.read().bits();
+    adc.adcsra.write(|w| unsafe { w.bits(adcsra & !ADEN) });
+
+    // Clear any stale pin change, so that only a new press wakes the microcontroller.
+    //   The other pin change interrupts (e.g. of the IR receiver) are left enabled.
+    let pcicr: u8 = exint.pcicr.read().bits();
+    exint.pcifr.write(|w| unsafe { w.bits(PCIF1) });
+    exint.pcmsk1.write(|w| unsafe { w.bits(PCINT10) });
+    exint.pcicr.write(|w| unsafe { w.bits(pcicr | PCIE1) });
+
+    cpu.smcr.write(|w| unsafe { w.bits(SM_POWER_DOWN | SE) });
+    avr_device::asm::sleep();
+    cpu.smcr.write(|w| unsafe { w.bits(0) });
+
+    exint.pcicr.write(|w| unsafe { w.bits(pcicr) });
+    exint.pcmsk1.write(|w| unsafe { w.bits(0) });
+    adc.adcsra.write(|w| unsafe { w.bits(adcsra) });
+}
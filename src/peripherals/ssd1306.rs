@@ -0,0 +1,145 @@
+/// Functionality for driving a 128x64 SSD1306 OLED on the I2C bus, as the display of the games.
+///
+/// The DotScreen is drawn on the left half of the OLED as 8x8 big blocks, each row of dots
+///   filling one 8 pixel high page of the OLED. The right half shows the status of the
+///   game-play (the score, and any lives), in the small font.
+use crate::graphics::{self, Font, SmallFont};
+use super::{Display, DotDisplay, DotScreen, I2c, I2cError};
+
+// The I2C address of the OLED.
+const ADDRESS: u8 = 0x3C;
+// The control bytes preceding commands and data.
+const COMMANDS: u8 = 0x00;
+const DATA: u8 = 0x40;
+// The commands turning the OLED off and on, setting its contrast, and addressing a page.
+const DISPLAY_OFF: u8 = 0xAE;
+const DISPLAY_ON: u8 = 0xAF;
+const SET_CONTRAST: u8 = 0x81;
+const SET_PAGE: u8 = 0xB0;
+// The commands setting up the OLED: its multiplex ratio and clock, the charge pump,
+//   page addressing, the orientation (not mirrored), and the pre-charge and VCOMH levels.
+const SETUP: [u8; 23] = [
+    COMMANDS, DISPLAY_OFF,
+    0xD5, 0x80, 0xA8, 0x3F, 0xD3, 0x00, 0x40,
+    0x8D, 0x14, 0x20, 0x02,
+    0xA1, 0xC8, 0xDA, 0x12,
+    0xD9, 0xF1, 0xDB, 0x40, 0xA4, 0xA6,
+];
+// The width of a half of the OLED (in pixels), and of a block (the pixels of a dot).
+const HALF_WIDTH: usize = 64;
+const BLOCK_WIDTH: usize = HALF_WIDTH / DotScreen::WIDTH;
+// The pixels of a column of a lit block, leaving a gap to the block below.
+const BLOCK_COLUMN: u8 = 0x7F;
+// The page (row) of the status on the right half.
+const STATUS_PAGE: u8 = 3;
+
+
+/// Object that interfaces with an SSD1306 OLED.
+pub struct Ssd1306 {
+    // The I2C bus the OLED is on.
+    bus: I2c,
+    // The screen shown, so that only the rows which change are sent.
+    screen: DotScreen,
+    // The status shown (the score, and any lives).
+    status: Option<(usize, Option<usize>)>,
+    // The current (perceived) brightness level.
+    brightness: u8,
+}
+
+impl Ssd1306 {
+
+    /// Creates a new Ssd1306 object, clearing and turning on the OLED.
+    ///
+    /// # Arguments
+    /// * bus - The I2C bus the OLED is on (see `I2c.share`).
+    ///
+    /// # Returns
+    /// The Ssd1306, or the error of the bus if the OLED doesn't respond.
+    pub fn new(mut bus: I2c) -> Result<Self, I2cError> {
+        bus.write(ADDRESS, &SETUP)?;
+        let mut oled = Self { bus, screen: DotScreen::new_full(), status: None, brightness: 0 };
+        for page in 0..(DotScreen::HEIGHT as u8) {
+            oled.write_page(page, 0, &[0; 2 * HALF_WIDTH]);
+        }
+        oled.show(&DotScreen::new_empty());
+        oled.set_brightness(DotDisplay::DEFAULT_BRIGHTNESS);
+        oled.command(&[DISPLAY_ON]);
+        Ok(oled)
+    }
+
+    /// Send commands to the OLED. Failures of the bus are ignored, the OLED being redrawn
+    ///   in full soon enough.
+    fn command(&mut self, commands: &[u8]) {
+        let mut bytes = [COMMANDS; 4];
+        bytes[1..=commands.len()].copy_from_slice(commands);
+        self.bus.write(ADDRESS, &bytes[..=commands.len()]).ok();
+    }
+
+    /// Write the pixels of (part of) a page, from the column.
+    fn write_page(&mut self, page: u8, column: usize, pixels: &[u8]) {
+        self.command(&[SET_PAGE | page, (column & 0x0F) as u8, 0x10 | (column >> 4) as u8]);
+        let mut bytes = [DATA; 1 + 2 * HALF_WIDTH];
+        bytes[1..=pixels.len()].copy_from_slice(pixels);
+        self.bus.write(ADDRESS, &bytes[..=pixels.len()]).ok();
+    }
+}
+
+
+impl Display for Ssd1306 {
+
+    /// Show a DotScreen as big blocks, sending only the rows which changed.
+    fn show(&mut self, screen: &DotScreen) {
+        for row in 0..DotScreen::HEIGHT {
+            let bit = 1 << (DotScreen::HEIGHT - 1 - row);
+            let changed = screen.columns.iter().zip(self.screen.columns.iter())
+                .any(|(column, shown)| (column ^ shown) & bit != 0);
+            if !changed { continue }
+
+            let mut pixels = [0u8; HALF_WIDTH];
+            for (block, column) in pixels.chunks_mut(BLOCK_WIDTH).zip(screen.columns.iter()) {
+                if column & bit != 0 { block[..BLOCK_WIDTH - 1].iter_mut().for_each(|pixel| *pixel = BLOCK_COLUMN) }
+            }
+            self.write_page(row as u8, 0, &pixels);
+        }
+        self.screen = *screen;
+    }
+
+    /// Show the score (and any lives, after an x) on the right half of the OLED.
+    fn show_status(&mut self, score: usize, lives: Option<usize>) {
+        if self.status == Some((score, lives)) { return }
+        self.status = Some((score, lives));
+
+        let mut score_buffer = graphics::NumberBuffer::default();
+        let mut lives_buffer = graphics::NumberBuffer::default();
+        let score = graphics::format_number(score as u32, &mut score_buffer);
+        let lives = lives.map(|lives| graphics::format_number(lives as u32, &mut lives_buffer));
+        let text = score.chars().chain(lives.into_iter().flat_map(|lives| " X".chars().chain(lives.chars())));
+
+        let mut pixels = [0u8; HALF_WIDTH];
+        let mut x = 2;
+        for glyph in text.filter_map(|character| SmallFont.glyph(character)) {
+            for &column in glyph.columns() {
+                if x >= HALF_WIDTH { break }
+                // The top of the glyph is its highest bit, but the top of a page is its lowest.
+                pixels[x] = column.reverse_bits() >> (8 - SmallFont.height());
+                x += 1;
+            }
+            x += 1;
+        }
+        self.write_page(STATUS_PAGE, HALF_WIDTH, &pixels);
+    }
+
+    fn set_brightness(&mut self, level: u8) {
+        self.brightness = level.min(DotDisplay::BRIGHTNESS_LEVELS - 1);
+        let contrast = 0xFF / (DotDisplay::BRIGHTNESS_LEVELS - 1) * self.brightness;
+        self.command(&[SET_CONTRAST, contrast.max(1)]);
+    }
+
+    fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    fn shutdown(&mut self, off: bool) {
+        self.command(&[if off { DISPLAY_OFF } else { DISPLAY_ON }]);
+    }
+}
@@ -0,0 +1,171 @@
+/// Functionality for driving a TM1637 4-digit 7-segment display, e.g. to show the score.
+///
+/// The TM1637 is driven over two wires, clock (CLK) and data (DIO), with a protocol like
+///   I2C's but without addresses. Both lines are pulled up by the display module, so they
+///   are driven open-drain: pulled low by making the pin an output (whose level is low),
+///   and released high by making it an input. The display acknowledges each byte by pulling
+///   DIO low, which must not be driven high meanwhile.
+use arduino_uno::hal::port::{mode::{Input, Floating}, portd::{PD4, PD5}};
+use avr_device::atmega328p::PORTD;
+use avr_device::interrupt;
+
+// The bits of the clock (D4) and data (D5) pins in the registers of Port D.
+const CLK: u8 = 1 << 4;
+const DIO: u8 = 1 << 5;
+// The time each level of the lines is held for, in microseconds.
+const BIT_DELAY_US: u16 = 5;
+// The commands of the TM1637: writing the digits from the first, the address of the first
+//   digit, and turning the display on (with the brightness in the lowest 3 bits) or off.
+const WRITE_DATA: u8 = 0x40;
+const FIRST_DIGIT: u8 = 0xC0;
+const DISPLAY_ON: u8 = 0x88;
+const DISPLAY_OFF: u8 = 0x80;
+// The segments (in the order gfedcba) of the decimal digits.
+const DIGIT_SEGMENTS: [u8; 10] = [0x3F, 0x06, 0x5B, 0x4F, 0x66, 0x6D, 0x7D, 0x07, 0x7F, 0x6F];
+// The bit of the second digit which lights the colon.
+const COLON: u8 = 0x80;
+
+
+/// Object that interfaces with a TM1637 4-digit 7-segment display, on pins D4 (CLK) and D5 (DIO).
+pub struct Tm1637 {
+    // The clock and data pins, driven through the registers of Port D.
+    _clk: PD4<Input<Floating>>,
+    _dio: PD5<Input<Floating>>,
+    // The brightness of the display, from 0 (lowest) to 7 (highest).
+    brightness: u8,
+    // The segments shown on each digit.
+    segments: [u8; 4],
+}
+
+impl Tm1637 {
+
+    /// The number of digits of the display.
+    pub const DIGITS: usize = 4;
+
+    /// The number of brightness levels of the display.
+    pub const BRIGHTNESS_LEVELS: u8 = 8;
+
+    /// Creates a new Tm1637 object, clearing the display.
+    ///
+    /// # Arguments
+    /// * clk - The pin (D4) connected to the clock (CLK) of the display.
+    /// * dio - The pin (D5) connected to the data (DIO) of the display.
+    pub fn new(clk: PD4<Input<Floating>>, dio: PD5<Input<Floating>>) -> Self {
+        // The lines are only ever driven low, and are released otherwise.
+        let portd = unsafe { &*PORTD::ptr() };
+        interrupt::free(|_| {
+            portd.portd.modify(|r, w| {
+                let bits: u8 = r.bits();
+                unsafe { w.bits(bits & !(CLK | DIO)) }
+            });
+        });
+        let mut display = Self { _clk: clk, _dio: dio, brightness: Self::BRIGHTNESS_LEVELS - 1, segments: [0xFF; 4] };
+        display.clear();
+        display
+    }
+
+    /// Show a number, right-aligned, without leading zeros. Numbers of more than four
+    ///   digits show their last four digits.
+    pub fn show_number(&mut self, number: u32) {
+        let mut segments = [0u8; 4];
+        let mut number = number % 10_000;
+        for (index, digit) in segments.iter_mut().enumerate().rev() {
+            let first = index == Self::DIGITS - 1;
+            if (number > 0) | first { *digit = DIGIT_SEGMENTS[(number % 10) as usize] }
+            number /= 10;
+        }
+        self.show_segments(segments);
+    }
+
+    /// Show a time as minutes and seconds (e.g. 2:05), the minutes being those of the
+    ///   last two digits.
+    pub fn show_time(&mut self, minutes: u32, seconds: u8) {
+        let minutes = minutes % 100;
+        let seconds = seconds.min(59);
+        let tens = if minutes >= 10 { DIGIT_SEGMENTS[(minutes / 10) as usize] } else { 0 };
+        self.show_segments([
+            tens,
+            DIGIT_SEGMENTS[(minutes % 10) as usize] | COLON,
+            DIGIT_SEGMENTS[(seconds / 10) as usize],
+            DIGIT_SEGMENTS[(seconds % 10) as usize],
+        ]);
+    }
+
+    /// Clear the display.
+    pub fn clear(&mut self) {
+        self.show_segments([0; 4]);
+    }
+
+    /// Show the segments (in the order gfedcba, with the colon on the second digit)
+    ///   of each digit, from the left. Nothing is sent if they are already shown.
+    pub fn show_segments(&mut self, segments: [u8; 4]) {
+        if segments == self.segments { return }
+        self.segments = segments;
+        self.send(&[WRITE_DATA]);
+        let mut data = [FIRST_DIGIT, 0, 0, 0, 0];
+        data[1..].copy_from_slice(&segments);
+        self.send(&data);
+        self.send(&[DISPLAY_ON | self.brightness]);
+    }
+
+    /// Set the brightness of the display.
+    ///
+    /// # Arguments
+    /// * level - The brightness, from 0 (lowest) to BRIGHTNESS_LEVELS - 1 (highest).
+    ///           Levels outside this range are clamped to the highest level.
+    pub fn set_brightness(&mut self, level: u8) {
+        self.brightness = level.min(Self::BRIGHTNESS_LEVELS - 1);
+        self.send(&[DISPLAY_ON | self.brightness]);
+    }
+
+    /// Shutdown the display (or turn it back on), keeping the digits shown.
+    pub fn shutdown(&mut self, off: bool) {
+        self.send(&[if off { DISPLAY_OFF } else { DISPLAY_ON | self.brightness }]);
+    }
+
+    /// Send a command, with any data following it, as a single transmission.
+    fn send(&mut self, bytes: &[u8]) {
+        // Start: DIO falls while CLK is high.
+        drive_low(DIO);
+        drive_low(CLK);
+        for &byte in bytes.iter() {
+            // The bits are sent from the lowest, each read by the display as CLK rises.
+            for bit in 0..8 {
+                if byte & (1 << bit) != 0 { release(DIO) } else { drive_low(DIO) }
+                release(CLK);
+                drive_low(CLK);
+            }
+            // The display acknowledges the byte while DIO is released, during a ninth clock.
+            release(DIO);
+            release(CLK);
+            drive_low(CLK);
+        }
+        // Stop: DIO rises while CLK is high.
+        drive_low(DIO);
+        release(CLK);
+        release(DIO);
+    }
+}
+
+
+/// Drive a line low, by making its pin an output (whose level is low).
+fn drive_low(line: u8) {
+    set_direction(line, true);
+}
+
+/// Release a line, which is then pulled up high, by making its pin an input.
+fn release(line: u8) {
+    set_direction(line, false);
+}
+
+/// Set whether the pin of a line is an output, then hold the line for a bit.
+fn set_direction(line: u8, output: bool) {
+    let portd = unsafe { &*PORTD::ptr() };
+    interrupt::free(|_| {
+        portd.ddrd.modify(|r, w| {
+            let bits: u8 = r.bits();
+            unsafe { w.bits(if output { bits | line } else { bits & !line }) }
+        });
+    });
+    arduino_uno::delay_us(BIT_DELAY_US);
+}
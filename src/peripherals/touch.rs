@@ -0,0 +1,108 @@
+/// Functionality for reading a capacitive touch button: a pad of foil (or any conductor,
+///   e.g. behind the wall of an enclosed case) which is pressed by touching it.
+///
+/// The pad is wired to a receive pin, which is wired through a high resistance (e.g. 1M)
+///   to a send pin. Each time the send pin changes level, the receive pin follows once the
+///   pad has charged (or discharged) through the resistor. A finger adds to the capacitance
+///   of the pad, so the charge takes longer: the time is measured by counting the checks of
+///   the receive pin until it follows, with interrupts disabled so that the counts are steady.
+///
+/// The counts depend on the pad, its wiring, and its surroundings, so the untouched count
+///   is calibrated as the button is created (while the pad isn't touched), and then follows
+///   the slow drift of the untouched counts (e.g. with the humidity).
+use arduino_uno::prelude::*;
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{Pin, mode::{Floating, Input, Output}};
+use avr_device::interrupt;
+
+use super::{InputDevice, InputSignal};
+
+// The number of charges (and discharges) of the pad summed into each reading.
+const SAMPLES: u8 = 8;
+// The most checks of the receive pin per charge (or discharge), in case the pad is unwired.
+const TIMEOUT_CHECKS: u16 = 2000;
+// How far above the untouched reading a reading must be for the pad to be touched,
+//   and how far below that it must fall for the pad to be released (the hysteresis).
+const TOUCH_THRESHOLD: u16 = 40;
+const RELEASE_THRESHOLD: u16 = 20;
+// The untouched reading follows the readings while the pad isn't touched,
+//   by 1/2^DRIFT_SHIFT of their difference per reading.
+const DRIFT_SHIFT: u8 = 4;
+
+
+/// Object that interfaces with a capacitive touch button.
+pub struct TouchButton {
+    // Digital pin that charges and discharges the pad, through the resistor.
+    send: Pin<Output>,
+    // Digital pin wired to the pad, which follows the send pin as the pad charges.
+    receive: Pin<Input<Floating>>,
+    // The reading of the untouched pad.
+    baseline: u16,
+    // Whether the pad is touched.
+    touched: bool,
+}
+
+impl TouchButton {
+
+    /// Creates a new TouchButton object, calibrating its untouched reading.
+    ///
+    /// # Arguments
+    /// * send    - The pin which charges the pad, through the resistor.
+    /// * receive - The pin wired to the pad.
+    pub fn new(send: Pin<Output>, receive: Pin<Input<Floating>>) -> Self {
+        let mut button = Self { send, receive, baseline: 0, touched: false };
+        button.baseline = button.measure();
+        button
+    }
+
+    /// Measure the time the pad takes to charge and discharge, summed over `SAMPLES`.
+    ///
+    /// # Returns
+    /// The number of checks of the receive pin until it followed the send pin.
+    fn measure(&mut self) -> u16 {
+        let (send, receive) = (&mut self.send, &self.receive);
+        interrupt::free(|_| {
+            // Returns the number of checks until the receive pin reads the level.
+            let wait = |high: bool| {
+                (0..TIMEOUT_CHECKS).find(|_| receive.is_high().void_unwrap() == high).unwrap_or(TIMEOUT_CHECKS)
+            };
+            // Begin with the pad discharged.
+            send.set_low().void_unwrap();
+            wait(false);
+            let mut checks: u16 = 0;
+            for _ in 0..SAMPLES {
+                send.set_high().void_unwrap();
+                checks += wait(true);
+                send.set_low().void_unwrap();
+                checks += wait(false);
+            }
+            checks
+        })
+    }
+}
+
+
+impl InputDevice for TouchButton {
+
+    /// Read the input data from the TouchButton Peripheral.
+    ///
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the pad is read by digital pins).
+    ///
+    /// # Returns
+    /// Some(InputSignal::Button) while the pad is touched.
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        let reading = self.measure();
+        self.touched = if self.touched {
+            reading >= self.baseline.saturating_add(RELEASE_THRESHOLD)
+        } else {
+            reading >= self.baseline.saturating_add(TOUCH_THRESHOLD)
+        };
+        if !self.touched {
+            // Follow the drift, whether up or down.
+            let difference = (reading as i32 - self.baseline as i32) >> DRIFT_SHIFT;
+            self.baseline = (self.baseline as i32 + difference) as u16;
+        }
+        if self.touched { Some(InputSignal::Button) } else { None }
+    }
+}
@@ -0,0 +1,133 @@
+/// Functionality for reading an HC-SR04 ultrasonic distance sensor, e.g. so that the height
+///   of a hand held over it controls a game.
+///
+/// A pulse of 10us on the trigger pin sends a burst of ultrasound, after which the echo pin
+///   is held high until the echo returns: for 5.8us per millimeter of the distance (there
+///   and back). The echo is timed by the count of Timer0 (see `clock::Stopwatch`), so it is
+///   timed alike whether or not interrupts are enabled.
+///
+/// The sensor is only read out to `FAR_MM`, so that a reading blocks for at most a few
+///   milliseconds, and no more often than every `MEASURE_INTERVAL_MS`, so that the echoes
+///   of a reading have died away before the next.
+use arduino_uno::prelude::*;
+use arduino_uno::adc::Adc;
+use arduino_uno::hal::port::{Pin, mode::{Floating, Input, Output}};
+
+use super::{clock, InputDevice, InputSignal, JoyStickSignal};
+
+// The time the trigger pin is held high, in microseconds.
+const TRIGGER_US: u16 = 10;
+// The most time between the trigger and the start of the echo, in microseconds.
+const ECHO_START_TIMEOUT_US: u32 = 1000;
+// The time of the echo per millimeter of the distance (there and back), in tenths of microseconds.
+const ECHO_TENTH_US_PER_MM: u32 = 58;
+// The least time between readings, in milliseconds.
+const MEASURE_INTERVAL_MS: u32 = 50;
+// The change of distance (in millimeters) below which the distance is considered unchanged,
+//   so that the noise of the readings isn't input.
+const DEADBAND_MM: u16 = 8;
+
+
+/// Object describing the distance measured by an ultrasonic sensor.
+#[derive(Copy, Clone)]
+pub struct DistanceSignal {
+    // The distance to the nearest object (e.g. a hand), in millimeters.
+    pub millimeters: u16,
+}
+
+impl DistanceSignal {
+
+    /// The nearest distance which is read, in millimeters (nearer objects read as this).
+    pub const NEAR_MM: u16 = 30;
+
+    /// The farthest distance which is read, in millimeters (farther objects aren't input).
+    pub const FAR_MM: u16 = 400;
+
+    /// Returns where the distance is within the range which is read, from 0 (`NEAR_MM`) to 255 (`FAR_MM`).
+    pub fn position(self) -> u8 {
+        let range = (Self::FAR_MM - Self::NEAR_MM) as u32;
+        let offset = self.millimeters.max(Self::NEAR_MM).min(Self::FAR_MM) - Self::NEAR_MM;
+        (offset as u32 * u8::MAX as u32 / range) as u8
+    }
+
+    /// Convert the DistanceSignal into the JoyStickSignal it stands for: the JoyStick pushed
+    ///   Up or Down as far as the distance is from the middle of the range (farther being Up).
+    pub fn to_joystick(self) -> JoyStickSignal {
+        JoyStickSignal { horiz: 0, vert: (self.position() as i16 - 128).max(-(i8::MAX as i16)) as i8, button: false }
+    }
+}
+
+
+/// Object that interfaces with an HC-SR04 ultrasonic distance sensor.
+pub struct Ultrasonic {
+    // Digital pin that triggers a reading.
+    trigger: Pin<Output>,
+    // Digital pin that the sensor holds high for the time of the echo.
+    echo: Pin<Input<Floating>>,
+    // The time, as returned by `clock::millis`, at which the sensor was last read.
+    read_ms: u32,
+    // The distance of the previous signal, if any.
+    millimeters: Option<u16>,
+}
+
+impl Ultrasonic {
+
+    /// Creates a new Ultrasonic object.
+    ///
+    /// # Arguments
+    /// * trigger - The pin connected to the trigger (Trig) of the sensor.
+    /// * echo    - The pin connected to the echo (Echo) of the sensor.
+    pub fn new(trigger: Pin<Output>, echo: Pin<Input<Floating>>) -> Self {
+        let read_ms = clock::millis().wrapping_sub(MEASURE_INTERVAL_MS);
+        Ultrasonic { trigger, echo, read_ms, millimeters: None }
+    }
+
+    /// Measure the distance to the nearest object, blocking until its echo returns.
+    ///
+    /// # Returns
+    /// The distance, in millimeters, or None if nothing is within `DistanceSignal::FAR_MM`.
+    pub fn measure(&mut self) -> Option<u16> {
+        let far_us = DistanceSignal::FAR_MM as u32 * ECHO_TENTH_US_PER_MM / 10;
+        self.read_ms = clock::millis();
+        self.trigger.set_high().void_unwrap();
+        arduino_uno::delay_us(TRIGGER_US);
+        self.trigger.set_low().void_unwrap();
+
+        let mut stopwatch = clock::Stopwatch::start();
+        while self.echo.is_low().void_unwrap() {
+            if stopwatch.elapsed_us() > ECHO_START_TIMEOUT_US { return None }
+        }
+        let mut stopwatch = clock::Stopwatch::start();
+        while self.echo.is_high().void_unwrap() {
+            if stopwatch.elapsed_us() > far_us { return None }
+        }
+        let echo_us = stopwatch.elapsed_us();
+        Some(((echo_us * 10 / ECHO_TENTH_US_PER_MM) as u16).max(DistanceSignal::NEAR_MM))
+    }
+}
+
+
+impl InputDevice for Ultrasonic {
+
+    /// Read the input data from the Ultrasonic Peripheral.
+    ///
+    /// The distance is only signalled when it changes (i.e. the first reading within
+    ///   range, and any which moves the distance beyond the deadband), so a hand held
+    ///   still (or no hand) is no input. Between readings, nothing is signalled.
+    ///
+    /// # Arguments
+    /// * _adc - The Analog-Digital convertor (unused, the sensor is read by digital pins).
+    ///
+    /// # Returns
+    /// Option<InputSignal::Distance>
+    fn read(&mut self, _adc: &mut Adc) -> Option<InputSignal> {
+        if clock::elapsed_since(self.read_ms) < MEASURE_INTERVAL_MS { return None }
+        let millimeters = self.measure()?;
+        let moved = self.millimeters.map_or(true, |previous| {
+            (millimeters as i32 - previous as i32).abs() > DEADBAND_MM as i32
+        });
+        if !moved { return None }
+        self.millimeters = Some(millimeters);
+        Some(InputSignal::Distance(DistanceSignal { millimeters }))
+    }
+}
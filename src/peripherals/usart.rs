@@ -0,0 +1,92 @@
+/// Functionality for writing and reading bytes directly through the registers of the USART,
+///   alongside the serial connection of the `Components` (e.g. by peripherals and debugging
+///   aids which have no access to it).
+///
+/// Writing blocks until the USART can take each byte. The bytes received are taken from the
+///   USART as they arrive, by the USART_RX interrupt (see `start_receiving`), and wait in a
+///   ring buffer to be read, so that none are lost while the game is busy (e.g. drawing).
+use avr_device::atmega328p::USART0;
+use avr_device::interrupt::{self, Mutex};
+use core::cell::RefCell;
+
+// The RX Complete Interrupt Enable bit of the UCSR0B register.
+const RXCIE0: u8 = 1 << 7;
+// The USART Data Register Empty bit of the UCSR0A register, set while a byte may be written.
+const UDRE0: u8 = 1 << 5;
+// The number of received bytes which may be waiting to be read (e.g. a command line of the console).
+const RECEIVED_CAPACITY: usize = 32;
+
+
+/// The bytes received, waiting to be read, as a ring buffer.
+struct Received {
+    bytes: [u8; RECEIVED_CAPACITY],
+    head: u8,
+    len: u8,
+}
+
+static RECEIVED: Mutex<RefCell<Received>> = Mutex::new(RefCell::new(Received {
+    bytes: [0; RECEIVED_CAPACITY], head: 0, len: 0,
+}));
+
+
+/// The USART_RX interrupt, which takes each byte received from the USART into the ring buffer.
+#[avr_device::interrupt(atmega328p)]
+fn USART_RX() {
+    let usart = unsafe { &*USART0::ptr() };
+    let byte: u8 = usart.udr0.read().bits();
+    interrupt::free(|cs| {
+        let mut received = RECEIVED.borrow(cs).borrow_mut();
+        // The newest byte is dropped if no room is left, so that the bytes read stay in order.
+        if received.len as usize == RECEIVED_CAPACITY { return }
+        let index = (received.head as usize + received.len as usize) % RECEIVED_CAPACITY;
+        received.bytes[index] = byte;
+        received.len += 1;
+    })
+}
+
+
+/// Start taking the bytes received into the ring buffer (see `read_byte`), once the serial
+///   connection is set up.
+pub fn start_receiving() {
+    let usart = unsafe { &*USART0::ptr() };
+    let ucsr0b: u8 = usart.ucsr0b.read().bits();
+    usart.ucsr0b.write(|w| unsafe { w.bits(ucsr0b | RXCIE0) });
+}
+
+
+/// Write a byte, blocking until the USART can take it.
+pub fn write_byte(byte: u8) {
+    let usart = unsafe { &*USART0::ptr() };
+    while usart.ucsr0a.read().bits::<u8>() & UDRE0 == 0 {}
+    usart.udr0.write(|w| unsafe { w.bits(byte) });
+}
+
+/// Write every byte of a slice, blocking until the USART has taken them all.
+pub fn write(bytes: &[u8]) {
+    bytes.iter().for_each(|&byte| write_byte(byte));
+}
+
+/// Read the oldest received byte, if one is waiting.
+pub fn read_byte() -> Option<u8> {
+    interrupt::free(|cs| {
+        let mut received = RECEIVED.borrow(cs).borrow_mut();
+        if received.len == 0 { return None }
+        let byte = received.bytes[received.head as usize];
+        received.head = ((received.head as usize + 1) % RECEIVED_CAPACITY) as u8;
+        received.len -= 1;
+        Some(byte)
+    })
+}
+
+
+/// A writer of text directly to the USART (see `write`), for formatting with `ufmt`.
+pub struct Writer;
+
+impl ufmt::uWrite for Writer {
+    type Error = core::convert::Infallible;
+
+    fn write_str(&mut self, text: &str) -> Result<(), Self::Error> {
+        write(text.as_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+/// Functionality having to do with the Watchdog Timer, which can reset the microcontroller.
+///
+/// Once enabled, the watchdog resets the microcontroller unless it is fed regularly,
+///   recovering from a crash (e.g. a game stuck in a loop).
+use avr_device::atmega328p::{CPU, WDT};
+use core::ptr;
+
+// The Watchdog Change Enable and Watchdog System Reset Enable bits of the WDTCSR register.
+const WDCE: u8 = 1 << 4;
+const WDE: u8 = 1 << 3;
+// The Watchdog Timer Prescaler bits of the WDTCSR register, selecting the longest timeout (8s).
+const WDP_8S: u8 = (1 << 5) | (1 << 0);
+// The Watchdog System Reset Flag bit of the MCUSR register.
+const WDRF: u8 = 1 << 3;
+
+/// The value marking a reset as requested (by `reset`), rather than a crash.
+const RESET_REQUESTED: u8 = 0x5A;
+
+/// Whether the last reset was requested, as RESET_REQUESTED.
+/// This is not initialized at start up, so that it survives the reset. It is only accessed
+///   through volatile reads and writes, so the compiler never assumes its initial value.
+#[link_section = ".noinit"]
+static mut RESET_MARKER: [u8; 1] = [0; 1];
+
+
+/// Returns whether the microcontroller was reset by the watchdog expiring, rather than
+///   by a `reset`, i.e. whether it has recovered from a crash.
+///
+/// This must be called at start up, before the watchdog is disabled.
+pub fn take_crash() -> bool {
+    let cpu = unsafe { &*CPU::ptr() };
+    let flags: u8 = cpu.mcusr.read().bits();
+    let [marker] = unsafe { ptr::read_volatile(ptr::addr_of!(RESET_MARKER)) };
+    unsafe { ptr::write_volatile(ptr::addr_of_mut!(RESET_MARKER), [0]) };
+    let requested = marker == RESET_REQUESTED;
+    (flags & WDRF != 0) & !requested
+}
+
+
+/// Disable the Watchdog Timer.
+///
+/// The Watchdog Timer remains enabled after it resets the microcontroller, so this must be
+///   called soon after start up, before the watchdog resets the microcontroller again.
+pub fn disable() {
+    avr_device::interrupt::free(|_| {
+        let (cpu, wdt) = unsafe { (&*CPU::ptr(), &*WDT::ptr()) };
+        cpu.mcusr.modify(|r, w| {
+            let flags: u8 = r.bits();
+            unsafe { w.bits(flags & !WDRF) }
+        });
+        // The change enable bit must be set before the watchdog can be turned off.
+        wdt.wdtcsr.write(|w| unsafe { w.bits(WDCE | WDE) });
+        wdt.wdtcsr.write(|w| unsafe { w.bits(0) });
+    });
+}
+
+/// Enable the Watchdog Timer, with its longest timeout (8s).
+///
+/// From then on, the watchdog must be fed (see `feed`) more often than its timeout,
+///   otherwise it resets the microcontroller.
+pub fn enable() {
+    avr_device::interrupt::free(|_| {
+        let wdt = unsafe { &*WDT::ptr() };
+        avr_device::asm::wdr();
+        wdt.wdtcsr.write(|w| unsafe { w.bits(WDCE | WDE) });
+        wdt.wdtcsr.write(|w| unsafe { w.bits(WDE | WDP_8S) });
+    });
+}
+
+/// Feed the Watchdog Timer, restarting its timeout.
+pub fn feed() {
+    avr_device::asm::wdr();
+}
+
+/// Reset the microcontroller.
+///
+/// This enables the Watchdog Timer with its shortest timeout (16ms),
+///   and waits for it to expire.
+pub fn reset() -> ! {
+    avr_device::interrupt::disable();
+    unsafe { ptr::write_volatile(ptr::addr_of_mut!(RESET_MARKER), [RESET_REQUESTED]) };
+    let wdt = unsafe { &*WDT::ptr() };
+    wdt.wdtcsr.write(|w| unsafe { w.bits(WDCE | WDE) });
+    wdt.wdtcsr.write(|w| unsafe { w.bits(WDE) });
+    loop {}
+}
@@ -0,0 +1,203 @@
+/// Functionality for driving an 8x8 matrix of WS2812 (NeoPixel) RGB LEDs, as the display
+///   of the games, in color (see `Display.show_tinted`).
+///
+/// The LEDs are chained on a single data line, each taking the first 24 bits it receives
+///   (green, red, then blue, from the highest bit) and passing on the rest. Each bit is a
+///   pulse of 1.25us, whose high time tells a 0 (0.4us) from a 1 (0.8us), so the bits are
+///   sent by a cycle-counted routine with interrupts disabled, and a low line for 50us
+///   latches the colors.
+///
+/// Rather than the 192 bytes of the colors of every LED, only the shade of each LED (an
+///   index into a handful of colors, e.g. a color per tint) is kept, and the bytes are
+///   rendered from the shades as they are sent.
+use arduino_uno::prelude::*;
+use arduino_uno::hal::port::{mode::Output, portd::PD6};
+#[cfg(target_arch = "avr")]
+use avr_device::atmega328p::PORTD;
+use avr_device::interrupt;
+
+use super::{Color, Display, DotDisplay, DotScreen, GreyScreen, Palette, Tint, TintedScreen};
+
+// The bit of the data pin (D6) in the registers of Port D.
+#[cfg(target_arch = "avr")]
+const DATA: u8 = 1 << 6;
+// The time the line is held low to latch the colors, in microseconds.
+const LATCH_US: u16 = 60;
+// Whether the rows of the matrix are wired in a serpentine (every other row running right
+//   to left), rather than each row running left to right.
+const SERPENTINE: bool = false;
+// The number of LEDs.
+const LEDS: usize = DotScreen::WIDTH * DotScreen::HEIGHT;
+// The number of shades the LEDs may show at once: off, then a color per tint (or per grey level).
+const SHADES: usize = 1 + Tint::COUNT;
+// The shade of an LED which is off.
+const OFF: u8 = 0;
+// The scale (out of 16) of the colors at each brightness level, perceived as evenly spaced.
+const GAMMA_TABLE: [u8; DotDisplay::BRIGHTNESS_LEVELS as usize] = [1, 2, 3, 4, 6, 8, 11, 16];
+
+
+/// Object that interfaces with an 8x8 WS2812 matrix, on pin D6.
+///
+/// The first LED is the top left dot, the LEDs following along each row.
+pub struct Ws2812 {
+    // The data pin, driven through the registers of Port D.
+    pin: PD6<Output>,
+    // The (perceived) brightness level.
+    brightness: u8,
+    // Whether the LEDs are off (shut down).
+    off: bool,
+    // The shade of each LED, two to a byte (the even LED in the low nibble), indexing `colors`.
+    shades: [u8; LEDS / 2],
+    // The color of each shade, at full brightness.
+    colors: [Color; SHADES],
+}
+
+impl Ws2812 {
+
+    /// Creates a new Ws2812 object, turning every LED off.
+    pub fn new(mut pin: PD6<Output>) -> Self {
+        pin.set_low().void_unwrap();
+        let mut matrix = Self {
+            pin,
+            brightness: DotDisplay::DEFAULT_BRIGHTNESS,
+            off: false,
+            shades: [0; LEDS / 2],
+            colors: [Color::new(0, 0, 0); SHADES],
+        };
+        matrix.send();
+        matrix
+    }
+
+    /// Set the shade of the LED of a dot.
+    fn set_shade(&mut self, x: usize, y: usize, shade: u8) {
+        let row = DotScreen::HEIGHT - 1 - y;
+        let column = if SERPENTINE && (row % 2 == 1) { DotScreen::WIDTH - 1 - x } else { x };
+        let led = row * DotScreen::WIDTH + column;
+        let shift = 4 * (led % 2);
+        self.shades[led / 2] = (self.shades[led / 2] & !(0x0F << shift)) | (shade << shift);
+    }
+
+    /// Send the shades to the LEDs, in their colors scaled to the brightness.
+    fn send(&mut self) {
+        let scale = if self.off { 0 } else { GAMMA_TABLE[self.brightness as usize] as u16 };
+        let scaled = |channel: u8| ((channel as u16 * scale) / 16) as u8;
+        // The bytes of each shade (green, red, blue), so that rendering each LED is a lookup.
+        let mut bytes = [[0u8; 3]; SHADES];
+        for (bytes, color) in bytes.iter_mut().zip(self.colors.iter()) {
+            *bytes = [scaled(color.green), scaled(color.red), scaled(color.blue)];
+        }
+        // The bits must not be stretched by an interrupt (the clock loses about 2ms meanwhile).
+        interrupt::free(|_| {
+            for led in 0..LEDS {
+                let shade = (self.shades[led / 2] >> (4 * (led % 2))) & 0x0F;
+                bytes[shade as usize].iter().for_each(|&byte| send_byte(byte));
+            }
+        });
+        arduino_uno::delay_us(LATCH_US);
+        self.pin.set_low().void_unwrap();
+    }
+}
+
+
+impl Display for Ws2812 {
+
+    /// Show a DotScreen, its dots in the color of the HUD (white).
+    fn show(&mut self, screen: &DotScreen) {
+        const ON: u8 = 1;
+        self.colors[ON as usize] = Palette::DEFAULT.color(Tint::Hud);
+        for dot in screen.iter() {
+            self.set_shade(dot.x, dot.y, if screen.is_dot_on(&dot) { ON } else { OFF });
+        }
+        self.send();
+    }
+
+    /// Show a TintedScreen, each dot in the color of its topmost tint.
+    fn show_tinted(&mut self, screen: &TintedScreen, palette: &Palette) {
+        // The shade of each tint follows that of the LEDs which are off.
+        let tints = [Tint::Background, Tint::Pickup, Tint::Hazard, Tint::Actor, Tint::Hud];
+        for &tint in tints.iter() {
+            self.colors[1 + tint as usize] = palette.color(tint);
+        }
+        for dot in DotScreen::new_empty().iter() {
+            let shade = screen.tint_at(&dot).map_or(OFF, |tint| 1 + tint as u8);
+            self.set_shade(dot.x, dot.y, shade);
+        }
+        self.send();
+    }
+
+    /// Show a GreyScreen, each dot in the color of the HUD (white) scaled to its level,
+    ///   at once (rather than as sub-frames).
+    fn show_grey(&mut self, screen: &GreyScreen) {
+        // The shade of each dot is its level (so that level 0 is off).
+        let color = Palette::DEFAULT.color(Tint::Hud);
+        let scale = |channel: u8, level: u8| (channel as u16 * level as u16 / GreyScreen::MAX_LEVEL as u16) as u8;
+        for level in 0..=GreyScreen::MAX_LEVEL {
+            self.colors[level as usize] = Color::new(scale(color.red, level), scale(color.green, level), scale(color.blue, level));
+        }
+        for dot in DotScreen::new_empty().iter() {
+            self.set_shade(dot.x, dot.y, screen.level(&dot));
+        }
+        self.send();
+    }
+
+    fn set_brightness(&mut self, level: u8) {
+        self.brightness = level.min(DotDisplay::BRIGHTNESS_LEVELS - 1);
+        self.send();
+    }
+
+    fn brightness(&self) -> u8 {
+        self.brightness
+    }
+
+    fn shutdown(&mut self, off: bool) {
+        self.off = off;
+        self.send();
+    }
+}
+
+
+/// Send a byte to the LEDs, from its highest bit, each bit taking 20 cycles (1.25us at 16MHz):
+///   the line is high for 5 cycles (0.3125us) for a 0, or 12 cycles (0.75us) for a 1.
+///
+/// Both paths of a bit take the same 20 cycles: a 0 runs the `sbrs` (1 cycle) and the `out`
+///   after it (1 cycle), where a 1 skips that `out` (the `sbrs` then taking 2 cycles).
+///
+/// This must be called with interrupts disabled.
+#[allow(unused_variables)]
+fn send_byte(byte: u8) {
+    #[cfg(target_arch = "avr")]
+    unsafe {
+        let portd: u8 = (*PORTD::ptr()).portd.read().bits();
+        let (high, low) = (portd | DATA, portd & !DATA);
+        let mut byte = byte;
+        let _count: u8;
+        // PORTD is at I/O address 0x0B.
+        llvm_asm!("
+            ldi $1, 8
+        1:
+            out 0x0B, $2
+            nop
+            nop
+            nop
+            sbrs $0, 7
+            out 0x0B, $3
+            lsl $0
+            nop
+            nop
+            nop
+            nop
+            nop
+            out 0x0B, $3
+            nop
+            nop
+            nop
+            nop
+            dec $1
+            brne 1b"
+            : "+r"(byte), "=&d"(_count)
+            : "r"(high), "r"(low)
+            :
+            : "volatile"
+        );
+    }
+}
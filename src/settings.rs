@@ -0,0 +1,63 @@
+/// The settings which apply across every game, persisted to the EEPROM.
+///
+/// The settings are stored as a record at the start of the EEPROM, holding the bytes:
+///   brightness, difficulty, sound
+/// If the EEPROM holds no valid record (e.g. it has never been written),
+///   the default settings are used instead.
+use crate::peripherals::{eeprom, DotDisplay};
+
+/// The magic byte identifying the record of the settings.
+const MAGIC: u8 = 0x5E;
+
+
+/// The settings which apply across every game.
+#[derive(Copy, Clone, PartialEq)]
+pub struct Settings {
+    /// The brightness level of the display, see `DotDisplay::set_brightness`,
+    ///   or `AUTO_BRIGHTNESS` for it to follow the ambient light.
+    pub brightness: u8,
+    /// The default difficulty level (0 is easy, 1 is normal, 2 is hard),
+    ///   for the games which offer a choice of difficulty.
+    pub difficulty: u8,
+    /// Whether sound is enabled.
+    pub sound: bool,
+}
+
+impl Settings {
+
+    /// The brightness setting for the brightness of the display to follow the ambient light,
+    ///   as sensed by the light sensor (see `LightSensor`). It is one above the highest level,
+    ///   which it amounts to if there is no light sensor.
+    pub const AUTO_BRIGHTNESS: u8 = DotDisplay::BRIGHTNESS_LEVELS;
+
+    /// The number of difficulty levels.
+    pub const DIFFICULTY_LEVELS: u8 = 3;
+
+    /// The address of the EEPROM at which the settings are stored.
+    pub const ADDRESS: usize = 0;
+
+    /// The number of bytes the settings occupy in the EEPROM.
+    pub const STORED_SIZE: usize = 3 + eeprom::RECORD_OVERHEAD;
+
+    /// Load the settings from the EEPROM, or the default settings if none are stored.
+    pub fn load() -> Self {
+        let mut bytes = [0u8; 3];
+        let valid = eeprom::read_record(Self::ADDRESS, MAGIC, &mut bytes);
+        let [brightness, difficulty, sound] = bytes;
+        let valid = valid
+            & (brightness <= Self::AUTO_BRIGHTNESS)
+            & (difficulty < Self::DIFFICULTY_LEVELS);
+        if valid { Self { brightness, difficulty, sound: sound != 0 } } else { Self::default() }
+    }
+
+    /// Store the settings to the EEPROM.
+    pub fn save(&self) {
+        eeprom::write_record(Self::ADDRESS, MAGIC, &[self.brightness, self.difficulty, self.sound as u8]);
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self { brightness: DotDisplay::DEFAULT_BRIGHTNESS, difficulty: 1, sound: true }
+    }
+}
@@ -0,0 +1,204 @@
+/// Melodies and sound effects, played on the buzzer alongside the game-play.
+///
+/// A melody is a sequence of notes kept in program memory (see `progmem`), each of two
+///   bytes: the pitch of the note (a MIDI note number, see `pitch`) and its length (in
+///   units of the tempo of the melody). Sound effects are simply short, fast melodies.
+///
+/// The `SoundPlayer` feeds the notes to the buzzer a few at a time, once every game tick,
+///   so that playing a melody never blocks the game-play (the buzzer plays the queued
+///   notes by itself, see `Buzzer`).
+use crate::peripherals::{progmem, Buzzer, Note};
+
+/// The number of notes of the music queued ahead on the buzzer. A sound effect cuts off
+///   the queued music, so this is kept small.
+const MUSIC_LOOKAHEAD: usize = 4;
+
+
+/// The pitches of the notes of melodies, as MIDI note numbers (middle C, C4, is 60).
+pub mod pitch {
+    /// A rest (silence).
+    pub const REST: u8 = 0;
+    pub const E2: u8 = 40;
+    pub const F2: u8 = 41;
+    pub const C4: u8 = 60;
+    pub const E4: u8 = 64;
+    pub const G4: u8 = 67;
+    pub const C5: u8 = 72;
+    pub const E5: u8 = 76;
+    pub const G5: u8 = 79;
+    pub const C6: u8 = 84;
+    pub const E6: u8 = 88;
+    pub const C7: u8 = 96;
+}
+
+/// The frequencies, in Hertz, of the notes of the highest octave (C8 to B8, MIDI notes 108
+///   to 119). Every lower octave halves the frequencies.
+#[link_section = ".progmem.data"]
+static TOP_OCTAVE_HZ: [u16; 12] = [4186, 4435, 4699, 4978, 5274, 5588, 5920, 6272, 6645, 7040, 7459, 7902];
+
+/// Returns the frequency, in Hertz, of a pitch (0 for a rest).
+fn frequency_hz(pitch: u8) -> u16 {
+    if pitch == pitch::REST { return 0 }
+    let address = &TOP_OCTAVE_HZ[pitch as usize % 12] as *const u16 as *const u8;
+    let top = unsafe { u16::from_le_bytes([progmem::read_byte(address), progmem::read_byte(address.add(1))]) };
+    top >> (9 - (pitch as usize / 12).min(9))
+}
+
+
+/// A melody: a sequence of notes, kept in program memory.
+pub struct Melody {
+    /// The notes, as (pitch, length) pairs. These must be placed in the `.progmem.data` section.
+    notes: &'static [[u8; 2]],
+    /// The duration of a unit of length, in milliseconds (the tempo of the melody).
+    unit_ms: u16,
+}
+
+impl Melody {
+
+    /// Create a new Melody.
+    ///
+    /// # Arguments
+    /// * notes   - The notes, as (pitch, length) pairs, placed in the `.progmem.data` section.
+    /// * unit_ms - The duration of a unit of length, in milliseconds.
+    pub const fn new(notes: &'static [[u8; 2]], unit_ms: u16) -> Self {
+        Self { notes, unit_ms }
+    }
+
+    /// Returns the number of notes of the melody.
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    /// Returns whether the melody has no notes.
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Returns a note of the melody, read from program memory.
+    pub fn note(&self, index: usize) -> Note {
+        let [pitch, length] = unsafe {
+            [progmem::read_byte(&self.notes[index][0]), progmem::read_byte(&self.notes[index][1])]
+        };
+        Note::new(frequency_hz(pitch), length as u16 * self.unit_ms)
+    }
+}
+
+
+#[link_section = ".progmem.data"]
+static BLIP_NOTES: [[u8; 2]; 2] = [[pitch::E6, 1], [pitch::C7, 1]];
+/// A short rising blip, e.g. for moving through a menu or scoring a point.
+pub static BLIP: Melody = Melody::new(&BLIP_NOTES, 30);
+
+#[link_section = ".progmem.data"]
+static BUZZ_NOTES: [[u8; 2]; 6] = [
+    [pitch::F2, 1], [pitch::E2, 1], [pitch::F2, 1], [pitch::E2, 1], [pitch::F2, 1], [pitch::E2, 2],
+];
+/// A low, harsh buzz, e.g. for a mistake or the player dying.
+pub static BUZZ: Melody = Melody::new(&BUZZ_NOTES, 35);
+
+#[link_section = ".progmem.data"]
+static FANFARE_NOTES: [[u8; 2]; 7] = [
+    [pitch::G4, 1], [pitch::C5, 1], [pitch::E5, 1], [pitch::G5, 3],
+    [pitch::E5, 1], [pitch::G5, 4], [pitch::C6, 6],
+];
+/// A triumphant fanfare, e.g. for completing a level or a new high score.
+pub static FANFARE: Melody = Melody::new(&FANFARE_NOTES, 80);
+
+
+/// The progress through a melody being played.
+#[derive(Copy, Clone)]
+struct Playing {
+    melody: &'static Melody,
+    /// The index of the next note to be queued.
+    position: usize,
+    /// Whether the melody starts over once it ends.
+    looping: bool,
+}
+
+impl Playing {
+
+    /// Queue the notes of the melody on the buzzer, until the buzzer has `limit` notes queued.
+    ///
+    /// # Returns
+    /// Whether the melody is still playing, i.e. false once every note has been queued.
+    fn feed(&mut self, buzzer: &mut Buzzer, limit: usize) -> bool {
+        if self.melody.is_empty() { return false }
+        while buzzer.queued() < limit {
+            if self.position == self.melody.len() {
+                if !self.looping { return false }
+                self.position = 0;
+            }
+            if !buzzer.play(self.melody.note(self.position)) { break }
+            self.position += 1;
+        }
+        true
+    }
+}
+
+
+/// Plays music and sound effects on the buzzer, advancing once every game tick (see `tick`).
+///
+/// A sound effect plays over the music: it cuts off the notes of the music queued on the
+///   buzzer, and the music carries on once the effect is over.
+pub struct SoundPlayer {
+    /// The music being played, if any.
+    music: Option<Playing>,
+    /// The sound effect being played, if any.
+    effect: Option<Playing>,
+    /// Whether a new sound effect is to cut off the notes queued on the buzzer.
+    cut: bool,
+}
+
+impl SoundPlayer {
+
+    /// Create a new SoundPlayer, playing nothing.
+    pub const fn new() -> Self {
+        Self { music: None, effect: None, cut: false }
+    }
+
+    /// Start playing music, replacing any music being played.
+    ///
+    /// # Arguments
+    /// * melody  - The melody of the music.
+    /// * looping - Whether the music starts over once it ends.
+    pub fn play_music(&mut self, melody: &'static Melody, looping: bool) {
+        self.music = Some(Playing { melody, position: 0, looping });
+    }
+
+    /// Start playing a sound effect, replacing any sound effect being played.
+    pub fn play_effect(&mut self, melody: &'static Melody) {
+        self.effect = Some(Playing { melody, position: 0, looping: false });
+        self.cut = true;
+    }
+
+    /// Stop the music and any sound effect. The notes already queued on the buzzer still play.
+    pub fn stop(&mut self) {
+        self.music = None;
+        self.effect = None;
+        self.cut = false;
+    }
+
+    /// Returns whether music or a sound effect is being played.
+    pub fn is_playing(&self) -> bool {
+        self.music.is_some() | self.effect.is_some()
+    }
+
+    /// Advance the playback, queueing the next notes on the buzzer. Call this once every game tick.
+    ///
+    /// # Arguments
+    /// * buzzer - The Buzzer peripheral.
+    pub fn tick(&mut self, buzzer: &mut Buzzer) {
+        if self.cut {
+            buzzer.stop();
+            self.cut = false;
+        }
+        if let Some(effect) = self.effect.as_mut() {
+            if !effect.feed(buzzer, usize::MAX) { self.effect = None }
+        }
+        // The music waits until every note of the sound effect has been queued.
+        if self.effect.is_some() { return }
+        if let Some(music) = self.music.as_mut() {
+            if !music.feed(buzzer, MUSIC_LOOKAHEAD) { self.music = None }
+        }
+    }
+}
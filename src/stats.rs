@@ -0,0 +1,89 @@
+/// The lifetime statistics of each game (how often it was played, and the total of its
+///   scores), persisted to the EEPROM.
+///
+/// The statistics are stored as a record directly after the high scores, holding a slot
+///   for each game: the identifier of the game, the number of games played (little endian),
+///   and the total of their scores (little endian). An identifier of 0 marks an unused slot.
+///   Both counts saturate, rather than wrapping around.
+use crate::high_scores;
+use crate::peripherals::eeprom;
+
+/// The magic byte identifying the record of the statistics.
+const MAGIC: u8 = 0x50;
+/// The address of the EEPROM at which the statistics are stored.
+pub const ADDRESS: usize = high_scores::ADDRESS + high_scores::STORED_SIZE;
+/// The number of games which may store statistics.
+const SLOTS: usize = 32;
+/// The number of bytes of each slot.
+const SLOT_SIZE: usize = 7;
+/// The number of bytes the statistics occupy in the EEPROM.
+pub const STORED_SIZE: usize = SLOTS * SLOT_SIZE + eeprom::RECORD_OVERHEAD;
+/// The identifier of an unused slot.
+const UNUSED: u8 = 0;
+
+
+/// The lifetime statistics of a game.
+#[derive(Copy, Clone, Default)]
+pub struct GameStats {
+    /// The number of games played (to the end, i.e. which reported a score).
+    pub plays: u16,
+    /// The total of the scores of every game played.
+    pub total_score: u32,
+}
+
+
+/// The slots of the statistics, as stored in the EEPROM.
+type Slots = [u8; SLOTS * SLOT_SIZE];
+
+/// Load the slots from the EEPROM, which are all unused if none are stored.
+fn load() -> Slots {
+    let mut slots = [UNUSED; SLOTS * SLOT_SIZE];
+    if !eeprom::read_record(ADDRESS, MAGIC, &mut slots) {
+        slots = [UNUSED; SLOTS * SLOT_SIZE];
+    }
+    slots
+}
+
+/// Returns the slot of the game, if it has one.
+fn find(slots: &Slots, game_id: u8) -> Option<usize> {
+    slots.chunks(SLOT_SIZE).position(|slot| slot[0] == game_id)
+}
+
+/// Returns the statistics stored in a slot.
+fn stats_of(slots: &Slots, index: usize) -> GameStats {
+    let slot = &slots[(index * SLOT_SIZE)..((index + 1) * SLOT_SIZE)];
+    GameStats {
+        plays: u16::from_le_bytes([slot[1], slot[2]]),
+        total_score: u32::from_le_bytes([slot[3], slot[4], slot[5], slot[6]]),
+    }
+}
+
+/// Returns the statistics of the game, which are all zero if it has never been played.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+pub fn of(game_id: u8) -> GameStats {
+    let slots = load();
+    find(&slots, game_id).map(|index| stats_of(&slots, index)).unwrap_or_default()
+}
+
+/// Record a finished game into the statistics of the game.
+///
+/// If every slot is in use by other games, the game is not recorded.
+///
+/// # Arguments
+/// * game_id - The identifier of the game, see `Components::report_score`.
+/// * score   - The final score.
+pub fn record(game_id: u8, score: u16) {
+    let mut slots = load();
+    let index = match find(&slots, game_id).or_else(|| find(&slots, UNUSED)) {
+        Some(index) => index,
+        None => { return },
+    };
+    let stats = if slots[index * SLOT_SIZE] == game_id { stats_of(&slots, index) } else { GameStats::default() };
+    let [plays_low, plays_high] = stats.plays.saturating_add(1).to_le_bytes();
+    let [total0, total1, total2, total3] = stats.total_score.saturating_add(score as u32).to_le_bytes();
+    slots[(index * SLOT_SIZE)..((index + 1) * SLOT_SIZE)]
+        .copy_from_slice(&[game_id, plays_low, plays_high, total0, total1, total2, total3]);
+    eeprom::write_record(ADDRESS, MAGIC, &slots);
+}